@@ -85,21 +85,68 @@ impl ChineseNumber {
         }
     }
 
+    /// 日文的漢數字，大寫數字使用大字。
+    #[cfg(feature = "number-to-chinese")]
+    #[inline]
+    pub(crate) const fn to_japanese_str(self, chinese_case: ChineseCase) -> &'static str {
+        match self {
+            Self::零 => match chinese_case {
+                ChineseCase::Upper => "零",
+                ChineseCase::Lower => "〇",
+            },
+            Self::一 => match chinese_case {
+                ChineseCase::Upper => "壱",
+                ChineseCase::Lower => "一",
+            },
+            Self::二 => match chinese_case {
+                ChineseCase::Upper => "弐",
+                ChineseCase::Lower => "二",
+            },
+            Self::三 => match chinese_case {
+                ChineseCase::Upper => "参",
+                ChineseCase::Lower => "三",
+            },
+            Self::四 => "四",
+            Self::五 => "五",
+            Self::六 => "六",
+            Self::七 => "七",
+            Self::八 => "八",
+            Self::九 => "九",
+            Self::十 => match chinese_case {
+                ChineseCase::Upper => "拾",
+                ChineseCase::Lower => "十",
+            },
+        }
+    }
+
+    /// 古代帳冊上防止竄改的大寫數字，沒有的話回傳 `None`。
+    #[cfg(feature = "number-to-chinese")]
+    #[inline]
+    pub(crate) const fn to_ancient_str(self) -> Option<&'static str> {
+        match self {
+            Self::一 => Some("弌"),
+            Self::二 => Some("弍"),
+            Self::三 => Some("弎"),
+            Self::十 => Some("什"),
+            _ => None,
+        }
+    }
+
     #[cfg(feature = "chinese-to-number")]
     #[inline]
     pub(crate) const fn from_char(character: char) -> Option<Self> {
         match character {
             '零' | '0' | '〇' => Some(Self::零),
-            '一' | '壹' | '1' => Some(Self::一),
-            '二' | '貳' | '贰' | '貮' | '兩' | '两' | '2' => Some(Self::二),
-            '三' | '參' | '叁' | '叄' | '参' | '3' => Some(Self::三),
+            '一' | '壹' | '弌' | '壱' | '1' => Some(Self::一),
+            '二' | '貳' | '贰' | '貮' | '弍' | '弐' | '兩' | '两' | '2' => Some(Self::二),
+            '三' | '參' | '叁' | '叄' | '参' | '弎' | '3' => Some(Self::三),
             '四' | '肆' | '4' => Some(Self::四),
             '五' | '伍' | '5' => Some(Self::五),
             '六' | '陸' | '陆' | '6' => Some(Self::六),
             '七' | '柒' | '7' => Some(Self::七),
             '八' | '捌' | '8' => Some(Self::八),
             '九' | '玖' | '9' => Some(Self::九),
-            '十' | '拾' => Some(Self::十),
+            '十' | '拾' | '什' => Some(Self::十),
             _ => None,
         }
     }
@@ -112,6 +159,14 @@ impl ChineseNumber {
 #[allow(dead_code)]
 #[repr(u8)]
 pub(crate) enum ChineseExponent {
+    塵,
+    沙,
+    纖,
+    微,
+    忽,
+    絲,
+    毫,
+    厘,
     分,
     角,
     個,
@@ -130,6 +185,11 @@ pub(crate) enum ChineseExponent {
     正,
     載,
     極,
+    恆河沙,
+    阿僧祇,
+    那由他,
+    不可思議,
+    無量大數,
 }
 
 impl ChineseExponent {
@@ -141,6 +201,23 @@ impl ChineseExponent {
         chinese_case: ChineseCase,
     ) -> &'static str {
         match self {
+            Self::塵 => match chinese_variant {
+                ChineseVariant::Traditional => "塵",
+                ChineseVariant::Simple => "尘",
+            },
+            Self::沙 => "沙",
+            Self::纖 => match chinese_variant {
+                ChineseVariant::Traditional => "纖",
+                ChineseVariant::Simple => "纤",
+            },
+            Self::微 => "微",
+            Self::忽 => "忽",
+            Self::絲 => match chinese_variant {
+                ChineseVariant::Traditional => "絲",
+                ChineseVariant::Simple => "丝",
+            },
+            Self::毫 => "毫",
+            Self::厘 => "厘",
             Self::分 => "分",
             Self::角 => "角",
             Self::個 => match chinese_variant {
@@ -189,6 +266,77 @@ impl ChineseExponent {
                 ChineseVariant::Traditional => "極",
                 ChineseVariant::Simple => "极",
             },
+            Self::恆河沙 => match chinese_variant {
+                ChineseVariant::Traditional => "恆河沙",
+                ChineseVariant::Simple => "恒河沙",
+            },
+            Self::阿僧祇 => "阿僧祇",
+            Self::那由他 => "那由他",
+            Self::不可思議 => match chinese_variant {
+                ChineseVariant::Traditional => "不可思議",
+                ChineseVariant::Simple => "不可思议",
+            },
+            Self::無量大數 => match chinese_variant {
+                ChineseVariant::Traditional => "無量大數",
+                ChineseVariant::Simple => "无量大数",
+            },
+        }
+    }
+
+    /// 日文的漢數字所使用的單位，大寫數字使用大字。
+    #[cfg(feature = "number-to-chinese")]
+    #[inline]
+    pub(crate) const fn to_japanese_str(self, chinese_case: ChineseCase) -> &'static str {
+        match self {
+            Self::塵 => "塵",
+            Self::沙 => "沙",
+            Self::纖 => "繊",
+            Self::微 => "微",
+            Self::忽 => "忽",
+            Self::絲 => "糸",
+            Self::毫 => "毛",
+            Self::厘 => "厘",
+            Self::分 => "分",
+            Self::角 => "角",
+            Self::個 => "個",
+            Self::十 => match chinese_case {
+                ChineseCase::Upper => "拾",
+                ChineseCase::Lower => "十",
+            },
+            Self::百 => "百",
+            Self::千 => "千",
+            Self::萬 => match chinese_case {
+                ChineseCase::Upper => "萬",
+                ChineseCase::Lower => "万",
+            },
+            Self::億 => "億",
+            Self::兆 => "兆",
+            Self::京 => "京",
+            Self::垓 => "垓",
+            Self::秭 => "𥝱",
+            Self::穰 => "穣",
+            Self::溝 => "溝",
+            Self::澗 => "澗",
+            Self::正 => "正",
+            Self::載 => "載",
+            Self::極 => "極",
+            Self::恆河沙 => "恒河沙",
+            Self::阿僧祇 => "阿僧祇",
+            Self::那由他 => "那由他",
+            Self::不可思議 => "不可思議",
+            Self::無量大數 => "無量大数",
+        }
+    }
+
+    /// 古代帳冊上防止竄改的大寫單位，沒有的話回傳 `None`。
+    #[cfg(feature = "number-to-chinese")]
+    #[inline]
+    pub(crate) const fn to_ancient_str(self) -> Option<&'static str> {
+        match self {
+            Self::十 => Some("什"),
+            Self::百 => Some("陌"),
+            Self::千 => Some("阡"),
+            _ => None,
         }
     }
 
@@ -196,19 +344,27 @@ impl ChineseExponent {
     #[inline]
     pub(crate) const fn from_char(character: char) -> Option<Self> {
         match character {
+            '塵' | '尘' => Some(Self::塵),
+            '沙' => Some(Self::沙),
+            '纖' | '纤' | '繊' => Some(Self::纖),
+            '微' => Some(Self::微),
+            '忽' => Some(Self::忽),
+            '絲' | '丝' | '糸' => Some(Self::絲),
+            '毫' => Some(Self::毫),
+            '厘' | '釐' => Some(Self::厘),
             '分' => Some(Self::分),
             '角' => Some(Self::角),
             '個' | '个' => Some(Self::個),
-            '十' | '拾' => Some(Self::十),
-            '百' | '佰' => Some(Self::百),
-            '千' | '仟' => Some(Self::千),
+            '十' | '拾' | '什' => Some(Self::十),
+            '百' | '佰' | '陌' => Some(Self::百),
+            '千' | '仟' | '阡' => Some(Self::千),
             '萬' | '万' => Some(Self::萬),
             '億' | '亿' => Some(Self::億),
             '兆' => Some(Self::兆),
             '京' => Some(Self::京),
             '垓' => Some(Self::垓),
-            '秭' => Some(Self::秭),
-            '穰' => Some(Self::穰),
+            '秭' | '𥝱' => Some(Self::秭),
+            '穰' | '穣' => Some(Self::穰),
             '溝' | '沟' => Some(Self::溝),
             '澗' | '涧' => Some(Self::澗),
             '正' => Some(Self::正),
@@ -217,8 +373,36 @@ impl ChineseExponent {
             _ => None,
         }
     }
+
+    /// 找出結尾位於 `chars[end]` 的單位，回傳單位和它開頭的索引。**「恆河沙」** 以上的單位由多個字元組成，無法使用 `from_char`。
+    #[cfg(feature = "chinese-to-number")]
+    pub(crate) fn from_chars_ending_at(chars: &[char], end: usize) -> Option<(Self, usize)> {
+        for (exponent, pattern) in MULTI_CHARACTER_EXPONENTS.iter() {
+            let length = pattern.len();
+
+            if end + 1 >= length {
+                let start = end + 1 - length;
+
+                if chars[start..=end].iter().zip(pattern.iter()).all(|(c, p)| p.contains(c)) {
+                    return Some((*exponent, start));
+                }
+            }
+        }
+
+        Self::from_char(chars[end]).map(|exponent| (exponent, end))
+    }
 }
 
+/// 由多個字元組成的單位，每個位置列出可以使用的字元。
+#[cfg(feature = "chinese-to-number")]
+const MULTI_CHARACTER_EXPONENTS: [(ChineseExponent, &[&[char]]); 5] = [
+    (ChineseExponent::恆河沙, &[&['恆', '恒'], &['河'], &['沙']]),
+    (ChineseExponent::阿僧祇, &[&['阿'], &['僧'], &['祇']]),
+    (ChineseExponent::那由他, &[&['那'], &['由'], &['他']]),
+    (ChineseExponent::不可思議, &[&['不'], &['可'], &['思'], &['議', '议']]),
+    (ChineseExponent::無量大數, &[&['無', '无'], &['量'], &['大'], &['數', '数']]),
+];
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[allow(dead_code)]
 #[repr(u8)]
@@ -244,11 +428,22 @@ impl ChineseSign {
     #[inline]
     pub(crate) const fn from_char(character: char) -> Option<Self> {
         match character {
-            '正' => Some(Self::正),
-            '負' | '负' => Some(Self::負),
+            '正' | '+' | '＋' => Some(Self::正),
+            '負' | '负' | '減' | '减' | '-' | '−' | '－' => Some(Self::負),
             _ => None,
         }
     }
+
+    /// 如果 `chars` 以正負號開頭，回傳該正負號和它的字數。除了單一字元的正負號之外，也接受 **「零下」**。
+    #[cfg(feature = "chinese-to-number")]
+    #[inline]
+    pub(crate) fn from_prefix(chars: &[char]) -> Option<(Self, usize)> {
+        match chars {
+            ['零', '下', ..] => Some((Self::負, 2)),
+            [c, ..] => Self::from_char(*c).map(|sign| (sign, 1)),
+            [] => None,
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -273,3 +468,147 @@ impl ChinesePoint {
         }
     }
 }
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct ChineseOrdinal;
+
+impl ChineseOrdinal {
+    #[cfg(feature = "number-to-chinese")]
+    #[inline]
+    pub(crate) const fn to_str() -> &'static str {
+        "第"
+    }
+
+    #[cfg(feature = "chinese-to-number")]
+    #[inline]
+    pub(crate) const fn from_char(character: char) -> Option<Self> {
+        match character {
+            '第' => Some(ChineseOrdinal),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct ChineseFraction;
+
+impl ChineseFraction {
+    #[cfg(feature = "number-to-chinese")]
+    #[inline]
+    pub(crate) const fn to_str() -> &'static str {
+        "分之"
+    }
+
+    #[cfg(feature = "number-to-chinese")]
+    #[inline]
+    pub(crate) const fn to_japanese_str() -> &'static str {
+        "分の"
+    }
+
+    /// 分數中位於分母和分子之間的 **「之」** 或日文的 **「の」**，前面的 **「分」** 可以省略。
+    #[cfg(feature = "chinese-to-number")]
+    #[inline]
+    pub(crate) const fn from_char(character: char) -> Option<Self> {
+        match character {
+            '之' | 'の' => Some(ChineseFraction),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "number-to-chinese")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum ChineseNonFinite {
+    無窮大,
+    非數,
+}
+
+#[cfg(feature = "number-to-chinese")]
+impl ChineseNonFinite {
+    #[inline]
+    pub(crate) const fn to_str(self, chinese_variant: ChineseVariant) -> &'static str {
+        match self {
+            Self::無窮大 => match chinese_variant {
+                ChineseVariant::Traditional => "無窮大",
+                ChineseVariant::Simple => "无穷大",
+            },
+            Self::非數 => match chinese_variant {
+                ChineseVariant::Traditional => "非數",
+                ChineseVariant::Simple => "非数",
+            },
+        }
+    }
+}
+
+#[cfg(feature = "number-to-chinese")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum ChineseApproximate {
+    多,
+    近,
+    上,
+    數,
+}
+
+#[cfg(feature = "number-to-chinese")]
+impl ChineseApproximate {
+    #[inline]
+    pub(crate) const fn to_str(self, chinese_variant: ChineseVariant) -> &'static str {
+        match self {
+            Self::多 => "多",
+            Self::近 => "近",
+            Self::上 => "上",
+            Self::數 => match chinese_variant {
+                ChineseVariant::Traditional => "數",
+                ChineseVariant::Simple => "数",
+            },
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum ChineseCurrencyUnit {
+    元,
+    圓,
+    角,
+    分,
+    毫,
+    仙,
+    整,
+    正,
+}
+
+impl ChineseCurrencyUnit {
+    #[cfg(feature = "number-to-chinese")]
+    #[inline]
+    pub(crate) const fn to_str(self, chinese_variant: ChineseVariant) -> &'static str {
+        match self {
+            Self::元 => "元",
+            Self::圓 => match chinese_variant {
+                ChineseVariant::Traditional => "圓",
+                ChineseVariant::Simple => "圆",
+            },
+            Self::角 => "角",
+            Self::分 => "分",
+            Self::毫 => "毫",
+            Self::仙 => "仙",
+            Self::整 => "整",
+            Self::正 => "正",
+        }
+    }
+
+    #[cfg(feature = "chinese-to-number")]
+    #[inline]
+    pub(crate) const fn from_char(character: char) -> Option<Self> {
+        match character {
+            '元' => Some(Self::元),
+            '圓' | '圆' => Some(Self::圓),
+            '角' => Some(Self::角),
+            '分' => Some(Self::分),
+            '毫' => Some(Self::毫),
+            '仙' => Some(Self::仙),
+            '整' => Some(Self::整),
+            '正' => Some(Self::正),
+            _ => None,
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use enum_ordinalize::Ordinalize;
 
+#[cfg(feature = "chinese-to-number")]
+use crate::ChineseExponent;
+
 /// 根據 **五經算術** 將大的單位分為 **上數** (`High`)、**中數** (`Middle`)、**下數** (`Low`) 三種類型，再加上現代使用的 **萬進** (`TenThousand`)。
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ordinalize)]
 #[ordinalize(impl_trait = false)]
@@ -14,3 +17,41 @@ pub enum ChineseCountMethod {
     /// 上數者，數窮則變。若言萬萬曰億，億億曰兆、兆兆曰京也。
     High,
 }
+
+impl ChineseCountMethod {
+    /// 逐位寫出阿拉伯數字時，由最低位開始第 `index` 個位組的位數（`index` 由 `0` 開始）。每個位組的邊界都對應到一個單位，例如 **「萬進」** 每四位一組，**「上數」** 最低的兩個位組為十六位，之後每個位組的位數都是前一個位組的兩倍。
+    #[cfg(any(feature = "chinese-to-number", feature = "number-to-chinese"))]
+    #[inline]
+    pub(crate) const fn digit_group_width(self, index: usize) -> usize {
+        match self {
+            Self::Low => {
+                if index == 0 {
+                    4
+                } else {
+                    1
+                }
+            },
+            Self::TenThousand => 4,
+            Self::Middle => 8,
+            Self::High => {
+                if index == 0 {
+                    16
+                } else if index < usize::BITS as usize - 4 {
+                    16 << (index - 1)
+                } else {
+                    usize::MAX
+                }
+            },
+        }
+    }
+
+    /// 能夠使用的最大單位。**「恆河沙」**、**「阿僧祇」**、**「那由他」**、**「不可思議」**、**「無量大數」** 只用在 **「萬進」** 和 **「中數」**，**「下數」** 和 **「上數」** 到 **「極」** 為止。
+    #[cfg(feature = "chinese-to-number")]
+    #[inline]
+    pub(crate) const fn max_exponent(self) -> ChineseExponent {
+        match self {
+            Self::Low | Self::High => ChineseExponent::極,
+            Self::TenThousand | Self::Middle => ChineseExponent::無量大數,
+        }
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(feature = "number-to-chinese")]
+use crate::{ChineseCurrencyUnit, ChineseTaiStyle, ChineseVariant};
+
+/// 寫在金額前面的貨幣名稱。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ChineseCurrency {
+    /// 人民幣，例如 **「人民幣壹萬零貳佰元整」**。
+    Renminbi,
+    /// 新臺幣，例如 **「新臺幣壹萬貳仟參佰元整」**。**「零」** 和 **「整」** 的寫法和人民幣相同。
+    NewTaiwanDollar,
+    /// 港幣，輔幣單位使用 **「毫」** 和 **「仙」**，例如 **「港幣壹佰貳拾圓伍毫」**。
+    HongKongDollar,
+}
+
+impl ChineseCurrency {
+    #[cfg(feature = "number-to-chinese")]
+    #[inline]
+    pub(crate) const fn to_str(
+        self,
+        chinese_variant: ChineseVariant,
+        tai_style: ChineseTaiStyle,
+    ) -> &'static str {
+        match self {
+            Self::Renminbi => match chinese_variant {
+                ChineseVariant::Traditional => "人民幣",
+                ChineseVariant::Simple => "人民币",
+            },
+            Self::NewTaiwanDollar => match chinese_variant {
+                ChineseVariant::Traditional => match tai_style {
+                    ChineseTaiStyle::Formal => "新臺幣",
+                    ChineseTaiStyle::Common => "新台幣",
+                },
+                ChineseVariant::Simple => "新台币",
+            },
+            Self::HongKongDollar => match chinese_variant {
+                ChineseVariant::Traditional => "港幣",
+                ChineseVariant::Simple => "港币",
+            },
+        }
+    }
+
+    /// 十分之一和百分之一的輔幣單位。
+    #[cfg(feature = "number-to-chinese")]
+    #[inline]
+    pub(crate) const fn minor_units(self) -> (ChineseCurrencyUnit, ChineseCurrencyUnit) {
+        match self {
+            Self::Renminbi | Self::NewTaiwanDollar => {
+                (ChineseCurrencyUnit::角, ChineseCurrencyUnit::分)
+            },
+            Self::HongKongDollar => (ChineseCurrencyUnit::毫, ChineseCurrencyUnit::仙),
+        }
+    }
+
+    /// 個位數是零而十分之一的輔幣單位不是零時，是否要在主要單位之後寫 **「零」**，例如 **「壹仟陸佰捌拾元零參角」**。
+    #[cfg(feature = "number-to-chinese")]
+    #[inline]
+    pub(crate) const fn zero_before_tenths(self) -> bool {
+        match self {
+            Self::Renminbi | Self::NewTaiwanDollar => true,
+            Self::HongKongDollar => false,
+        }
+    }
+
+    /// 金額寫到十分之一的輔幣單位時，是否也要在最後寫 **「整」** 或 **「正」**。
+    #[cfg(feature = "number-to-chinese")]
+    #[inline]
+    pub(crate) const fn whole_after_tenths(self) -> bool {
+        match self {
+            Self::Renminbi | Self::NewTaiwanDollar => true,
+            Self::HongKongDollar => false,
+        }
+    }
+
+    /// 如果 `chars` 以貨幣名稱開頭，回傳該貨幣和名稱的字數。
+    #[cfg(feature = "chinese-to-number")]
+    pub(crate) fn from_prefix(chars: &[char]) -> Option<(Self, usize)> {
+        const NAMES: [(ChineseCurrency, &str); 8] = [
+            (ChineseCurrency::Renminbi, "人民幣"),
+            (ChineseCurrency::Renminbi, "人民币"),
+            (ChineseCurrency::NewTaiwanDollar, "新臺幣"),
+            (ChineseCurrency::NewTaiwanDollar, "新台幣"),
+            (ChineseCurrency::NewTaiwanDollar, "新台币"),
+            (ChineseCurrency::NewTaiwanDollar, "新臺币"),
+            (ChineseCurrency::HongKongDollar, "港幣"),
+            (ChineseCurrency::HongKongDollar, "港币"),
+        ];
+
+        NAMES.iter().find_map(|&(currency, name)| {
+            let length = name.chars().count();
+
+            if chars.len() >= length && chars.iter().zip(name.chars()).all(|(&a, b)| a == b) {
+                Some((currency, length))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl Default for ChineseCurrency {
+    #[inline]
+    fn default() -> Self {
+        ChineseCurrency::Renminbi
+    }
+}
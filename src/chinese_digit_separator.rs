@@ -0,0 +1,40 @@
+/// 逐位寫出數字時，用來分隔位組的符號。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ChineseDigitSeparator {
+    /// 全形逗號 **「，」**，例如 **「一二三四，五六七八」**。
+    FullWidthComma,
+    /// 半形逗號 **「,」**，例如 **「一二三四,五六七八」**。
+    Comma,
+    /// 空格，例如 **「一二三四 五六七八」**。
+    Space,
+}
+
+impl ChineseDigitSeparator {
+    #[cfg(feature = "number-to-chinese")]
+    #[inline]
+    pub(crate) const fn to_char(self) -> char {
+        match self {
+            Self::FullWidthComma => '，',
+            Self::Comma => ',',
+            Self::Space => ' ',
+        }
+    }
+
+    #[cfg(feature = "chinese-to-number")]
+    #[inline]
+    pub(crate) const fn from_char(character: char) -> Option<Self> {
+        match character {
+            '，' => Some(Self::FullWidthComma),
+            ',' => Some(Self::Comma),
+            ' ' => Some(Self::Space),
+            _ => None,
+        }
+    }
+}
+
+impl Default for ChineseDigitSeparator {
+    #[inline]
+    fn default() -> Self {
+        ChineseDigitSeparator::FullWidthComma
+    }
+}
@@ -0,0 +1,46 @@
+use crate::ChineseExponent;
+
+/// 百分數、千分數和萬分數，以 **「百」**、**「千」** 或 **「萬」** 加上 **「分之」** 開頭。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ChinesePercent {
+    /// 百分之，例如 **「百分之十五點三」** 表示 `0.153`。
+    Percent,
+    /// 千分之，例如 **「千分之五」** 表示 `0.005`。
+    PerMille,
+    /// 萬分之，例如 **「萬分之三」** 表示 `0.0003`。
+    PerTenThousand,
+}
+
+impl ChinesePercent {
+    /// 開頭所使用的單位。
+    #[cfg(feature = "number-to-chinese")]
+    #[inline]
+    pub(crate) const fn exponent(self) -> ChineseExponent {
+        match self {
+            Self::Percent => ChineseExponent::百,
+            Self::PerMille => ChineseExponent::千,
+            Self::PerTenThousand => ChineseExponent::萬,
+        }
+    }
+
+    /// 數值要移動的十進位位數。
+    #[inline]
+    pub(crate) const fn digits(self) -> usize {
+        match self {
+            Self::Percent => 2,
+            Self::PerMille => 3,
+            Self::PerTenThousand => 4,
+        }
+    }
+
+    #[cfg(feature = "chinese-to-number")]
+    #[inline]
+    pub(crate) const fn from_exponent(exponent: ChineseExponent) -> Option<Self> {
+        match exponent {
+            ChineseExponent::百 => Some(Self::Percent),
+            ChineseExponent::千 => Some(Self::PerMille),
+            ChineseExponent::萬 => Some(Self::PerTenThousand),
+            _ => None,
+        }
+    }
+}
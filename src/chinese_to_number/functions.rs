@@ -1,71 +1,145 @@
-use alloc::vec::Vec;
-use core::cmp::Ordering;
+use alloc::{string::String, vec::Vec};
+use core::{cmp::Ordering, ops::Div, str::FromStr};
 
+#[cfg(feature = "bigint")]
+use num_bigint::{BigInt, BigUint};
 #[cfg(not(feature = "std"))]
 #[allow(unused_imports)]
 use num_traits::float::FloatCore;
+use num_traits::{checked_pow, CheckedAdd, CheckedMul, One, Zero};
 
 use crate::{
-    ChineseCountMethod, ChineseExponent, ChineseNumber, ChineseSign, ChineseToNumberError,
+    ChineseCountMethod, ChineseCurrency, ChineseCurrencyUnit, ChineseDigitSeparator,
+    ChineseExponent, ChineseFraction, ChineseNumber, ChineseOrdinal, ChinesePercent, ChinesePoint,
+    ChineseSign, ChineseToNumberError,
 };
 
+/// 能夠被中文數字解析成的無號整數型別。
+pub(crate) trait UnsignedInteger:
+    Clone + Zero + One + CheckedAdd + CheckedMul + Div<Output = Self> + From<u32> {
+}
+
+impl<N: Clone + Zero + One + CheckedAdd + CheckedMul + Div<Output = N> + From<u32>> UnsignedInteger
+    for N
+{
+}
+
 #[inline]
 pub(crate) fn to_chars_vec<S: AsRef<str>>(s: S) -> Vec<char> {
     s.as_ref().chars().filter(|c| !c.is_whitespace()).collect()
 }
 
-fn get_exp_base(
+/// 去掉序數開頭的 **「第」**，回傳剩下的字串。序數必須是大於或等於 `1` 的整數，不能有正負號或小數部分，和寫出時的限制相同。
+pub(crate) fn strip_ordinal(
+    method: ChineseCountMethod,
+    s: &str,
+) -> Result<&str, ChineseToNumberError> {
+    let s = s.trim_start();
+
+    let mut chars = s.chars();
+
+    match chars.next() {
+        Some(c) => match ChineseOrdinal::from_char(c) {
+            Some(_) => {
+                let rest = chars.as_str();
+                let chars = to_chars_vec(rest);
+
+                if ChineseSign::from_prefix(&chars).is_some() {
+                    return Err(ChineseToNumberError::ChineseNumberIncorrect {
+                        char_index: 1
+                    });
+                }
+
+                // 先以整數解析一次來排除零和小數，超出 `u128` 範圍的數值一定是正整數
+                match chinese_to_unsigned_integer::<u128>(method, &chars) {
+                    Ok(0) => Err(ChineseToNumberError::ChineseNumberIncorrect {
+                        char_index: 1
+                    }),
+                    Ok(_) | Err(ChineseToNumberError::Overflow) => Ok(rest),
+                    Err(ChineseToNumberError::ChineseNumberIncorrect {
+                        char_index,
+                    }) => Err(ChineseToNumberError::ChineseNumberIncorrect {
+                        char_index: char_index + 1,
+                    }),
+                    Err(error) => Err(error),
+                }
+            },
+            None => Err(ChineseToNumberError::ChineseNumberIncorrect {
+                char_index: 0
+            }),
+        },
+        None => Err(ChineseToNumberError::ChineseNumberEmpty),
+    }
+}
+
+/// 找出結尾位於 `chars[end]` 且能在算術類型中使用的單位，回傳單位和它開頭的索引。
+#[inline]
+fn exponent_ending_at(
+    method: ChineseCountMethod,
+    chars: &[char],
+    end: usize,
+) -> Option<(ChineseExponent, usize)> {
+    ChineseExponent::from_chars_ending_at(chars, end)
+        .filter(|&(exponent, _)| exponent <= method.max_exponent())
+}
+
+fn get_exp_base<N: UnsignedInteger>(
     method: ChineseCountMethod,
     exp: ChineseExponent,
-) -> Result<u128, ChineseToNumberError> {
+) -> Result<N, ChineseToNumberError> {
     match method {
         ChineseCountMethod::Low => match exp {
-            ChineseExponent::個 => Ok(1),
+            ChineseExponent::個 => Ok(N::one()),
             _ => {
                 debug_assert!(exp > ChineseExponent::個);
 
-                Ok(10u128.pow((exp.ordinal() - ChineseExponent::個.ordinal()) as u32))
+                checked_pow(N::from(10), (exp.ordinal() - ChineseExponent::個.ordinal()) as usize)
+                    .ok_or(ChineseToNumberError::Overflow)
             },
         },
         ChineseCountMethod::TenThousand => match exp {
-            ChineseExponent::個 => Ok(1),
-            ChineseExponent::十 => Ok(10),
-            ChineseExponent::百 => Ok(100),
-            ChineseExponent::千 => Ok(1000),
+            ChineseExponent::個 => Ok(N::one()),
+            ChineseExponent::十 => Ok(N::from(10)),
+            ChineseExponent::百 => Ok(N::from(100)),
+            ChineseExponent::千 => Ok(N::from(1000)),
             _ => {
                 debug_assert!(exp > ChineseExponent::千);
 
-                1_0000u128
-                    .checked_pow((exp.ordinal() - ChineseExponent::千.ordinal()) as u32)
-                    .ok_or(ChineseToNumberError::Overflow)
+                checked_pow(
+                    N::from(1_0000),
+                    (exp.ordinal() - ChineseExponent::千.ordinal()) as usize,
+                )
+                .ok_or(ChineseToNumberError::Overflow)
             },
         },
         ChineseCountMethod::Middle => match exp {
-            ChineseExponent::個 => Ok(1),
-            ChineseExponent::十 => Ok(10),
-            ChineseExponent::百 => Ok(100),
-            ChineseExponent::千 => Ok(1000),
-            ChineseExponent::萬 => Ok(1_0000),
+            ChineseExponent::個 => Ok(N::one()),
+            ChineseExponent::十 => Ok(N::from(10)),
+            ChineseExponent::百 => Ok(N::from(100)),
+            ChineseExponent::千 => Ok(N::from(1000)),
+            ChineseExponent::萬 => Ok(N::from(1_0000)),
             _ => {
                 debug_assert!(exp > ChineseExponent::萬);
 
-                1_0000_0000u128
-                    .checked_pow((exp.ordinal() - ChineseExponent::萬.ordinal()) as u32)
-                    .ok_or(ChineseToNumberError::Overflow)
+                checked_pow(
+                    N::from(1_0000_0000),
+                    (exp.ordinal() - ChineseExponent::萬.ordinal()) as usize,
+                )
+                .ok_or(ChineseToNumberError::Overflow)
             },
         },
         ChineseCountMethod::High => match exp {
-            ChineseExponent::個 => Ok(1),
-            ChineseExponent::十 => Ok(10),
-            ChineseExponent::百 => Ok(100),
-            ChineseExponent::千 => Ok(1000),
+            ChineseExponent::個 => Ok(N::one()),
+            ChineseExponent::十 => Ok(N::from(10)),
+            ChineseExponent::百 => Ok(N::from(100)),
+            ChineseExponent::千 => Ok(N::from(1000)),
             _ => {
                 debug_assert!(exp > ChineseExponent::千);
 
-                let mut w = 1_0000u128;
+                let mut w = N::from(1_0000);
 
                 for _ in 0..exp.ordinal() - ChineseExponent::萬.ordinal() {
-                    w = w.checked_pow(2).ok_or(ChineseToNumberError::Overflow)?;
+                    w = w.checked_mul(&w).ok_or(ChineseToNumberError::Overflow)?;
                 }
 
                 Ok(w)
@@ -74,34 +148,34 @@ fn get_exp_base(
     }
 }
 
-pub(crate) fn chinese_to_unsigned_integer_unit(
+pub(crate) fn chinese_to_unsigned_integer_unit<N: UnsignedInteger>(
     method: ChineseCountMethod,
     chars: &[char],
     mut pointer: usize,
     level: ChineseExponent,
-) -> Result<(u128, Option<(usize, ChineseExponent)>), ChineseToNumberError> {
+) -> Result<(N, Option<(usize, ChineseExponent)>), ChineseToNumberError> {
     debug_assert!(!chars.is_empty() && pointer < chars.len());
 
-    let base = get_exp_base(method, level)?;
+    let base: N = get_exp_base(method, level)?;
 
     let (n, exp) = match ChineseNumber::from_char(chars[pointer]) {
         Some(n) if n == ChineseNumber::十 => {
             if pointer == 0 {
                 return Ok((
-                    (n.ordinal() as u128)
-                        .checked_mul(base)
+                    N::from(n.ordinal() as u32)
+                        .checked_mul(&base)
                         .ok_or(ChineseToNumberError::Overflow)?,
                     None,
                 ));
             }
 
-            (0u128, ChineseExponent::十)
+            (N::zero(), ChineseExponent::十)
         },
         Some(n) => {
             if pointer == 0 {
                 return Ok((
-                    (n.ordinal() as u128)
-                        .checked_mul(base)
+                    N::from(n.ordinal() as u32)
+                        .checked_mul(&base)
                         .ok_or(ChineseToNumberError::Overflow)?,
                     None,
                 ));
@@ -110,13 +184,15 @@ pub(crate) fn chinese_to_unsigned_integer_unit(
             pointer -= 1;
 
             loop {
-                match ChineseExponent::from_char(chars[pointer]) {
-                    Some(exp) if exp > ChineseExponent::個 => {
+                match exponent_ending_at(method, chars, pointer) {
+                    Some((exp, start)) if exp > ChineseExponent::個 => {
+                        pointer = start;
+
                         if pointer == 0 {
                             if exp == ChineseExponent::十 {
                                 return Ok((
-                                    ((10 + n.ordinal()) as u128)
-                                        .checked_mul(base)
+                                    N::from((10 + n.ordinal()) as u32)
+                                        .checked_mul(&base)
                                         .ok_or(ChineseToNumberError::Overflow)?,
                                     None,
                                 ));
@@ -127,14 +203,14 @@ pub(crate) fn chinese_to_unsigned_integer_unit(
                             }
                         }
 
-                        break (n.ordinal() as u128, exp);
+                        break (N::from(n.ordinal() as u32), exp);
                     },
                     _ => match ChineseNumber::from_char(chars[pointer]) {
                         Some(ChineseNumber::零) => {
                             if pointer == 0 {
                                 return Ok((
-                                    (n.ordinal() as u128)
-                                        .checked_mul(base)
+                                    N::from(n.ordinal() as u32)
+                                        .checked_mul(&base)
                                         .ok_or(ChineseToNumberError::Overflow)?,
                                     None,
                                 ));
@@ -160,8 +236,12 @@ pub(crate) fn chinese_to_unsigned_integer_unit(
                 });
             }
 
-            match ChineseExponent::from_char(chars[pointer]) {
-                Some(exp) if exp < level => (0u128, exp),
+            match exponent_ending_at(method, chars, pointer) {
+                Some((exp, start)) if start > 0 && exp > ChineseExponent::個 && exp < level => {
+                    pointer = start;
+
+                    (N::zero(), exp)
+                },
                 _ => {
                     return Err(ChineseToNumberError::ChineseNumberIncorrect {
                         char_index: pointer,
@@ -180,9 +260,10 @@ pub(crate) fn chinese_to_unsigned_integer_unit(
                 break;
             },
             Ordering::Less => {
-                let result = chinese_to_unsigned_integer_unit(method, chars, pointer - 1, exp)?;
+                let result: (N, _) =
+                    chinese_to_unsigned_integer_unit(method, chars, pointer - 1, exp)?;
 
-                sum = sum.checked_add(result.0).ok_or(ChineseToNumberError::Overflow)?;
+                sum = sum.checked_add(&result.0).ok_or(ChineseToNumberError::Overflow)?;
 
                 next = result.1;
             },
@@ -194,15 +275,15 @@ pub(crate) fn chinese_to_unsigned_integer_unit(
         }
     }
 
-    sum = sum.checked_mul(base).ok_or(ChineseToNumberError::Overflow)?;
+    sum = sum.checked_mul(&base).ok_or(ChineseToNumberError::Overflow)?;
 
     Ok((sum, next))
 }
 
-pub(crate) fn chinese_to_unsigned_integer(
+pub(crate) fn chinese_to_unsigned_integer<N: UnsignedInteger>(
     method: ChineseCountMethod,
     chars: &[char],
-) -> Result<u128, ChineseToNumberError> {
+) -> Result<N, ChineseToNumberError> {
     let length = chars.len();
 
     if length == 0 {
@@ -211,11 +292,13 @@ pub(crate) fn chinese_to_unsigned_integer(
 
     let mut pointer = length - 1;
 
-    let mut exp = match ChineseExponent::from_char(chars[pointer]) {
-        Some(exp) if exp > ChineseExponent::個 => {
+    let mut exp = match exponent_ending_at(method, chars, pointer) {
+        Some((exp, start)) if exp > ChineseExponent::個 => {
+            pointer = start;
+
             if pointer == 0 {
                 if exp == ChineseExponent::十 {
-                    return Ok(10);
+                    return Ok(N::from(10));
                 } else {
                     return Err(ChineseToNumberError::ChineseNumberIncorrect {
                         char_index: pointer,
@@ -234,26 +317,29 @@ pub(crate) fn chinese_to_unsigned_integer(
                 if let Some(ChineseNumber::零) = ChineseNumber::from_char(left_char) {
                     // do nothing
                 } else {
-                    match ChineseExponent::from_char(left_char) {
+                    match exponent_ending_at(method, chars, pointer - 1).map(|(exp, _)| exp) {
                         Some(exp) if exp >= ChineseExponent::百 => {
-                            let high = chinese_to_unsigned_integer(method, &chars[..pointer])?;
-
-                            let low =
-                                chinese_to_unsigned_integer_unit(method, &chars[pointer..], 0, exp)
-                                    .map_err(|mut err| {
-                                        if let ChineseToNumberError::ChineseNumberIncorrect {
-                                            char_index,
-                                        } = &mut err
-                                        {
-                                            *char_index = pointer;
-                                        }
-
-                                        err
-                                    })?
-                                    .0
-                                    / 10;
-
-                            return high.checked_add(low).ok_or(ChineseToNumberError::Overflow);
+                            let high: N = chinese_to_unsigned_integer(method, &chars[..pointer])?;
+
+                            let low: N = chinese_to_unsigned_integer_unit::<N>(
+                                method,
+                                &chars[pointer..],
+                                0,
+                                exp,
+                            )
+                            .map_err(|mut err| {
+                                if let ChineseToNumberError::ChineseNumberIncorrect {
+                                    char_index,
+                                } = &mut err
+                                {
+                                    *char_index = pointer;
+                                }
+
+                                err
+                            })?
+                            .0 / N::from(10);
+
+                            return high.checked_add(&low).ok_or(ChineseToNumberError::Overflow);
                         },
                         _ => (),
                     }
@@ -265,12 +351,12 @@ pub(crate) fn chinese_to_unsigned_integer(
         },
     };
 
-    let mut sum = 0u128;
+    let mut sum = N::zero();
 
     loop {
-        let result = chinese_to_unsigned_integer_unit(method, chars, pointer - 1, exp)?;
+        let result: (N, _) = chinese_to_unsigned_integer_unit(method, chars, pointer - 1, exp)?;
 
-        sum = sum.checked_add(result.0).ok_or(ChineseToNumberError::Overflow)?;
+        sum = sum.checked_add(&result.0).ok_or(ChineseToNumberError::Overflow)?;
 
         if let Some((p, e)) = result.1 {
             pointer = p;
@@ -295,14 +381,9 @@ pub(crate) fn chinese_to_signed_integer(
         return Err(ChineseToNumberError::ChineseNumberEmpty);
     }
 
-    let c = chars[0];
-
-    let (sign, offset) = match ChineseSign::from_char(c) {
-        Some(sign) => (sign, 1),
-        None => (ChineseSign::正, 0),
-    };
+    let (sign, offset) = ChineseSign::from_prefix(chars).unwrap_or((ChineseSign::正, 0));
 
-    let uint = match chinese_to_unsigned_integer(method, &chars[offset..]) {
+    let uint: u128 = match chinese_to_unsigned_integer(method, &chars[offset..]) {
         Ok(n) => n,
         Err(error) => {
             return match error {
@@ -343,6 +424,116 @@ pub(crate) fn chinese_to_signed_integer(
     }
 }
 
+/// 解析分母在前的分數，例如 **「三分之一」**，**「分」** 可以省略，分母不能是零。回傳是否為負數，以及分子和分母的絕對值。`parse` 用來解析分子和分母。
+pub(crate) fn chinese_to_fraction(
+    chars: &[char],
+    parse: impl Fn(&[char]) -> Result<u128, ChineseToNumberError>,
+) -> Result<(bool, u128, u128), ChineseToNumberError> {
+    let length = chars.len();
+
+    if length == 0 {
+        return Err(ChineseToNumberError::ChineseNumberEmpty);
+    }
+
+    let (sign, offset) = ChineseSign::from_prefix(chars).unwrap_or((ChineseSign::正, 0));
+
+    let index = match chars[offset..].iter().position(|&c| ChineseFraction::from_char(c).is_some())
+    {
+        Some(i) => i + offset,
+        None => {
+            return Err(ChineseToNumberError::ChineseNumberIncorrect {
+                char_index: offset
+            })
+        },
+    };
+
+    let denominator_end = if index > offset
+        && ChineseExponent::from_char(chars[index - 1]) == Some(ChineseExponent::分)
+    {
+        index - 1
+    } else {
+        index
+    };
+
+    let map_error = |error, start: usize, separator: usize| match error {
+        ChineseToNumberError::ChineseNumberEmpty => ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: separator,
+        },
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index,
+        } => ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: char_index + start
+        },
+        _ => error,
+    };
+
+    // 分母是 10 的次方時可以省略開頭的 **「一」**，例如 **「百分之五」**、**「百萬分之一」**
+    let denominator = match chars[offset..denominator_end] {
+        [c, ..] if ChineseExponent::from_char(c).map_or(false, |e| e > ChineseExponent::十) => {
+            let mut denominator = Vec::with_capacity(denominator_end - offset + 1);
+
+            denominator.push('一');
+            denominator.extend_from_slice(&chars[offset..denominator_end]);
+
+            parse(&denominator).map_err(|error| match error {
+                ChineseToNumberError::ChineseNumberIncorrect {
+                    char_index,
+                } => ChineseToNumberError::ChineseNumberIncorrect {
+                    char_index: char_index.saturating_sub(1),
+                },
+                _ => error,
+            })
+        },
+        ref denominator => parse(denominator),
+    }
+    .map_err(|error| map_error(error, offset, denominator_end))?;
+
+    if denominator == 0 {
+        return Err(ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: offset
+        });
+    }
+
+    let numerator =
+        parse(&chars[(index + 1)..]).map_err(|error| match map_error(error, index + 1, index) {
+            ChineseToNumberError::Overflow if sign == ChineseSign::負 => {
+                ChineseToNumberError::Underflow
+            },
+            error => error,
+        })?;
+
+    Ok((sign == ChineseSign::負, numerator, denominator))
+}
+
+#[cfg(feature = "bigint")]
+pub(crate) fn chinese_to_big_signed_integer(
+    method: ChineseCountMethod,
+    chars: &[char],
+) -> Result<BigInt, ChineseToNumberError> {
+    let length = chars.len();
+
+    if length == 0 {
+        return Err(ChineseToNumberError::ChineseNumberEmpty);
+    }
+
+    let (sign, offset) = ChineseSign::from_prefix(chars).unwrap_or((ChineseSign::正, 0));
+
+    let uint: BigUint =
+        chinese_to_unsigned_integer(method, &chars[offset..]).map_err(|error| match error {
+            ChineseToNumberError::ChineseNumberIncorrect {
+                char_index: index,
+            } => ChineseToNumberError::ChineseNumberIncorrect {
+                char_index: index + offset
+            },
+            _ => error,
+        })?;
+
+    match sign {
+        ChineseSign::正 => Ok(BigInt::from(uint)),
+        ChineseSign::負 => Ok(-BigInt::from(uint)),
+    }
+}
+
 // TODO f64
 
 fn get_exp_base_f64(method: ChineseCountMethod, exp: ChineseExponent) -> f64 {
@@ -423,8 +614,10 @@ pub(crate) fn chinese_to_f64_unit(
             pointer -= 1;
 
             loop {
-                match ChineseExponent::from_char(chars[pointer]) {
-                    Some(exp) if exp > ChineseExponent::個 => {
+                match exponent_ending_at(method, chars, pointer) {
+                    Some((exp, start)) if exp > ChineseExponent::個 => {
+                        pointer = start;
+
                         if pointer == 0 {
                             if exp == ChineseExponent::十 {
                                 return Ok((((10 + n.ordinal()) as f64) * base, None));
@@ -463,8 +656,12 @@ pub(crate) fn chinese_to_f64_unit(
                 });
             }
 
-            match ChineseExponent::from_char(chars[pointer]) {
-                Some(exp) if exp < level => (0f64, exp),
+            match exponent_ending_at(method, chars, pointer) {
+                Some((exp, start)) if start > 0 && exp > ChineseExponent::個 && exp < level => {
+                    pointer = start;
+
+                    (0f64, exp)
+                },
                 _ => {
                     return Err(ChineseToNumberError::ChineseNumberIncorrect {
                         char_index: pointer,
@@ -514,8 +711,10 @@ pub(crate) fn chinese_to_unsigned_f64(
 
     let mut pointer = length - 1;
 
-    let mut exp = match ChineseExponent::from_char(chars[pointer]) {
-        Some(exp) if exp > ChineseExponent::個 => {
+    let mut exp = match exponent_ending_at(method, chars, pointer) {
+        Some((exp, start)) if exp > ChineseExponent::個 => {
+            pointer = start;
+
             if pointer == 0 {
                 if exp == ChineseExponent::十 {
                     return Ok(10f64);
@@ -564,89 +763,564 @@ pub(crate) fn chinese_to_f64(
         return Err(ChineseToNumberError::ChineseNumberEmpty);
     }
 
+    let (sign, offset) = ChineseSign::from_prefix(chars).unwrap_or((ChineseSign::正, 0));
+
     let mut end = length - 1;
     let mut fraction = 0.00;
 
-    if let Some(ChineseExponent::分) = ChineseExponent::from_char(chars[end]) {
-        if end == 0 {
+    // 整數部分是否已經被小數部分的單位用完，例如「負三角」
+    let mut consumed = false;
+
+    if let Some(point) = chars.iter().position(|&c| ChinesePoint::from_char(c).is_some()) {
+        if point == offset || point == end {
             return Err(ChineseToNumberError::ChineseNumberIncorrect {
-                char_index: end
+                char_index: point
             });
         }
 
-        end -= 1;
+        let mut numerator = 0f64;
+        let mut digits = 0i32;
 
-        match ChineseNumber::from_char(chars[end]) {
-            Some(n) if n != ChineseNumber::十 => {
-                fraction += n.ordinal() as f64 * 0.01;
+        for (i, &char) in chars[point + 1..].iter().enumerate() {
+            match ChineseNumber::from_char(char) {
+                Some(n) if n != ChineseNumber::十 => {
+                    numerator = numerator * 10.0 + n.ordinal() as f64;
+                    digits += 1;
+                },
+                _ => {
+                    return Err(ChineseToNumberError::ChineseNumberIncorrect {
+                        char_index: point + 1 + i,
+                    });
+                },
+            }
+        }
 
-                if end == 0 {
-                    return Ok(fraction);
-                }
+        fraction = numerator / 10f64.powi(digits);
 
-                end -= 1;
+        end = point - 1;
+    } else {
+        // 港幣使用「毫」和「仙」代替「角」和「分」
+        let fraction_unit = |i: usize| match ChineseCurrencyUnit::from_char(chars[i]) {
+            Some(ChineseCurrencyUnit::仙) => Some(ChineseExponent::分),
+            _ => match ChineseExponent::from_chars_ending_at(chars, i) {
+                Some((exponent, _)) if exponent < ChineseExponent::個 => Some(exponent),
+                _ => None,
             },
-            _ => {
+        };
+        let is_yuan = |c: char| {
+            matches!(
+                ChineseCurrencyUnit::from_char(c),
+                Some(ChineseCurrencyUnit::元) | Some(ChineseCurrencyUnit::圓)
+            )
+        };
+
+        // 以最小的單位累計係數，最後再一次換算，以免累積浮點數的誤差
+        let mut numerator = 0u64;
+        let mut smallest: Option<ChineseExponent> = None;
+        let mut last: Option<ChineseExponent> = None;
+
+        while let Some(mut exponent) = fraction_unit(end) {
+            // 「毫」的係數緊接在「元」或「圓」之後時才是港幣的「毫」，例如「三圓五毫」，否則是「厘」以下的「毫」
+            if exponent == ChineseExponent::毫 && end >= 2 && is_yuan(chars[end - 2]) {
+                exponent = ChineseExponent::角;
+            }
+
+            if end <= offset || last.map_or(false, |last| exponent <= last) {
                 return Err(ChineseToNumberError::ChineseNumberIncorrect {
                     char_index: end
                 });
-            },
-        }
-    }
+            }
 
-    if let Some(ChineseExponent::角) = ChineseExponent::from_char(chars[end]) {
-        if end == 0 {
-            return Err(ChineseToNumberError::ChineseNumberIncorrect {
-                char_index: end
-            });
-        }
+            end -= 1;
 
-        end -= 1;
+            match ChineseNumber::from_char(chars[end]) {
+                Some(n) if n != ChineseNumber::十 => {
+                    let smallest = *smallest.get_or_insert(exponent);
 
-        match ChineseNumber::from_char(chars[end]) {
-            Some(n) if n != ChineseNumber::十 => {
-                fraction += n.ordinal() as f64 * 0.1;
+                    numerator += n.ordinal() as u64
+                        * 10u64.pow((exponent.ordinal() - smallest.ordinal()) as u32);
 
-                if end == 0 {
-                    return Ok(fraction);
-                }
+                    last = Some(exponent);
+                },
+                _ => {
+                    return Err(ChineseToNumberError::ChineseNumberIncorrect {
+                        char_index: end
+                    });
+                },
+            }
 
-                end -= 1;
-            },
-            _ => {
+            if end == offset {
+                consumed = true;
+
+                break;
+            }
+
+            end -= 1;
+        }
+
+        if let Some(smallest) = smallest {
+            fraction = numerator as f64
+                / 10f64.powi((ChineseExponent::個.ordinal() - smallest.ordinal()) as i32);
+        }
+
+        // 整數部分之後的「元」或「圓」，例如「三圓五毫」、「三元零五分」
+        if !consumed
+            && smallest.is_some()
+            && end > offset
+            && ChineseNumber::from_char(chars[end]) == Some(ChineseNumber::零)
+            && is_yuan(chars[end - 1])
+        {
+            end -= 1;
+        }
+
+        if !consumed && is_yuan(chars[end]) {
+            if end == offset {
                 return Err(ChineseToNumberError::ChineseNumberIncorrect {
                     char_index: end
                 });
+            }
+
+            end -= 1;
+        }
+    }
+
+    let f = if consumed {
+        fraction
+    } else {
+        match chinese_to_unsigned_f64(method, &chars[offset..=end]) {
+            Ok(n) => n + fraction,
+            Err(error) => {
+                return match error {
+                    ChineseToNumberError::ChineseNumberIncorrect {
+                        char_index: index,
+                    } => Err(ChineseToNumberError::ChineseNumberIncorrect {
+                        char_index: index + offset,
+                    }),
+                    ChineseToNumberError::Overflow if sign == ChineseSign::負 => {
+                        Err(ChineseToNumberError::Underflow)
+                    },
+                    _ => Err(error),
+                };
             },
         }
+    };
+
+    match sign {
+        ChineseSign::正 => Ok(f),
+        ChineseSign::負 => Ok(-f),
     }
+}
 
-    let c = chars[0];
+/// 將以 **「百分之」**、**「千分之」** 或 **「萬分之」** 開頭的中文數字轉成移動小數點之後的數值，正負號要寫在最前面。**「分之」** 之後只能是整數或使用 **「點」** 的小數。
+pub(crate) fn chinese_to_percent(
+    method: ChineseCountMethod,
+    chars: &[char],
+) -> Result<f64, ChineseToNumberError> {
+    let length = chars.len();
+
+    if length == 0 {
+        return Err(ChineseToNumberError::ChineseNumberEmpty);
+    }
 
-    let (sign, offset) = match ChineseSign::from_char(c) {
-        Some(sign) => (sign, 1),
-        None => (ChineseSign::正, 0),
+    let (sign, offset) = ChineseSign::from_prefix(chars).unwrap_or((ChineseSign::正, 0));
+
+    let percent = match chars.get(offset).copied().and_then(ChineseExponent::from_char) {
+        Some(exponent) => ChinesePercent::from_exponent(exponent),
+        None => None,
     };
 
-    let f = match chinese_to_unsigned_f64(method, &chars[offset..=end]) {
-        Ok(n) => n + fraction,
-        Err(error) => {
-            return match error {
-                ChineseToNumberError::ChineseNumberIncorrect {
-                    char_index: index,
-                } => Err(ChineseToNumberError::ChineseNumberIncorrect {
-                    char_index: index + offset,
-                }),
-                ChineseToNumberError::Overflow if sign == ChineseSign::負 => {
-                    Err(ChineseToNumberError::Underflow)
-                },
-                _ => Err(error),
-            };
+    let percent = match percent {
+        Some(percent)
+            if chars.get(offset + 1).copied().and_then(ChineseExponent::from_char)
+                == Some(ChineseExponent::分)
+                && chars
+                    .get(offset + 2)
+                    .copied()
+                    .and_then(ChineseFraction::from_char)
+                    .is_some() =>
+        {
+            percent
         },
+        _ => {
+            return Err(ChineseToNumberError::ChineseNumberIncorrect {
+                char_index: offset
+            })
+        },
+    };
+
+    let start = offset + 3;
+
+    if start == length {
+        return Err(ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: start - 1
+        });
+    }
+
+    if ChineseSign::from_prefix(&chars[start..]).is_some() {
+        return Err(ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: start
+        });
+    }
+
+    // 只接受整數和使用「點」的小數，和寫出時的形式相同
+    let body = &chars[start..];
+
+    let f = if body.iter().any(|&c| ChinesePoint::from_char(c).is_some()) {
+        chinese_to_f64(method, body)
+    } else {
+        chinese_to_unsigned_f64(method, body)
     };
 
+    let f = f.map_err(|error| match error {
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index,
+        } => ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: char_index + start
+        },
+        ChineseToNumberError::Overflow if sign == ChineseSign::負 => {
+            ChineseToNumberError::Underflow
+        },
+        _ => error,
+    })?;
+
+    let f = f / 10f64.powi(percent.digits() as i32);
+
     match sign {
         ChineseSign::正 => Ok(f),
         ChineseSign::負 => Ok(-f),
     }
 }
+
+/// 將依照算術類型以 `ChineseDigitSeparator` 的符號分成位組的阿拉伯數字轉成數值，例如 **「萬進」** 的 **「12,3456,7890」**。每個位組的位數都必須符合算術類型，且整個數字只能使用同一種分隔符號。
+pub(crate) fn grouped_digits_to_number<T: FromStr>(
+    method: ChineseCountMethod,
+    chars: &[char],
+) -> Result<T, ChineseToNumberError> {
+    let length = chars.len();
+
+    if length == 0 {
+        return Err(ChineseToNumberError::ChineseNumberEmpty);
+    }
+
+    let (negative, offset) = match chars[0] {
+        '-' => (true, 1),
+        '+' => (false, 1),
+        _ => (false, 0),
+    };
+
+    let point = chars[offset..].iter().position(|&c| c == '.').map(|i| i + offset);
+    let end = point.unwrap_or(length);
+
+    if end == offset {
+        return Err(ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: if point.is_some() { offset } else { offset - 1 },
+        });
+    }
+
+    let mut separator = None;
+    let mut group_index = 0;
+    let mut count = 0;
+
+    for char_index in (offset..end).rev() {
+        let c = chars[char_index];
+
+        let error = ChineseToNumberError::ChineseNumberIncorrect {
+            char_index,
+        };
+
+        if c.is_ascii_digit() {
+            count += 1;
+
+            // 位組的位數過多，表示少了分隔符號
+            if count > method.digit_group_width(group_index) {
+                return Err(error);
+            }
+        } else if let Some(s) = ChineseDigitSeparator::from_char(c) {
+            if count != method.digit_group_width(group_index)
+                || separator.map_or(false, |separator| separator != s)
+            {
+                return Err(error);
+            }
+
+            separator = Some(s);
+            group_index += 1;
+            count = 0;
+        } else {
+            return Err(error);
+        }
+    }
+
+    // 最高的位組不能是空的，也不能補零，只有整數部分就是零時才能以「0」開頭
+    if count == 0 || (chars[offset] == '0' && end - offset > 1) {
+        return Err(ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: offset
+        });
+    }
+
+    let mut s = String::with_capacity(length);
+
+    s.extend(chars[offset..end].iter().filter(|c| c.is_ascii_digit()));
+
+    if let Some(point) = point {
+        let fraction = &chars[(point + 1)..];
+
+        if fraction.is_empty() {
+            return Err(ChineseToNumberError::ChineseNumberIncorrect {
+                char_index: point
+            });
+        }
+
+        if let Some(i) = fraction.iter().position(|c| !c.is_ascii_digit()) {
+            return Err(ChineseToNumberError::ChineseNumberIncorrect {
+                char_index: point + 1 + i
+            });
+        }
+
+        s.push('.');
+        s.extend(fraction);
+    }
+
+    // 負零視為零，讓無號整數也能解析
+    let negative = negative && s.chars().any(|c| c != '0' && c != '.');
+
+    if negative {
+        s.insert(0, '-');
+    }
+
+    // 語法已經檢查過，無法解析時只可能是超出範圍，或是數值型別不接受小數
+    s.parse().map_err(|_| match point {
+        Some(point) => ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: point
+        },
+        None if negative => ChineseToNumberError::Underflow,
+        None => ChineseToNumberError::Overflow,
+    })
+}
+
+/// 將票據上所使用的中文金額轉成以分為單位的金額，例如 **「人民幣壹萬零貳佰元整」**、**「新臺幣壹萬貳仟參佰元整」**、**「港幣壹佰貳拾圓伍毫」**。開頭可以有貨幣名稱，結尾可以有 **「整」** 或 **「正」**，**「元」** 之後可以有一個 **「零」**。整數部分使用 **「萬進」**。
+pub(crate) fn chinese_to_currency(chars: &[char]) -> Result<u128, ChineseToNumberError> {
+    let length = chars.len();
+
+    if length == 0 {
+        return Err(ChineseToNumberError::ChineseNumberEmpty);
+    }
+
+    let start = ChineseCurrency::from_prefix(chars).map_or(0, |(_, length)| length);
+
+    let end = match ChineseCurrencyUnit::from_char(chars[length - 1]) {
+        Some(ChineseCurrencyUnit::整) | Some(ChineseCurrencyUnit::正) => length - 1,
+        _ => length,
+    };
+
+    if start >= end {
+        return Err(ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: start.min(length - 1),
+        });
+    }
+
+    let yuan_index = chars[start..end]
+        .iter()
+        .position(|&c| {
+            matches!(
+                ChineseCurrencyUnit::from_char(c),
+                Some(ChineseCurrencyUnit::元) | Some(ChineseCurrencyUnit::圓)
+            )
+        })
+        .map(|i| i + start);
+
+    let (mut amount, mut pointer) = match yuan_index {
+        Some(yuan_index) => {
+            if yuan_index == start {
+                return Err(ChineseToNumberError::ChineseNumberIncorrect {
+                    char_index: yuan_index,
+                });
+            }
+
+            let yuan: u128 = chinese_to_unsigned_integer(
+                ChineseCountMethod::TenThousand,
+                &chars[start..yuan_index],
+            )
+            .map_err(|error| match error {
+                ChineseToNumberError::ChineseNumberIncorrect {
+                    char_index,
+                } => ChineseToNumberError::ChineseNumberIncorrect {
+                    char_index: char_index + start,
+                },
+                _ => error,
+            })?;
+
+            let mut pointer = yuan_index + 1;
+
+            // 元位或角位是零時，「元」之後的「零」
+            if pointer + 1 < end
+                && ChineseNumber::from_char(chars[pointer]) == Some(ChineseNumber::零)
+            {
+                pointer += 1;
+            }
+
+            (yuan.checked_mul(100).ok_or(ChineseToNumberError::Overflow)?, pointer)
+        },
+        None => (0, start),
+    };
+
+    // 港幣使用「毫」和「仙」代替「角」和「分」
+    for (units, scale) in [
+        ([ChineseCurrencyUnit::角, ChineseCurrencyUnit::毫], 10u8),
+        ([ChineseCurrencyUnit::分, ChineseCurrencyUnit::仙], 1),
+    ] {
+        if pointer + 1 < end
+            && ChineseCurrencyUnit::from_char(chars[pointer + 1])
+                .map_or(false, |unit| units.contains(&unit))
+        {
+            let d = match ChineseNumber::from_char(chars[pointer]) {
+                Some(number) if number != ChineseNumber::十 => number.ordinal(),
+                _ => {
+                    return Err(ChineseToNumberError::ChineseNumberIncorrect {
+                        char_index: pointer,
+                    })
+                },
+            };
+
+            amount =
+                amount.checked_add((d * scale) as u128).ok_or(ChineseToNumberError::Overflow)?;
+            pointer += 2;
+        }
+    }
+
+    if pointer != end {
+        return Err(ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: pointer
+        });
+    }
+
+    Ok(amount)
+}
+
+/// 將日文的漢數字轉成整數，回傳是否為負數和絕對值，例如 **「千二百三万四千五」**。**「十」**、**「百」**、**「千」** 前的 **「一」** 可以省略，數字中間不寫零，大的單位使用 **「萬進」**。
+pub(crate) fn japanese_to_integer(chars: &[char]) -> Result<(bool, u128), ChineseToNumberError> {
+    let (sign, offset) = ChineseSign::from_prefix(chars).unwrap_or((ChineseSign::正, 0));
+
+    let negative = sign == ChineseSign::負;
+
+    let magnitude =
+        japanese_to_unsigned_integer(&chars[offset..]).map_err(|error| match error {
+            ChineseToNumberError::ChineseNumberIncorrect {
+                char_index,
+            } => ChineseToNumberError::ChineseNumberIncorrect {
+                char_index: char_index + offset
+            },
+            ChineseToNumberError::Overflow if negative => ChineseToNumberError::Underflow,
+            _ => error,
+        })?;
+
+    Ok((negative, magnitude))
+}
+
+fn japanese_to_unsigned_integer(chars: &[char]) -> Result<u128, ChineseToNumberError> {
+    let method = ChineseCountMethod::TenThousand;
+    let length = chars.len();
+
+    if length == 0 {
+        return Err(ChineseToNumberError::ChineseNumberEmpty);
+    }
+
+    // 只有零本身會寫出零
+    if length == 1 && ChineseNumber::from_char(chars[0]) == Some(ChineseNumber::零) {
+        return Ok(0);
+    }
+
+    let mut sum = 0u128;
+
+    // 目前位組的單位和它的索引，以及位組中是否已經有數字
+    let mut group = (ChineseExponent::個, length);
+    let mut group_filled = false;
+
+    // 下一個數字所在的位數，以及這個位數是否已經有數字
+    let mut place = ChineseExponent::個;
+    let mut place_filled = false;
+
+    let add = |sum: u128, d: u8, place: ChineseExponent, group: ChineseExponent| {
+        let base = get_exp_base::<u128>(method, place)?
+            .checked_mul(get_exp_base(method, group)?)
+            .ok_or(ChineseToNumberError::Overflow)?;
+
+        (d as u128)
+            .checked_mul(base)
+            .and_then(|n| sum.checked_add(n))
+            .ok_or(ChineseToNumberError::Overflow)
+    };
+
+    let mut end = length;
+
+    while end > 0 {
+        let pointer = end - 1;
+
+        if let Some(n) = ChineseNumber::from_char(chars[pointer]) {
+            if n != ChineseNumber::零 && n != ChineseNumber::十 {
+                if place_filled {
+                    return Err(ChineseToNumberError::ChineseNumberIncorrect {
+                        char_index: pointer,
+                    });
+                }
+
+                sum = add(sum, n.ordinal(), place, group.0)?;
+                place_filled = true;
+                group_filled = true;
+
+                end = pointer;
+
+                continue;
+            }
+        }
+
+        let (exp, start) = match exponent_ending_at(method, chars, pointer) {
+            Some((exp, start)) if exp > ChineseExponent::個 => (exp, start),
+            _ => {
+                return Err(ChineseToNumberError::ChineseNumberIncorrect {
+                    char_index: pointer
+                })
+            },
+        };
+
+        // 省略的 **「一」**
+        if place > ChineseExponent::個 && !place_filled {
+            sum = add(sum, 1, place, group.0)?;
+            group_filled = true;
+        }
+
+        if exp < ChineseExponent::萬 {
+            if exp <= place {
+                return Err(ChineseToNumberError::ChineseNumberIncorrect {
+                    char_index: start
+                });
+            }
+        } else {
+            if exp <= group.0 || (group.0 > ChineseExponent::個 && !group_filled) {
+                return Err(ChineseToNumberError::ChineseNumberIncorrect {
+                    char_index: if exp <= group.0 { start } else { group.1 },
+                });
+            }
+
+            group = (exp, start);
+            group_filled = false;
+        }
+
+        place = if exp < ChineseExponent::萬 { exp } else { ChineseExponent::個 };
+        place_filled = false;
+
+        end = start;
+    }
+
+    if place > ChineseExponent::個 && !place_filled {
+        sum = add(sum, 1, place, group.0)?;
+        group_filled = true;
+    }
+
+    if group.0 > ChineseExponent::個 && !group_filled {
+        return Err(ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: group.1
+        });
+    }
+
+    Ok(sum)
+}
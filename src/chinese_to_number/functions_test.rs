@@ -8,7 +8,7 @@ fn test_chinese_to_unsigned_integer_low() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                chinese_to_unsigned_integer(ChineseCountMethod::Low, &to_chars_vec($value))
+                chinese_to_unsigned_integer::<u128>(ChineseCountMethod::Low, &to_chars_vec($value))
                     .unwrap()
             );
         };
@@ -54,8 +54,11 @@ fn test_chinese_to_unsigned_integer_ten_thousand() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                chinese_to_unsigned_integer(ChineseCountMethod::TenThousand, &to_chars_vec($value))
-                    .unwrap()
+                chinese_to_unsigned_integer::<u128>(
+                    ChineseCountMethod::TenThousand,
+                    &to_chars_vec($value)
+                )
+                .unwrap()
             );
         };
     }
@@ -100,8 +103,11 @@ fn test_chinese_to_unsigned_integer_middle() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                chinese_to_unsigned_integer(ChineseCountMethod::Middle, &to_chars_vec($value))
-                    .unwrap()
+                chinese_to_unsigned_integer::<u128>(
+                    ChineseCountMethod::Middle,
+                    &to_chars_vec($value)
+                )
+                .unwrap()
             );
         };
     }
@@ -149,8 +155,11 @@ fn test_chinese_to_unsigned_integer_high() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                chinese_to_unsigned_integer(ChineseCountMethod::High, &to_chars_vec($value))
-                    .unwrap()
+                chinese_to_unsigned_integer::<u128>(
+                    ChineseCountMethod::High,
+                    &to_chars_vec($value)
+                )
+                .unwrap()
             );
         };
     }
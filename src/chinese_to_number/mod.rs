@@ -8,16 +8,18 @@ mod functions_test;
 pub use chinese_to_number_error::*;
 use functions::*;
 pub use naive::*;
+#[cfg(feature = "bigint")]
+use num_bigint::{BigInt, BigUint};
 pub use traits::*;
 
-use crate::ChineseCountMethod;
+use crate::{ChineseCountMethod, FractionInteger};
 
 /// 將中文數字轉成 `u8` 整數。
 #[inline]
 pub fn from_chinese_to_u8<S: AsRef<str>>(chinese_number: S) -> Result<u8, ChineseToNumberError> {
     let chars = to_chars_vec(chinese_number.as_ref());
 
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::Low, &chars)?;
+    let n: u128 = chinese_to_unsigned_integer(ChineseCountMethod::Low, &chars)?;
 
     if n > u8::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -31,7 +33,7 @@ pub fn from_chinese_to_u8<S: AsRef<str>>(chinese_number: S) -> Result<u8, Chines
 pub fn from_chinese_to_u16<S: AsRef<str>>(chinese_number: S) -> Result<u16, ChineseToNumberError> {
     let chars = to_chars_vec(chinese_number.as_ref());
 
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::Low, &chars)?;
+    let n: u128 = chinese_to_unsigned_integer(ChineseCountMethod::Low, &chars)?;
 
     if n > u16::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -47,7 +49,7 @@ pub fn from_chinese_to_u32_low<S: AsRef<str>>(
 ) -> Result<u32, ChineseToNumberError> {
     let chars = to_chars_vec(chinese_number.as_ref());
 
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::Low, &chars)?;
+    let n: u128 = chinese_to_unsigned_integer(ChineseCountMethod::Low, &chars)?;
 
     if n > u32::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -63,7 +65,7 @@ pub fn from_chinese_to_u32_ten_thousand<S: AsRef<str>>(
 ) -> Result<u32, ChineseToNumberError> {
     let chars = to_chars_vec(chinese_number.as_ref());
 
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::TenThousand, &chars)?;
+    let n: u128 = chinese_to_unsigned_integer(ChineseCountMethod::TenThousand, &chars)?;
 
     if n > u32::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -79,7 +81,7 @@ pub fn from_chinese_to_u32_middle<S: AsRef<str>>(
 ) -> Result<u32, ChineseToNumberError> {
     let chars = to_chars_vec(chinese_number.as_ref());
 
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::Middle, &chars)?;
+    let n: u128 = chinese_to_unsigned_integer(ChineseCountMethod::Middle, &chars)?;
 
     if n > u32::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -95,7 +97,7 @@ pub fn from_chinese_to_u32_high<S: AsRef<str>>(
 ) -> Result<u32, ChineseToNumberError> {
     let chars = to_chars_vec(chinese_number.as_ref());
 
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::High, &chars)?;
+    let n: u128 = chinese_to_unsigned_integer(ChineseCountMethod::High, &chars)?;
 
     if n > u32::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -111,7 +113,7 @@ pub fn from_chinese_to_u64_low<S: AsRef<str>>(
 ) -> Result<u64, ChineseToNumberError> {
     let chars = to_chars_vec(chinese_number.as_ref());
 
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::Low, &chars)?;
+    let n: u128 = chinese_to_unsigned_integer(ChineseCountMethod::Low, &chars)?;
 
     if n > u64::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -127,7 +129,7 @@ pub fn from_chinese_to_u64_ten_thousand<S: AsRef<str>>(
 ) -> Result<u64, ChineseToNumberError> {
     let chars = to_chars_vec(chinese_number.as_ref());
 
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::TenThousand, &chars)?;
+    let n: u128 = chinese_to_unsigned_integer(ChineseCountMethod::TenThousand, &chars)?;
 
     if n > u64::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -143,7 +145,7 @@ pub fn from_chinese_to_u64_middle<S: AsRef<str>>(
 ) -> Result<u64, ChineseToNumberError> {
     let chars = to_chars_vec(chinese_number.as_ref());
 
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::Middle, &chars)?;
+    let n: u128 = chinese_to_unsigned_integer(ChineseCountMethod::Middle, &chars)?;
 
     if n > u64::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -159,7 +161,7 @@ pub fn from_chinese_to_u64_high<S: AsRef<str>>(
 ) -> Result<u64, ChineseToNumberError> {
     let chars = to_chars_vec(chinese_number.as_ref());
 
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::High, &chars)?;
+    let n: u128 = chinese_to_unsigned_integer(ChineseCountMethod::High, &chars)?;
 
     if n > u64::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -215,7 +217,7 @@ pub fn from_chinese_to_usize_low<S: AsRef<str>>(
 ) -> Result<usize, ChineseToNumberError> {
     let chars = to_chars_vec(chinese_number.as_ref());
 
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::Low, &chars)?;
+    let n: u128 = chinese_to_unsigned_integer(ChineseCountMethod::Low, &chars)?;
 
     if n > usize::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -231,7 +233,7 @@ pub fn from_chinese_to_usize_ten_thousand<S: AsRef<str>>(
 ) -> Result<usize, ChineseToNumberError> {
     let chars = to_chars_vec(chinese_number.as_ref());
 
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::TenThousand, &chars)?;
+    let n: u128 = chinese_to_unsigned_integer(ChineseCountMethod::TenThousand, &chars)?;
 
     if n > usize::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -247,7 +249,7 @@ pub fn from_chinese_to_usize_middle<S: AsRef<str>>(
 ) -> Result<usize, ChineseToNumberError> {
     let chars = to_chars_vec(chinese_number.as_ref());
 
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::Middle, &chars)?;
+    let n: u128 = chinese_to_unsigned_integer(ChineseCountMethod::Middle, &chars)?;
 
     if n > usize::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -263,7 +265,7 @@ pub fn from_chinese_to_usize_high<S: AsRef<str>>(
 ) -> Result<usize, ChineseToNumberError> {
     let chars = to_chars_vec(chinese_number.as_ref());
 
-    let n = chinese_to_unsigned_integer(ChineseCountMethod::High, &chars)?;
+    let n: u128 = chinese_to_unsigned_integer(ChineseCountMethod::High, &chars)?;
 
     if n > usize::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -639,3 +641,93 @@ pub fn from_chinese_to_f64_high<S: AsRef<str>>(
 
     chinese_to_f64(ChineseCountMethod::High, &chars)
 }
+
+// TODO big integer
+
+/// 將中文數字轉成 `BigUint` 整數。使用 **「下數」**。
+#[cfg(feature = "bigint")]
+#[inline]
+pub fn from_chinese_to_biguint_low<S: AsRef<str>>(
+    chinese_number: S,
+) -> Result<BigUint, ChineseToNumberError> {
+    let chars = to_chars_vec(chinese_number.as_ref());
+
+    chinese_to_unsigned_integer(ChineseCountMethod::Low, &chars)
+}
+
+/// 將中文數字轉成 `BigUint` 整數。使用 **「萬進」**。
+#[cfg(feature = "bigint")]
+#[inline]
+pub fn from_chinese_to_biguint_ten_thousand<S: AsRef<str>>(
+    chinese_number: S,
+) -> Result<BigUint, ChineseToNumberError> {
+    let chars = to_chars_vec(chinese_number.as_ref());
+
+    chinese_to_unsigned_integer(ChineseCountMethod::TenThousand, &chars)
+}
+
+/// 將中文數字轉成 `BigUint` 整數。使用 **「中數」**。
+#[cfg(feature = "bigint")]
+#[inline]
+pub fn from_chinese_to_biguint_middle<S: AsRef<str>>(
+    chinese_number: S,
+) -> Result<BigUint, ChineseToNumberError> {
+    let chars = to_chars_vec(chinese_number.as_ref());
+
+    chinese_to_unsigned_integer(ChineseCountMethod::Middle, &chars)
+}
+
+/// 將中文數字轉成 `BigUint` 整數。使用 **「上數」**。
+#[cfg(feature = "bigint")]
+#[inline]
+pub fn from_chinese_to_biguint_high<S: AsRef<str>>(
+    chinese_number: S,
+) -> Result<BigUint, ChineseToNumberError> {
+    let chars = to_chars_vec(chinese_number.as_ref());
+
+    chinese_to_unsigned_integer(ChineseCountMethod::High, &chars)
+}
+
+/// 將中文數字轉成 `BigInt` 整數。使用 **「下數」**。
+#[cfg(feature = "bigint")]
+#[inline]
+pub fn from_chinese_to_bigint_low<S: AsRef<str>>(
+    chinese_number: S,
+) -> Result<BigInt, ChineseToNumberError> {
+    let chars = to_chars_vec(chinese_number.as_ref());
+
+    chinese_to_big_signed_integer(ChineseCountMethod::Low, &chars)
+}
+
+/// 將中文數字轉成 `BigInt` 整數。使用 **「萬進」**。
+#[cfg(feature = "bigint")]
+#[inline]
+pub fn from_chinese_to_bigint_ten_thousand<S: AsRef<str>>(
+    chinese_number: S,
+) -> Result<BigInt, ChineseToNumberError> {
+    let chars = to_chars_vec(chinese_number.as_ref());
+
+    chinese_to_big_signed_integer(ChineseCountMethod::TenThousand, &chars)
+}
+
+/// 將中文數字轉成 `BigInt` 整數。使用 **「中數」**。
+#[cfg(feature = "bigint")]
+#[inline]
+pub fn from_chinese_to_bigint_middle<S: AsRef<str>>(
+    chinese_number: S,
+) -> Result<BigInt, ChineseToNumberError> {
+    let chars = to_chars_vec(chinese_number.as_ref());
+
+    chinese_to_big_signed_integer(ChineseCountMethod::Middle, &chars)
+}
+
+/// 將中文數字轉成 `BigInt` 整數。使用 **「上數」**。
+#[cfg(feature = "bigint")]
+#[inline]
+pub fn from_chinese_to_bigint_high<S: AsRef<str>>(
+    chinese_number: S,
+) -> Result<BigInt, ChineseToNumberError> {
+    let chars = to_chars_vec(chinese_number.as_ref());
+
+    chinese_to_big_signed_integer(ChineseCountMethod::High, &chars)
+}
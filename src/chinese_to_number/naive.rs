@@ -1,25 +1,98 @@
+#[cfg(feature = "bigint")]
+use num_bigint::{BigInt, BigUint};
 #[cfg(not(feature = "std"))]
 #[allow(unused_imports)]
 use num_traits::float::FloatCore;
 
-use super::to_chars_vec;
+use super::{chinese_to_fraction, to_chars_vec, UnsignedInteger};
 use crate::{
     chinese_characters::{ChineseNumber, ChinesePoint, ChineseSign},
-    ChineseToNumberError,
+    ChineseDigitSeparator, ChineseToNumberError,
 };
 
-fn chinese_to_unsigned_integer(chars: &[char]) -> Result<u128, ChineseToNumberError> {
+/// 檢查逐位數字中位組分隔符號的位置。除了第一個位組之外，每個位組的位數都必須相同，且第一個位組的位數不能比其它位組多。
+#[derive(Default)]
+struct DigitGroups {
+    count:          usize,
+    first:          Option<(usize, usize)>,
+    size:           Option<usize>,
+    last_separator: usize,
+}
+
+impl DigitGroups {
+    #[inline]
+    fn push_digit(&mut self) {
+        self.count += 1;
+    }
+
+    fn push_separator(&mut self, char_index: usize) -> Result<(), ChineseToNumberError> {
+        let error = ChineseToNumberError::ChineseNumberIncorrect {
+            char_index,
+        };
+
+        if self.count == 0 {
+            return Err(error);
+        }
+
+        match self.first {
+            None => self.first = Some((self.count, char_index)),
+            Some(_) => match self.size {
+                None => self.size = Some(self.count),
+                Some(size) if size != self.count => return Err(error),
+                _ => (),
+            },
+        }
+
+        self.count = 0;
+        self.last_separator = char_index;
+
+        Ok(())
+    }
+
+    fn finish(&self) -> Result<(), ChineseToNumberError> {
+        if let Some((first, first_separator)) = self.first {
+            let size = self.size.unwrap_or(self.count);
+
+            if self.count != size {
+                return Err(ChineseToNumberError::ChineseNumberIncorrect {
+                    char_index: self.last_separator,
+                });
+            }
+
+            if first > size {
+                return Err(ChineseToNumberError::ChineseNumberIncorrect {
+                    char_index: first_separator,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn chinese_to_unsigned_integer<N: UnsignedInteger>(
+    chars: &[char],
+) -> Result<N, ChineseToNumberError> {
     let length = chars.len();
 
     if length == 0 {
         return Err(ChineseToNumberError::ChineseNumberEmpty);
     }
 
-    let mut sum = 0u128;
+    let ten = N::from(10);
+
+    let mut sum = N::zero();
+    let mut groups = DigitGroups::default();
 
     for (char_index, &char) in chars.iter().enumerate() {
+        if ChineseDigitSeparator::from_char(char).is_some() {
+            groups.push_separator(char_index)?;
+
+            continue;
+        }
+
         let d = match ChineseNumber::from_char(char) {
-            Some(cn) if cn != ChineseNumber::十 => cn.ordinal() as u128,
+            Some(cn) if cn != ChineseNumber::十 => N::from(cn.ordinal() as u32),
             _ => {
                 return Err(ChineseToNumberError::ChineseNumberIncorrect {
                     char_index,
@@ -27,11 +100,15 @@ fn chinese_to_unsigned_integer(chars: &[char]) -> Result<u128, ChineseToNumberEr
             },
         };
 
-        sum = sum.checked_mul(10).ok_or(ChineseToNumberError::Overflow)?;
+        sum = sum.checked_mul(&ten).ok_or(ChineseToNumberError::Overflow)?;
 
-        sum = sum.checked_add(d).ok_or(ChineseToNumberError::Overflow)?;
+        sum = sum.checked_add(&d).ok_or(ChineseToNumberError::Overflow)?;
+
+        groups.push_digit();
     }
 
+    groups.finish()?;
+
     Ok(sum)
 }
 
@@ -42,14 +119,9 @@ fn chinese_to_signed_integer(chars: &[char]) -> Result<i128, ChineseToNumberErro
         return Err(ChineseToNumberError::ChineseNumberEmpty);
     }
 
-    let c = chars[0];
+    let (sign, offset) = ChineseSign::from_prefix(chars).unwrap_or((ChineseSign::正, 0));
 
-    let (sign, offset) = match ChineseSign::from_char(c) {
-        Some(sign) => (sign, 1),
-        None => (ChineseSign::正, 0),
-    };
-
-    let uint = match chinese_to_unsigned_integer(&chars[offset..]) {
+    let uint: u128 = match chinese_to_unsigned_integer(&chars[offset..]) {
         Ok(n) => n,
         Err(error) => {
             return match error {
@@ -97,7 +169,7 @@ pub fn from_chinese_to_u8_naive<S: AsRef<str>>(
 ) -> Result<u8, ChineseToNumberError> {
     let chars = to_chars_vec(chinese_number.as_ref());
 
-    let n = chinese_to_unsigned_integer(&chars)?;
+    let n: u128 = chinese_to_unsigned_integer(&chars)?;
 
     if n > u8::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -113,7 +185,7 @@ pub fn from_chinese_to_u16_naive<S: AsRef<str>>(
 ) -> Result<u16, ChineseToNumberError> {
     let chars = to_chars_vec(chinese_number.as_ref());
 
-    let n = chinese_to_unsigned_integer(&chars)?;
+    let n: u128 = chinese_to_unsigned_integer(&chars)?;
 
     if n > u16::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -129,7 +201,7 @@ pub fn from_chinese_to_u32_naive<S: AsRef<str>>(
 ) -> Result<u32, ChineseToNumberError> {
     let chars = to_chars_vec(chinese_number.as_ref());
 
-    let n = chinese_to_unsigned_integer(&chars)?;
+    let n: u128 = chinese_to_unsigned_integer(&chars)?;
 
     if n > u32::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -145,7 +217,7 @@ pub fn from_chinese_to_u64_naive<S: AsRef<str>>(
 ) -> Result<u64, ChineseToNumberError> {
     let chars = to_chars_vec(chinese_number.as_ref());
 
-    let n = chinese_to_unsigned_integer(&chars)?;
+    let n: u128 = chinese_to_unsigned_integer(&chars)?;
 
     if n > u64::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -171,7 +243,7 @@ pub fn from_chinese_to_usize_naive<S: AsRef<str>>(
 ) -> Result<usize, ChineseToNumberError> {
     let chars = to_chars_vec(chinese_number.as_ref());
 
-    let n = chinese_to_unsigned_integer(&chars)?;
+    let n: u128 = chinese_to_unsigned_integer(&chars)?;
 
     if n > usize::MAX as u128 {
         return Err(ChineseToNumberError::Overflow);
@@ -280,6 +352,55 @@ pub fn from_chinese_to_isize_naive<S: AsRef<str>>(
     Ok(n as isize)
 }
 
+/// 將中文數字轉成 `BigUint` 整數。不進行單位計算。
+#[cfg(feature = "bigint")]
+#[inline]
+pub fn from_chinese_to_biguint_naive<S: AsRef<str>>(
+    chinese_number: S,
+) -> Result<BigUint, ChineseToNumberError> {
+    let chars = to_chars_vec(chinese_number.as_ref());
+
+    chinese_to_unsigned_integer(&chars)
+}
+
+/// 將中文數字轉成 `BigInt` 整數。不進行單位計算。
+#[cfg(feature = "bigint")]
+#[inline]
+pub fn from_chinese_to_bigint_naive<S: AsRef<str>>(
+    chinese_number: S,
+) -> Result<BigInt, ChineseToNumberError> {
+    let chars = to_chars_vec(chinese_number.as_ref());
+
+    if chars.is_empty() {
+        return Err(ChineseToNumberError::ChineseNumberEmpty);
+    }
+
+    let (sign, offset) = ChineseSign::from_prefix(&chars).unwrap_or((ChineseSign::正, 0));
+
+    let uint: BigUint =
+        chinese_to_unsigned_integer(&chars[offset..]).map_err(|error| match error {
+            ChineseToNumberError::ChineseNumberIncorrect {
+                char_index: index,
+            } => ChineseToNumberError::ChineseNumberIncorrect {
+                char_index: index + offset
+            },
+            _ => error,
+        })?;
+
+    match sign {
+        ChineseSign::正 => Ok(BigInt::from(uint)),
+        ChineseSign::負 => Ok(-BigInt::from(uint)),
+    }
+}
+
+/// 解析分母在前、不進行單位計算的分數。
+#[inline]
+pub(crate) fn chinese_to_fraction_naive(
+    chars: &[char],
+) -> Result<(bool, u128, u128), ChineseToNumberError> {
+    chinese_to_fraction(chars, chinese_to_unsigned_integer)
+}
+
 // TODO f64
 
 fn chinese_to_f64(chars: &[char]) -> Result<f64, ChineseToNumberError> {
@@ -289,16 +410,20 @@ fn chinese_to_f64(chars: &[char]) -> Result<f64, ChineseToNumberError> {
         return Err(ChineseToNumberError::ChineseNumberEmpty);
     }
 
-    let (sign, offset) = match ChineseSign::from_char(chars[0]) {
-        Some(sign) => (sign, 1),
-        None => (ChineseSign::正, 0),
-    };
+    let (sign, offset) = ChineseSign::from_prefix(chars).unwrap_or((ChineseSign::正, 0));
 
     let mut sum = 0f64;
+    let mut groups = DigitGroups::default();
 
     let mut iter = chars[offset..].iter().enumerate();
 
     for (i, &char) in iter.by_ref() {
+        if ChineseDigitSeparator::from_char(char).is_some() {
+            groups.push_separator(i + offset)?;
+
+            continue;
+        }
+
         let d = match ChineseNumber::from_char(char) {
             Some(cn) if cn != ChineseNumber::十 => cn.ordinal() as f64,
             _ => match ChinesePoint::from_char(char) {
@@ -314,11 +439,13 @@ fn chinese_to_f64(chars: &[char]) -> Result<f64, ChineseToNumberError> {
         sum *= 10.0;
 
         sum += d;
+
+        groups.push_digit();
     }
 
-    let mut c = 1i32;
+    groups.finish()?;
 
-    for (i, &char) in iter {
+    for (c, (i, &char)) in (1i32..).zip(iter) {
         let d = match ChineseNumber::from_char(char) {
             Some(cn) if cn != ChineseNumber::十 => cn.ordinal() as f64,
             _ => {
@@ -329,7 +456,6 @@ fn chinese_to_f64(chars: &[char]) -> Result<f64, ChineseToNumberError> {
         };
 
         sum += d * 0.1f64.powi(c);
-        c += 1;
     }
 
     match sign {
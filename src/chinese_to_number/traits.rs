@@ -1,9 +1,16 @@
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+use num_traits::float::FloatCore;
+
 use super::*;
 
 /// 讓 Rust 程式語言的字串型別擁有將中文數字轉成數值的能力。
 pub trait ChineseToNumber<T> {
     /// 將中文數字轉成數值。
     ///
+    /// 浮點數的小數部分可以使用 **「點」** 逐位表示，或使用 **「角」** 和 **「分」** 表示，也可以使用港幣的 **「毫」** 和 **「仙」**，整數部分之後可以有 **「元」** 或 **「圓」**，例如 **「三圓五毫」**。只有緊接在 **「元」** 或 **「圓」** 之後的 **「毫」** 會被當作港幣的一角，其它的 **「毫」** 都是萬分之一。
+    ///
     /// ## 範例
     ///
     /// ```rust
@@ -13,7 +20,7 @@ pub trait ChineseToNumber<T> {
     /// ```
     fn to_number(&self, method: ChineseCountMethod) -> Result<T, ChineseToNumberError>;
 
-    /// 將中文數字直接轉成數值，不進行單位計算。
+    /// 將中文數字直接轉成數值，不進行單位計算。數字可以使用 **「〇」** 表示零，整數部分也可以使用 `ChineseDigitSeparator` 的符號分隔位組，但除了第一個位組之外，每個位組的位數都必須相同。
     ///
     /// ## 範例
     ///
@@ -23,6 +30,164 @@ pub trait ChineseToNumber<T> {
     /// assert_eq!(123456789u64, "一二三四五六七八九".to_number_naive().unwrap());
     /// ```
     fn to_number_naive(&self) -> Result<T, ChineseToNumberError>;
+
+    /// 將以 **「第」** 開頭的序數轉成數值，例如 **「第一百二十三」**。序數必須是大於或等於 `1` 的整數，不能有正負號或小數部分。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{ChineseCountMethod, ChineseToNumber};
+    ///
+    /// assert_eq!(
+    ///     123u8,
+    ///     "第一百二十三"
+    ///         .to_number_ordinal(ChineseCountMethod::TenThousand)
+    ///         .unwrap()
+    /// );
+    /// ```
+    #[inline]
+    fn to_number_ordinal(&self, method: ChineseCountMethod) -> Result<T, ChineseToNumberError>
+    where
+        Self: AsRef<str>,
+        for<'a> &'a str: ChineseToNumber<T>, {
+        strip_ordinal(method, self.as_ref())?.to_number(method).map_err(|error| match error {
+            ChineseToNumberError::ChineseNumberIncorrect {
+                char_index,
+            } => ChineseToNumberError::ChineseNumberIncorrect {
+                char_index: char_index + 1
+            },
+            _ => error,
+        })
+    }
+
+    /// 將以 **「百分之」**、**「千分之」** 或 **「萬分之」** 開頭的中文數字（**「之」** 也可以寫成日文的 **「の」**）轉成移動小數點之後的浮點數，例如 **「百分之十五點三」** 會轉成 `0.153`。單位可以使用繁體、簡體、大寫或小寫，正負號要寫在最前面，**「分之」** 之後只能是整數或使用 **「點」** 的小數。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{ChineseCountMethod, ChineseToNumber};
+    ///
+    /// assert_eq!(
+    ///     0.153f64,
+    ///     "百分之十五點三"
+    ///         .to_number_percent(ChineseCountMethod::TenThousand)
+    ///         .unwrap()
+    /// );
+    /// assert_eq!(
+    ///     -0.005f64,
+    ///     "負千分之五"
+    ///         .to_number_percent(ChineseCountMethod::TenThousand)
+    ///         .unwrap()
+    /// );
+    /// assert_eq!(
+    ///     0.0003f32,
+    ///     "万分之叁".to_number_percent(ChineseCountMethod::TenThousand).unwrap()
+    /// );
+    /// ```
+    #[inline]
+    fn to_number_percent(&self, method: ChineseCountMethod) -> Result<T, ChineseToNumberError>
+    where
+        Self: AsRef<str>,
+        T: FloatCore, {
+        let chars = to_chars_vec(self.as_ref());
+
+        chinese_to_percent(method, &chars).map(|f| T::from(f).unwrap())
+    }
+
+    /// 將票據上所使用的中文金額轉成以分為單位的整數，例如 **「人民幣壹萬零貳佰元整」** 會轉成 `1_020_000`。開頭可以有 **「人民幣」**、**「新臺幣」** 或 **「港幣」** 等貨幣名稱，結尾可以有 **「整」** 或 **「正」**，主要單位可以使用 **「元」** 或 **「圓」**，輔幣單位可以使用 **「角」** 和 **「分」** 或是 **「毫」** 和 **「仙」**，整數部分使用 **「萬進」**。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::ChineseToNumber;
+    ///
+    /// assert_eq!(
+    ///     1_020_000u32,
+    ///     "人民幣壹萬零貳佰元整".to_number_currency().unwrap()
+    /// );
+    /// assert_eq!(
+    ///     1_230_000i64,
+    ///     "新臺幣壹萬貳仟參佰元整".to_number_currency().unwrap()
+    /// );
+    /// assert_eq!(
+    ///     32_504u64,
+    ///     "人民币叁佰贰拾伍元零肆分".to_number_currency().unwrap()
+    /// );
+    /// assert_eq!(50u8, "伍角正".to_number_currency().unwrap());
+    /// assert_eq!(12_050u32, "港幣壹佰貳拾圓伍毫".to_number_currency().unwrap());
+    /// ```
+    #[inline]
+    fn to_number_currency(&self) -> Result<T, ChineseToNumberError>
+    where
+        Self: AsRef<str>,
+        T: FractionInteger, {
+        let chars = to_chars_vec(self.as_ref());
+
+        T::from_sign_magnitude(false, chinese_to_currency(&chars)?)
+    }
+
+    /// 將日文的漢數字轉成整數，例如 **「千二百三万四千五」**、**「壱萬弐千参拾」**。**「十」**、**「百」**、**「千」** 前的 **「一」** 可以省略，數字中間不寫零，所以 **「千一」** 是 `1001`。大的單位使用 **「萬進」**，可以使用 **「万」**、**「𥝱」**、**「穣」** 等日文的字形。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::ChineseToNumber;
+    ///
+    /// assert_eq!(1001u16, "千一".to_number_japanese().unwrap());
+    /// assert_eq!(12034005u32, "千二百三万四千五".to_number_japanese().unwrap());
+    /// assert_eq!(12030u32, "壱萬弐千参拾".to_number_japanese().unwrap());
+    /// assert_eq!(-20i8, "負二十".to_number_japanese().unwrap());
+    /// ```
+    #[inline]
+    fn to_number_japanese(&self) -> Result<T, ChineseToNumberError>
+    where
+        Self: AsRef<str>,
+        T: FractionInteger, {
+        let chars = to_chars_vec(self.as_ref());
+
+        let (negative, magnitude) = japanese_to_integer(&chars)?;
+
+        T::from_sign_magnitude(negative, magnitude)
+    }
+
+    /// 將依照算術類型以 `ChineseDigitSeparator` 的符號分成位組的阿拉伯數字轉成數值，例如 **「萬進」** 的 **「12,3456,7890」**。每個位組的位數都必須和 `NumberToChinese::to_grouped_digits_with` 寫出的相同，且整個數字只能使用同一種分隔符號。可以使用 **「-」** 或 **「+」** 開頭，小數部分以 **「.」** 分隔且不分組。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{ChineseCountMethod, ChineseToNumber};
+    ///
+    /// assert_eq!(
+    ///     1234567890u64,
+    ///     "12,3456,7890"
+    ///         .to_number_grouped(ChineseCountMethod::TenThousand)
+    ///         .unwrap()
+    /// );
+    /// assert_eq!(
+    ///     -34567890.5f64,
+    ///     "-3456，7890.5"
+    ///         .to_number_grouped(ChineseCountMethod::TenThousand)
+    ///         .unwrap()
+    /// );
+    /// assert_eq!(
+    ///     1234567890u64,
+    ///     "12 34567890".to_number_grouped(ChineseCountMethod::Middle).unwrap()
+    /// );
+    ///
+    /// let result: Result<u32, _> =
+    ///     "123,4567".to_number_grouped(ChineseCountMethod::Middle);
+    ///
+    /// assert!(result.is_err());
+    /// ```
+    #[inline]
+    fn to_number_grouped(&self, method: ChineseCountMethod) -> Result<T, ChineseToNumberError>
+    where
+        Self: AsRef<str>,
+        T: FromStr, {
+        let chars: Vec<char> = self.as_ref().trim().chars().collect();
+
+        grouped_digits_to_number(method, &chars)
+    }
 }
 
 impl<T: AsRef<str>> ChineseToNumber<u8> for T {
@@ -242,3 +407,89 @@ impl<T: AsRef<str>> ChineseToNumber<f64> for T {
         from_chinese_to_f64_naive(self)
     }
 }
+
+#[cfg(feature = "bigint")]
+impl<T: AsRef<str>> ChineseToNumber<BigUint> for T {
+    #[inline]
+    fn to_number(&self, method: ChineseCountMethod) -> Result<BigUint, ChineseToNumberError> {
+        match method {
+            ChineseCountMethod::Low => from_chinese_to_biguint_low(self),
+            ChineseCountMethod::TenThousand => from_chinese_to_biguint_ten_thousand(self),
+            ChineseCountMethod::Middle => from_chinese_to_biguint_middle(self),
+            ChineseCountMethod::High => from_chinese_to_biguint_high(self),
+        }
+    }
+
+    #[inline]
+    fn to_number_naive(&self) -> Result<BigUint, ChineseToNumberError> {
+        from_chinese_to_biguint_naive(self)
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl<T: AsRef<str>> ChineseToNumber<BigInt> for T {
+    #[inline]
+    fn to_number(&self, method: ChineseCountMethod) -> Result<BigInt, ChineseToNumberError> {
+        match method {
+            ChineseCountMethod::Low => from_chinese_to_bigint_low(self),
+            ChineseCountMethod::TenThousand => from_chinese_to_bigint_ten_thousand(self),
+            ChineseCountMethod::Middle => from_chinese_to_bigint_middle(self),
+            ChineseCountMethod::High => from_chinese_to_bigint_high(self),
+        }
+    }
+
+    #[inline]
+    fn to_number_naive(&self) -> Result<BigInt, ChineseToNumberError> {
+        from_chinese_to_bigint_naive(self)
+    }
+}
+
+/// 將分母在前的中文分數轉成 `(分子, 分母)`，例如 **「三分之一」** 會轉成 `(1, 3)`，**「負五分之二」** 會轉成 `(-2, 5)`。也可以使用日文的 **「の」** 代替 **「之」**，例如 **「三分の一」**。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{ChineseCountMethod, ChineseToNumber};
+///
+/// assert_eq!(
+///     (1u8, 3u8),
+///     "三分之一".to_number(ChineseCountMethod::TenThousand).unwrap()
+/// );
+/// assert_eq!(
+///     (-2i32, 5i32),
+///     "負五分之二".to_number(ChineseCountMethod::TenThousand).unwrap()
+/// );
+/// assert_eq!(
+///     (8u8, 10u8),
+///     "十之八".to_number(ChineseCountMethod::TenThousand).unwrap()
+/// );
+/// ```
+impl<S: AsRef<str>, T: FractionInteger> ChineseToNumber<(T, T)> for S {
+    #[inline]
+    fn to_number(&self, method: ChineseCountMethod) -> Result<(T, T), ChineseToNumberError> {
+        let chars = to_chars_vec(self.as_ref());
+
+        let (negative, numerator, denominator) =
+            chinese_to_fraction(&chars, |chars| chinese_to_unsigned_integer(method, chars))?;
+
+        fraction_from_sign_magnitude(negative, numerator, denominator)
+    }
+
+    #[inline]
+    fn to_number_naive(&self) -> Result<(T, T), ChineseToNumberError> {
+        let chars = to_chars_vec(self.as_ref());
+
+        let (negative, numerator, denominator) = chinese_to_fraction_naive(&chars)?;
+
+        fraction_from_sign_magnitude(negative, numerator, denominator)
+    }
+}
+
+#[inline]
+fn fraction_from_sign_magnitude<T: FractionInteger>(
+    negative: bool,
+    numerator: u128,
+    denominator: u128,
+) -> Result<(T, T), ChineseToNumberError> {
+    Ok((T::from_sign_magnitude(negative, numerator)?, T::from_sign_magnitude(false, denominator)?))
+}
@@ -0,0 +1,114 @@
+#[cfg(feature = "chinese-to-number")]
+use crate::ChineseToNumberError;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// 能夠作為分數的分子和分母的基本整數型別，只能由這個 crate 實作。
+pub trait FractionInteger: Copy + private::Sealed {
+    /// 拆成是否為負數和絕對值。
+    #[cfg(feature = "number-to-chinese")]
+    #[doc(hidden)]
+    fn into_sign_magnitude(self) -> (bool, u128);
+
+    /// 由是否為負數和絕對值組成整數。
+    #[cfg(feature = "chinese-to-number")]
+    #[doc(hidden)]
+    fn from_sign_magnitude(negative: bool, magnitude: u128) -> Result<Self, ChineseToNumberError>;
+}
+
+#[cfg(feature = "chinese-to-number")]
+#[inline]
+fn unsigned_from_sign_magnitude<T: TryFrom<u128>>(
+    negative: bool,
+    magnitude: u128,
+) -> Result<T, ChineseToNumberError> {
+    if negative && magnitude > 0 {
+        return Err(ChineseToNumberError::Underflow);
+    }
+
+    T::try_from(magnitude).map_err(|_| ChineseToNumberError::Overflow)
+}
+
+#[cfg(feature = "chinese-to-number")]
+#[inline]
+fn signed_from_sign_magnitude<T: TryFrom<i128>>(
+    negative: bool,
+    magnitude: u128,
+) -> Result<T, ChineseToNumberError> {
+    let value = if negative {
+        if magnitude > i128::MAX as u128 + 1 {
+            return Err(ChineseToNumberError::Underflow);
+        }
+
+        (magnitude as i128).wrapping_neg()
+    } else {
+        if magnitude > i128::MAX as u128 {
+            return Err(ChineseToNumberError::Overflow);
+        }
+
+        magnitude as i128
+    };
+
+    T::try_from(value).map_err(|_| {
+        if negative {
+            ChineseToNumberError::Underflow
+        } else {
+            ChineseToNumberError::Overflow
+        }
+    })
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+
+            impl FractionInteger for $t {
+                #[cfg(feature = "number-to-chinese")]
+                #[inline]
+                fn into_sign_magnitude(self) -> (bool, u128) {
+                    (false, self as u128)
+                }
+
+                #[cfg(feature = "chinese-to-number")]
+                #[inline]
+                fn from_sign_magnitude(
+                    negative: bool,
+                    magnitude: u128,
+                ) -> Result<Self, ChineseToNumberError> {
+                    unsigned_from_sign_magnitude(negative, magnitude)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+
+            impl FractionInteger for $t {
+                #[cfg(feature = "number-to-chinese")]
+                #[inline]
+                fn into_sign_magnitude(self) -> (bool, u128) {
+                    (self < 0, (self as i128).unsigned_abs())
+                }
+
+                #[cfg(feature = "chinese-to-number")]
+                #[inline]
+                fn from_sign_magnitude(
+                    negative: bool,
+                    magnitude: u128,
+                ) -> Result<Self, ChineseToNumberError> {
+                    signed_from_sign_magnitude(negative, magnitude)
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_signed!(i8, i16, i32, i64, i128, isize);
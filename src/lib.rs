@@ -12,7 +12,7 @@ This crate can convert Rust's primitive number data types to Chinese numbers as
 # {
 use chinese_number::{ChineseCase, ChineseCountMethod, ChineseVariant, NumberToChinese, ChineseToNumber};
 
-assert_eq!("一二三", 123i8.to_chinese_naive(ChineseVariant::Traditional, ChineseCase::Lower));
+assert_eq!("一二三", 123i8.to_chinese_naive(ChineseVariant::Traditional, ChineseCase::Lower).unwrap());
 
 assert_eq!("壹佰貳拾參", 123i8.to_chinese(ChineseVariant::Traditional, ChineseCase::Upper, ChineseCountMethod::TenThousand).unwrap());
 assert_eq!("壹佰贰拾叁", 123i8.to_chinese(ChineseVariant::Simple, ChineseCase::Upper, ChineseCountMethod::TenThousand).unwrap());
@@ -25,6 +25,7 @@ assert_eq!("十二萬三千四百五十六京七千八百九十萬一千二百
 assert_eq!("十二萬三千四百五十六億七千八百九十萬一千二百三十四兆五千六百七十八萬九千零一十二億三千四百五十六萬七千八百九十", 123456789012345678901234567890i128.to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, ChineseCountMethod::High).unwrap());
 
 assert_eq!("一角二分", 0.12f64.to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, ChineseCountMethod::TenThousand).unwrap());
+assert_eq!("一點二三四五", 1.2345f64.to_chinese_decimal(ChineseVariant::Traditional, ChineseCase::Lower, ChineseCountMethod::TenThousand).unwrap());
 
 assert_eq!(123i8, "一二三".to_number_naive().unwrap());
 
@@ -46,6 +47,26 @@ assert_eq!(150000000u64, "一億五".to_number(ChineseCountMethod::TenThousand).
 # }
 ```
 
+## Format Options
+
+All the rendering options can be put together in a `ChineseFormatOptions` and used with `to_chinese_with`.
+
+```rust
+# #[cfg(feature = "number-to-chinese")]
+# {
+use chinese_number::{ChineseCountMethod, ChineseFormatOptions, ChineseTwoStyle, ChineseVariant, ChineseZeroStyle, NumberToChinese};
+
+let options = ChineseFormatOptions::new()
+    .with_variant(ChineseVariant::Simple)
+    .with_method(ChineseCountMethod::TenThousand)
+    .with_two_style(ChineseTwoStyle::Liang)
+    .with_zero_style(ChineseZeroStyle::Circle);
+
+assert_eq!("两千〇二十四", 2024.to_chinese_with(&options).unwrap());
+assert_eq!("共两万人", format!("共{}人", 20000.to_chinese_display_with(&options).unwrap()));
+# }
+```
+
 ## No Std
 
 Disable the default features to compile this crate without std.
@@ -55,6 +76,16 @@ Disable the default features to compile this crate without std.
 version = "*"
 default-features = false
 features = ["number-to-chinese", "chinese-to-number"]
+```
+
+## Big Integers
+
+Enable the `bigint` feature to convert between Chinese numbers and `BigUint` / `BigInt` of the `num-bigint` crate.
+
+```toml
+[dependencies.chinese-number]
+version = "*"
+features = ["bigint"]
 ```
  */
 
@@ -73,13 +104,29 @@ mod chinese_case;
 #[cfg(any(feature = "chinese-to-number", feature = "number-to-chinese"))]
 mod chinese_characters;
 mod chinese_count_method;
+#[cfg(any(feature = "chinese-to-number", feature = "number-to-chinese"))]
+mod chinese_currency;
+#[cfg(any(feature = "chinese-to-number", feature = "number-to-chinese"))]
+mod chinese_digit_separator;
+#[cfg(any(feature = "chinese-to-number", feature = "number-to-chinese"))]
+mod chinese_percent;
+#[cfg(any(feature = "chinese-to-number", feature = "number-to-chinese"))]
+mod fraction_integer;
 
 pub use chinese_case::*;
 #[cfg(any(feature = "chinese-to-number", feature = "number-to-chinese"))]
 pub(crate) use chinese_characters::*;
 pub use chinese_count_method::*;
+#[cfg(any(feature = "chinese-to-number", feature = "number-to-chinese"))]
+pub use chinese_currency::*;
+#[cfg(any(feature = "chinese-to-number", feature = "number-to-chinese"))]
+pub use chinese_digit_separator::*;
+#[cfg(any(feature = "chinese-to-number", feature = "number-to-chinese"))]
+pub use chinese_percent::*;
 #[cfg(feature = "chinese-to-number")]
 pub use chinese_to_number::*;
 pub use chinese_variant::*;
+#[cfg(any(feature = "chinese-to-number", feature = "number-to-chinese"))]
+pub use fraction_integer::FractionInteger;
 #[cfg(feature = "number-to-chinese")]
 pub use number_to_chinese::*;
@@ -0,0 +1,134 @@
+use core::fmt::{Display, Write};
+
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use super::{
+    functions::{
+        check_big_unsigned_integer_range, display_to_decimal, trailing_unit_position,
+        write_big_unsigned_integer, write_point, write_power_of_ten, write_sign,
+    },
+    ChineseApproximateOptions, ChineseFormatOptions, NumberToChineseError,
+};
+use crate::ChineseApproximate;
+
+/// 依照選項將數值寫成約略的說法，例如 **「三十多」**、**「近三千」**、**「上萬」**、**「數百萬」**。只有整數部分會被用來決定單位，小數部分只會影響是否要加上 **「多」**。
+pub(crate) fn write_approximate<W: Write, V: Display>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    approximate_options: &ChineseApproximateOptions,
+    value: V,
+) -> Result<(), NumberToChineseError> {
+    let (negative, integer, fraction) = match display_to_decimal(f, options, value)? {
+        Some(decimal) => decimal,
+        None => return Ok(()),
+    };
+
+    let method = options.method();
+
+    check_big_unsigned_integer_range(method, &integer).map_err(|err| {
+        if negative {
+            NumberToChineseError::Underflow
+        } else {
+            err
+        }
+    })?;
+
+    // 約略的說法不使用口語的省略寫法，以免 **「三萬五千多」** 變成 **「三萬五多」**
+    let options = &options.with_colloquial(false);
+    let chinese_variant = options.chinese_variant();
+    let more = ChineseApproximate::多.to_str(chinese_variant);
+
+    let has_fraction = fraction.iter().any(|&d| d > 0);
+    let length = if integer.is_zero() { 0 } else { integer.to_radix_be(10).len() };
+
+    // 數值比 `next` 少不超過容許範圍，且 `next` 能被寫出
+    let is_near = |next: &BigUint| {
+        let tolerance = approximate_options.tolerance();
+
+        tolerance > 0
+            && (next - &integer) * 100u8 <= next * tolerance
+            && check_big_unsigned_integer_range(method, next).is_ok()
+    };
+
+    if approximate_options.magnitude() && length >= 2 {
+        write_sign(f, options, negative, false)?;
+
+        let unit = BigUint::from(10u8).pow(length as u32 - 1);
+
+        if is_near(&(&unit * 10u8)) {
+            f.write_str(ChineseApproximate::近.to_str(chinese_variant))?;
+            write_power_of_ten(f, options, length)?;
+        } else {
+            let approximate = if integer < &unit * 2u8 {
+                ChineseApproximate::上
+            } else {
+                ChineseApproximate::數
+            };
+
+            f.write_str(approximate.to_str(chinese_variant))?;
+            write_power_of_ten(f, options, length - 1)?;
+        }
+
+        return Ok(());
+    }
+
+    let significant_digits = approximate_options.significant_digits();
+
+    if length > significant_digits {
+        let position = length - significant_digits;
+
+        let unit = BigUint::from(10u8).pow(position as u32);
+        let kept = &integer / &unit;
+        let dropped = &integer - &kept * &unit;
+
+        write_sign(f, options, negative, false)?;
+
+        if dropped.is_zero() && !has_fraction {
+            write_big_unsigned_integer(f, options, integer)?;
+
+            return Ok(());
+        }
+
+        // 被捨去的部分不到半個單位時，數值只是剛好超過保留的位數，不使用 **「近」**
+        let next = (&kept + 1u8) * &unit;
+
+        if dropped * 2u8 >= unit && is_near(&next) {
+            f.write_str(ChineseApproximate::近.to_str(chinese_variant))?;
+            write_big_unsigned_integer(f, options, next)?;
+
+            return Ok(());
+        }
+
+        let base = kept * unit;
+
+        // **「多」** 要緊接在最後一個保留的位數的單位之後，例如 **「三百多萬」**，而不是 **「三百萬多」**
+        let trailing = trailing_unit_position(method, position);
+
+        if trailing == position {
+            write_big_unsigned_integer(f, options, base)?;
+            f.write_str(more)?;
+        } else {
+            write_big_unsigned_integer(
+                f,
+                options,
+                base / BigUint::from(10u8).pow(trailing as u32),
+            )?;
+            f.write_str(more)?;
+            write_power_of_ten(f, options, trailing)?;
+        }
+
+        return Ok(());
+    }
+
+    write_sign(f, options, negative, integer.is_zero() && !has_fraction)?;
+    write_big_unsigned_integer(f, options, integer)?;
+
+    // 整數部分已經全部寫出，小數部分以 **「點多」** 表示
+    if has_fraction {
+        write_point(f, options)?;
+        f.write_str(more)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+/// 將數值轉成約略的中文說法時所使用的選項，搭配 `NumberToChinese::to_chinese_approximate_with` 使用。
+///
+/// 預設只保留一個有效位數，捨去的部分以 **「多」** 表示，例如 **「三十多」**；比下一個整數少不超過 5% 時以 **「近」** 表示，例如 **「近三千」**。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::ChineseApproximateOptions;
+///
+/// let options = ChineseApproximateOptions::new()
+///     .with_significant_digits(2)
+///     .with_tolerance(10);
+///
+/// assert_eq!(2, options.significant_digits());
+/// assert_eq!(10, options.tolerance());
+/// assert!(!options.magnitude());
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ChineseApproximateOptions {
+    significant_digits: usize,
+    tolerance:          u8,
+    magnitude:          bool,
+}
+
+impl ChineseApproximateOptions {
+    /// 建立預設的選項，保留一個有效位數，**「近」** 的容許範圍為 5%。
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            significant_digits: 1, tolerance: 5, magnitude: false
+        }
+    }
+
+    /// 設定要保留的有效位數，最少為 `1`。例如保留兩位時，`35_123` 會寫成 **「三萬五千多」**。
+    #[inline]
+    pub const fn with_significant_digits(mut self, significant_digits: usize) -> Self {
+        self.significant_digits = if significant_digits > 0 { significant_digits } else { 1 };
+
+        self
+    }
+
+    /// 設定使用 **「近」** 的容許範圍，單位為百分比。被捨去的部分至少有半個單位，且數值比下一個整數少不超過該整數的這個比例時，會寫成 **「近」** 加上該整數。設為 `0` 時不使用 **「近」**。
+    #[inline]
+    pub const fn with_tolerance(mut self, tolerance: u8) -> Self {
+        self.tolerance = tolerance;
+
+        self
+    }
+
+    /// 設定是否只表示數量級。啟用後，最高位數為一時使用 **「上」**，例如 **「上萬」**，其餘使用 **「數」**，例如 **「數百萬」**。
+    #[inline]
+    pub const fn with_magnitude(mut self, magnitude: bool) -> Self {
+        self.magnitude = magnitude;
+
+        self
+    }
+
+    /// 要保留的有效位數。
+    #[inline]
+    pub const fn significant_digits(&self) -> usize {
+        self.significant_digits
+    }
+
+    /// 使用 **「近」** 的容許範圍，單位為百分比。
+    #[inline]
+    pub const fn tolerance(&self) -> u8 {
+        self.tolerance
+    }
+
+    /// 是否只表示數量級。
+    #[inline]
+    pub const fn magnitude(&self) -> bool {
+        self.magnitude
+    }
+}
+
+impl Default for ChineseApproximateOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,177 @@
+use super::{ChineseGlyphStyle, ChineseTaiStyle, ChineseWholeStyle, ChineseYuanStyle};
+use crate::{ChineseCurrency, ChineseVariant};
+
+/// 將金額轉成支票等票據上所使用的中文大寫金額時的選項，搭配 `NumberToChinese::to_chinese_currency_with` 使用。
+///
+/// 預設使用繁體、**「人民幣」**、**「元」** 和 **「整」**，並在金額前寫出貨幣名稱。也可以使用 `new_taiwan_dollar` 或 `hong_kong_dollar` 建立 **「新臺幣」** 或 **「港幣」** 的選項。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{
+///     ChineseCurrencyOptions, ChineseVariant, ChineseWholeStyle,
+///     ChineseYuanStyle,
+/// };
+///
+/// let options = ChineseCurrencyOptions::new()
+///     .with_variant(ChineseVariant::Simple)
+///     .with_yuan_style(ChineseYuanStyle::Round)
+///     .with_whole_style(ChineseWholeStyle::Exact);
+///
+/// assert_eq!(ChineseVariant::Simple, options.chinese_variant());
+/// assert!(options.currency_name());
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ChineseCurrencyOptions {
+    currency:        ChineseCurrency,
+    chinese_variant: ChineseVariant,
+    yuan_style:      ChineseYuanStyle,
+    whole_style:     ChineseWholeStyle,
+    tai_style:       ChineseTaiStyle,
+    glyph_style:     ChineseGlyphStyle,
+    currency_name:   bool,
+}
+
+impl ChineseCurrencyOptions {
+    /// 建立預設的選項，和 `renminbi` 相同。
+    #[inline]
+    pub const fn new() -> Self {
+        Self::renminbi()
+    }
+
+    /// 建立人民幣的選項，例如 **「人民幣壹萬零貳佰元整」**。
+    #[inline]
+    pub const fn renminbi() -> Self {
+        Self {
+            currency:        ChineseCurrency::Renminbi,
+            chinese_variant: ChineseVariant::Traditional,
+            yuan_style:      ChineseYuanStyle::Yuan,
+            whole_style:     ChineseWholeStyle::Whole,
+            tai_style:       ChineseTaiStyle::Formal,
+            glyph_style:     ChineseGlyphStyle::Standard,
+            currency_name:   true,
+        }
+    }
+
+    /// 建立新臺幣的選項，例如 **「新臺幣壹萬貳仟參佰元整」**。
+    ///
+    /// 臺灣的票據也是在中間連續的零寫一個 **「零」**，在 **「元」** 之後沒有 **「角」** 時寫 **「零」**，並在沒有 **「分」** 時寫 **「整」**，所以除了貨幣名稱以外都刻意沿用人民幣的規則。
+    #[inline]
+    pub const fn new_taiwan_dollar() -> Self {
+        Self::renminbi().with_currency(ChineseCurrency::NewTaiwanDollar)
+    }
+
+    /// 建立港幣的選項，使用 **「圓」** 和 **「正」**，例如 **「港幣壹佰貳拾圓伍毫」**、**「港幣壹佰圓正」**。
+    #[inline]
+    pub const fn hong_kong_dollar() -> Self {
+        Self::renminbi()
+            .with_currency(ChineseCurrency::HongKongDollar)
+            .with_yuan_style(ChineseYuanStyle::Round)
+            .with_whole_style(ChineseWholeStyle::Exact)
+    }
+
+    /// 設定貨幣。
+    #[inline]
+    pub const fn with_currency(mut self, currency: ChineseCurrency) -> Self {
+        self.currency = currency;
+
+        self
+    }
+
+    /// 設定要使用繁體或是簡體。
+    #[inline]
+    pub const fn with_variant(mut self, chinese_variant: ChineseVariant) -> Self {
+        self.chinese_variant = chinese_variant;
+
+        self
+    }
+
+    /// 設定主要單位要使用 **「元」** 或是 **「圓」**。
+    #[inline]
+    pub const fn with_yuan_style(mut self, yuan_style: ChineseYuanStyle) -> Self {
+        self.yuan_style = yuan_style;
+
+        self
+    }
+
+    /// 設定結尾要使用 **「整」** 或是 **「正」**。
+    #[inline]
+    pub const fn with_whole_style(mut self, whole_style: ChineseWholeStyle) -> Self {
+        self.whole_style = whole_style;
+
+        self
+    }
+
+    /// 設定新臺幣的名稱要使用 **「臺」** 或是 **「台」**。
+    #[inline]
+    pub const fn with_tai_style(mut self, tai_style: ChineseTaiStyle) -> Self {
+        self.tai_style = tai_style;
+
+        self
+    }
+
+    /// 設定大寫數字所使用的字形，例如 **「人民幣弌陌弍什元整」**。
+    #[inline]
+    pub const fn with_glyph_style(mut self, glyph_style: ChineseGlyphStyle) -> Self {
+        self.glyph_style = glyph_style;
+
+        self
+    }
+
+    /// 設定是否要在金額前寫出貨幣名稱，例如 **「人民幣」**。
+    #[inline]
+    pub const fn with_currency_name(mut self, currency_name: bool) -> Self {
+        self.currency_name = currency_name;
+
+        self
+    }
+
+    /// 貨幣。
+    #[inline]
+    pub const fn currency(&self) -> ChineseCurrency {
+        self.currency
+    }
+
+    /// 使用繁體或是簡體。
+    #[inline]
+    pub const fn chinese_variant(&self) -> ChineseVariant {
+        self.chinese_variant
+    }
+
+    /// 主要單位所使用的字。
+    #[inline]
+    pub const fn yuan_style(&self) -> ChineseYuanStyle {
+        self.yuan_style
+    }
+
+    /// 結尾所使用的字。
+    #[inline]
+    pub const fn whole_style(&self) -> ChineseWholeStyle {
+        self.whole_style
+    }
+
+    /// 新臺幣的名稱中 **「臺」** 的寫法。
+    #[inline]
+    pub const fn tai_style(&self) -> ChineseTaiStyle {
+        self.tai_style
+    }
+
+    /// 大寫數字所使用的字形。
+    #[inline]
+    pub const fn glyph_style(&self) -> ChineseGlyphStyle {
+        self.glyph_style
+    }
+
+    /// 是否要在金額前寫出貨幣名稱。
+    #[inline]
+    pub const fn currency_name(&self) -> bool {
+        self.currency_name
+    }
+}
+
+impl Default for ChineseCurrencyOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
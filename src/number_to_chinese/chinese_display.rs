@@ -0,0 +1,73 @@
+use core::fmt::{self, Display, Formatter, Write};
+
+use super::{ChineseFormatOptions, NumberToChinese};
+
+/// 將數值格式化成中文數字的包裝，可直接用在 `format!`、`write!` 等巨集中。可以透過 `NumberToChinese::to_chinese_display` 或 `NumberToChinese::to_chinese_display_with` 來建立。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{
+///     ChineseCase, ChineseCountMethod, ChineseVariant, NumberToChinese,
+/// };
+///
+/// let display = 123u8
+///     .to_chinese_display(
+///         ChineseVariant::Traditional,
+///         ChineseCase::Lower,
+///         ChineseCountMethod::TenThousand,
+///     )
+///     .unwrap();
+///
+/// assert_eq!("共一百二十三人", format!("共{}人", display));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ChineseDisplay<T> {
+    value:   T,
+    options: ChineseFormatOptions,
+}
+
+impl<T> ChineseDisplay<T> {
+    #[inline]
+    pub(crate) const fn new(value: T, options: ChineseFormatOptions) -> Self {
+        ChineseDisplay {
+            value,
+            options,
+        }
+    }
+
+    /// 取得被包裝的數值。
+    #[inline]
+    pub const fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// 格式化時所使用的選項。
+    #[inline]
+    pub const fn options(&self) -> &ChineseFormatOptions {
+        &self.options
+    }
+
+    /// 取出被包裝的數值。
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: NumberToChinese + Clone> Display for ChineseDisplay<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        self.value.clone().write_chinese_with(f, &self.options).map_err(|_| fmt::Error)
+    }
+}
+
+/// 丟棄所有寫入內容的 `Write`，用來在不配置記憶體的情況下檢查數值能否被轉換。
+pub(crate) struct Discard;
+
+impl Write for Discard {
+    #[inline]
+    fn write_str(&mut self, _s: &str) -> Result<(), fmt::Error> {
+        Ok(())
+    }
+}
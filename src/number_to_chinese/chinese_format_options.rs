@@ -0,0 +1,310 @@
+use super::{
+    ChineseFractionOptions, ChineseFractionStyle, ChineseGlyphStyle, ChineseNegativeStyle,
+    ChinesePositiveStyle, ChineseScript, ChineseSignStyle, ChineseTenStyle, ChineseTwoStyle,
+    ChineseZeroStyle,
+};
+use crate::{ChineseCase, ChineseCountMethod, ChineseDigitSeparator, ChineseVariant};
+
+/// 將數值轉成中文數字時所使用的所有選項，搭配 `NumberToChinese::to_chinese_with` 使用。
+///
+/// 預設使用繁體、小寫、**「萬進」**，小數部分使用 **「角」** 和 **「分」** 表示，零使用 **「零」**，二使用 **「二」**，只在數值開頭省略 **「十」** 前的 **「一」**，只在負數前加上 **「負」**，不使用口語的省略寫法，逐位寫出數字時不分隔位組。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{
+///     ChineseCase, ChineseCountMethod, ChineseFormatOptions,
+///     ChineseFractionStyle, ChineseTwoStyle, ChineseVariant, NumberToChinese,
+/// };
+///
+/// let options = ChineseFormatOptions::new()
+///     .with_variant(ChineseVariant::Simple)
+///     .with_two_style(ChineseTwoStyle::Liang);
+///
+/// assert_eq!("两万两千二百", 22200.to_chinese_with(&options).unwrap());
+///
+/// let options = ChineseFormatOptions::new()
+///     .with_case(ChineseCase::Upper)
+///     .with_method(ChineseCountMethod::Middle)
+///     .with_fraction_style(ChineseFractionStyle::Decimal);
+///
+/// assert_eq!("壹萬點伍", 10000.5.to_chinese_with(&options).unwrap());
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ChineseFormatOptions {
+    chinese_variant:  ChineseVariant,
+    script:           ChineseScript,
+    chinese_case:     ChineseCase,
+    method:           ChineseCountMethod,
+    fraction_style:   ChineseFractionStyle,
+    fraction_options: Option<ChineseFractionOptions>,
+    zero_style:       ChineseZeroStyle,
+    two_style:        ChineseTwoStyle,
+    ten_style:        ChineseTenStyle,
+    glyph_style:      ChineseGlyphStyle,
+    sign_style:       ChineseSignStyle,
+    negative_style:   ChineseNegativeStyle,
+    positive_style:   ChinesePositiveStyle,
+    colloquial:       bool,
+    digit_grouping:   Option<(usize, ChineseDigitSeparator)>,
+}
+
+impl ChineseFormatOptions {
+    /// 建立預設的選項。
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            chinese_variant:  ChineseVariant::Traditional,
+            script:           ChineseScript::Chinese,
+            chinese_case:     ChineseCase::Lower,
+            method:           ChineseCountMethod::TenThousand,
+            fraction_style:   ChineseFractionStyle::Unit,
+            fraction_options: None,
+            zero_style:       ChineseZeroStyle::Ling,
+            two_style:        ChineseTwoStyle::Er,
+            ten_style:        ChineseTenStyle::Natural,
+            glyph_style:      ChineseGlyphStyle::Standard,
+            sign_style:       ChineseSignStyle::Negative,
+            negative_style:   ChineseNegativeStyle::Fu,
+            positive_style:   ChinesePositiveStyle::Zheng,
+            colloquial:       false,
+            digit_grouping:   None,
+        }
+    }
+
+    /// 設定要使用繁體或是簡體。
+    #[inline]
+    pub const fn with_variant(mut self, chinese_variant: ChineseVariant) -> Self {
+        self.chinese_variant = chinese_variant;
+
+        self
+    }
+
+    /// 設定要使用中文數字或是日文的漢數字。
+    #[inline]
+    pub const fn with_script(mut self, script: ChineseScript) -> Self {
+        self.script = script;
+
+        self
+    }
+
+    /// 設定要使用大寫或是小寫數字。
+    #[inline]
+    pub const fn with_case(mut self, chinese_case: ChineseCase) -> Self {
+        self.chinese_case = chinese_case;
+
+        self
+    }
+
+    /// 設定大的單位的算術類型。
+    #[inline]
+    pub const fn with_method(mut self, method: ChineseCountMethod) -> Self {
+        self.method = method;
+
+        self
+    }
+
+    /// 設定浮點數小數部分的表示方式。
+    #[inline]
+    pub const fn with_fraction_style(mut self, fraction_style: ChineseFractionStyle) -> Self {
+        self.fraction_style = fraction_style;
+
+        self
+    }
+
+    /// 設定浮點數小數部分的位數和進位方式，以及 NaN 和無窮大的處理方式。
+    ///
+    /// 如果沒有設定，使用 **「角」** 和 **「分」** 表示時會套用 `ChineseFractionOptions::new()`，使用 **「點」** 表示時則會保留所有位數。
+    #[inline]
+    pub const fn with_fraction_options(mut self, fraction_options: ChineseFractionOptions) -> Self {
+        self.fraction_options = Some(fraction_options);
+
+        self
+    }
+
+    /// 設定數字零所使用的字形。
+    #[inline]
+    pub const fn with_zero_style(mut self, zero_style: ChineseZeroStyle) -> Self {
+        self.zero_style = zero_style;
+
+        self
+    }
+
+    /// 設定數字二所使用的字形。
+    #[inline]
+    pub const fn with_two_style(mut self, two_style: ChineseTwoStyle) -> Self {
+        self.two_style = two_style;
+
+        self
+    }
+
+    /// 設定 **「十」** 前的 **「一」** 的寫法。
+    #[inline]
+    pub const fn with_ten_style(mut self, ten_style: ChineseTenStyle) -> Self {
+        self.ten_style = ten_style;
+
+        self
+    }
+
+    /// 設定大寫數字所使用的字形。
+    #[inline]
+    pub const fn with_glyph_style(mut self, glyph_style: ChineseGlyphStyle) -> Self {
+        self.glyph_style = glyph_style;
+
+        self
+    }
+
+    /// 設定正負號的表示方式。
+    #[inline]
+    pub const fn with_sign_style(mut self, sign_style: ChineseSignStyle) -> Self {
+        self.sign_style = sign_style;
+
+        self
+    }
+
+    /// 設定負數前所加上的負號，例如溫度使用的 **「零下」**。
+    #[inline]
+    pub const fn with_negative_style(mut self, negative_style: ChineseNegativeStyle) -> Self {
+        self.negative_style = negative_style;
+
+        self
+    }
+
+    /// 設定正負號的表示方式為 `ChineseSignStyle::Explicit` 時，正數前所加上的正號。
+    #[inline]
+    pub const fn with_positive_style(mut self, positive_style: ChinesePositiveStyle) -> Self {
+        self.positive_style = positive_style;
+
+        self
+    }
+
+    /// 設定整數是否使用口語的省略寫法。啟用後，如果最後一個非零位數緊接在前一個單位之下，就省略這個位數之後的單位，例如 **「一百二」**、**「三萬四」**、**「兩千三」**。
+    ///
+    /// 這個寫法能被 `ChineseToNumber` 解析回原本的數值。浮點數不使用這個寫法。
+    #[inline]
+    pub const fn with_colloquial(mut self, colloquial: bool) -> Self {
+        self.colloquial = colloquial;
+
+        self
+    }
+
+    /// 設定逐位寫出數字（`to_chinese_naive_with`）時，由個位數開始每 `size` 個位數使用 `separator` 分隔，例如 **「一二，三四五，六七八」**。`size` 為 `0` 時不分隔。
+    ///
+    /// 只有整數部分會被分隔。分隔後的數字能被 `ChineseToNumber::to_number_naive` 解析回原本的數值。
+    #[inline]
+    pub const fn with_digit_grouping(
+        mut self,
+        size: usize,
+        separator: ChineseDigitSeparator,
+    ) -> Self {
+        self.digit_grouping = if size > 0 { Some((size, separator)) } else { None };
+
+        self
+    }
+
+    /// 使用繁體或是簡體。
+    #[inline]
+    pub const fn chinese_variant(&self) -> ChineseVariant {
+        self.chinese_variant
+    }
+
+    /// 使用中文數字或是日文的漢數字。
+    #[inline]
+    pub const fn script(&self) -> ChineseScript {
+        self.script
+    }
+
+    /// 使用大寫或是小寫數字。
+    #[inline]
+    pub const fn chinese_case(&self) -> ChineseCase {
+        self.chinese_case
+    }
+
+    /// 大的單位的算術類型。
+    #[inline]
+    pub const fn method(&self) -> ChineseCountMethod {
+        self.method
+    }
+
+    /// 浮點數小數部分的表示方式。
+    #[inline]
+    pub const fn fraction_style(&self) -> ChineseFractionStyle {
+        self.fraction_style
+    }
+
+    /// 浮點數小數部分的位數和進位方式。
+    #[inline]
+    pub const fn fraction_options(&self) -> Option<ChineseFractionOptions> {
+        self.fraction_options
+    }
+
+    /// 數字零所使用的字形。
+    #[inline]
+    pub const fn zero_style(&self) -> ChineseZeroStyle {
+        self.zero_style
+    }
+
+    /// 數字二所使用的字形。
+    #[inline]
+    pub const fn two_style(&self) -> ChineseTwoStyle {
+        self.two_style
+    }
+
+    /// **「十」** 前的 **「一」** 的寫法。
+    #[inline]
+    pub const fn ten_style(&self) -> ChineseTenStyle {
+        self.ten_style
+    }
+
+    /// 大寫數字所使用的字形。
+    #[inline]
+    pub const fn glyph_style(&self) -> ChineseGlyphStyle {
+        self.glyph_style
+    }
+
+    /// 正負號的表示方式。
+    #[inline]
+    pub const fn sign_style(&self) -> ChineseSignStyle {
+        self.sign_style
+    }
+
+    /// 負數前所加上的負號。
+    #[inline]
+    pub const fn negative_style(&self) -> ChineseNegativeStyle {
+        self.negative_style
+    }
+
+    /// 正數前所加上的正號。
+    #[inline]
+    pub const fn positive_style(&self) -> ChinesePositiveStyle {
+        self.positive_style
+    }
+
+    /// 整數是否使用口語的省略寫法。
+    #[inline]
+    pub const fn colloquial(&self) -> bool {
+        self.colloquial
+    }
+
+    /// 逐位寫出數字時，每個位組的位數和分隔符號。
+    #[inline]
+    pub const fn digit_grouping(&self) -> Option<(usize, ChineseDigitSeparator)> {
+        self.digit_grouping
+    }
+}
+
+impl Default for ChineseFormatOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<(ChineseVariant, ChineseCase, ChineseCountMethod)> for ChineseFormatOptions {
+    #[inline]
+    fn from(
+        (chinese_variant, chinese_case, method): (ChineseVariant, ChineseCase, ChineseCountMethod),
+    ) -> Self {
+        Self::new().with_variant(chinese_variant).with_case(chinese_case).with_method(method)
+    }
+}
@@ -0,0 +1,83 @@
+use super::ChineseRounding;
+
+/// 將浮點數轉成中文數字時，小數部分的位數和進位方式，以及 NaN 和無窮大的處理方式。
+///
+/// 預設保留兩位小數並且四捨五入，遇到 NaN 和無窮大時回傳錯誤。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{ChineseFractionOptions, ChineseRounding};
+///
+/// let options = ChineseFractionOptions::new()
+///     .with_precision(1)
+///     .with_rounding(ChineseRounding::Truncate);
+///
+/// assert_eq!(1, options.precision());
+/// assert_eq!(ChineseRounding::Truncate, options.rounding());
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ChineseFractionOptions {
+    precision:  usize,
+    rounding:   ChineseRounding,
+    non_finite: bool,
+}
+
+impl ChineseFractionOptions {
+    /// 建立預設的選項，保留兩位小數並且四捨五入。
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            precision: 2, rounding: ChineseRounding::HalfUp, non_finite: false
+        }
+    }
+
+    /// 設定要保留的小數位數。使用 **「角」** 和 **「分」** 表示時，超過兩位的部分會接著使用 **「厘」**、**「毫」**、**「絲」**、**「忽」**、**「微」**、**「纖」**、**「沙」**、**「塵」**，所以最多只會保留十位。
+    #[inline]
+    pub const fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+
+        self
+    }
+
+    /// 設定捨去多餘小數位數時所使用的進位方式。
+    #[inline]
+    pub const fn with_rounding(mut self, rounding: ChineseRounding) -> Self {
+        self.rounding = rounding;
+
+        self
+    }
+
+    /// 設定是否要將 NaN 和無窮大轉成 **「非數」**、**「無窮大」** 和 **「負無窮大」**。如果不允許，則會回傳錯誤。預設為不允許。
+    #[inline]
+    pub const fn with_non_finite(mut self, non_finite: bool) -> Self {
+        self.non_finite = non_finite;
+
+        self
+    }
+
+    /// 要保留的小數位數。
+    #[inline]
+    pub const fn precision(&self) -> usize {
+        self.precision
+    }
+
+    /// 捨去多餘小數位數時所使用的進位方式。
+    #[inline]
+    pub const fn rounding(&self) -> ChineseRounding {
+        self.rounding
+    }
+
+    /// 是否要將 NaN 和無窮大轉成中文。
+    #[inline]
+    pub const fn non_finite(&self) -> bool {
+        self.non_finite
+    }
+}
+
+impl Default for ChineseFractionOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
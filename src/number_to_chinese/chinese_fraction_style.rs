@@ -0,0 +1,15 @@
+/// 浮點數小數部分的表示方式。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ChineseFractionStyle {
+    /// 使用 **「角」** 和 **「分」** 表示，例如 **「一二角五分」**。日文的漢數字不使用這個方式，而是使用 **「点」**。
+    Unit,
+    /// 使用 **「點」** 逐位表示，例如 **「一點二五」**。
+    Decimal,
+}
+
+impl Default for ChineseFractionStyle {
+    #[inline]
+    fn default() -> Self {
+        ChineseFractionStyle::Unit
+    }
+}
@@ -0,0 +1,15 @@
+/// 大寫數字所使用的字形。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ChineseGlyphStyle {
+    /// 使用現行的大寫數字，例如 **「壹佰貳拾參」**。
+    Standard,
+    /// 一、二、三、十、百、千使用古代帳冊上防止竄改的字形 **「弌」**、**「弍」**、**「弎」**、**「什」**、**「陌」**、**「阡」**，例如 **「弌陌弍什弎」**。其餘的數字和單位仍使用現行的大寫數字。只有大寫數字有效。
+    Ancient,
+}
+
+impl Default for ChineseGlyphStyle {
+    #[inline]
+    fn default() -> Self {
+        ChineseGlyphStyle::Standard
+    }
+}
@@ -0,0 +1,21 @@
+/// 負數前所加上的負號。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ChineseNegativeStyle {
+    /// 使用 **「負」**，例如 **「負五」**。
+    Fu,
+    /// 使用 **「減」**，例如 **「減五」**。常見於帳目。
+    Jian,
+    /// 使用 **「零下」**，例如 **「零下五」**。用於溫度。
+    LingXia,
+    /// 使用數學的減號 **「−」**（U+2212），例如 **「−五」**。
+    Minus,
+    /// 使用 ASCII 的連字減號 **「-」**，例如 **「-五」**。
+    HyphenMinus,
+}
+
+impl Default for ChineseNegativeStyle {
+    #[inline]
+    fn default() -> Self {
+        ChineseNegativeStyle::Fu
+    }
+}
@@ -0,0 +1,15 @@
+/// 正負號的表示方式為 `ChineseSignStyle::Explicit` 時，正數前所加上的正號。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ChinesePositiveStyle {
+    /// 使用 **「正」**，例如 **「正三」**。
+    Zheng,
+    /// 使用 ASCII 的加號 **「+」**，例如 **「+三」**。
+    Plus,
+}
+
+impl Default for ChinesePositiveStyle {
+    #[inline]
+    fn default() -> Self {
+        ChinesePositiveStyle::Zheng
+    }
+}
@@ -0,0 +1,19 @@
+/// 捨去多餘小數位數時所使用的進位方式。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ChineseRounding {
+    /// 四捨五入，剛好一半時遠離零。
+    HalfUp,
+    /// 四捨六入五成雙，剛好一半時進位到偶數。
+    HalfEven,
+    /// 無條件捨去，朝零的方向捨去。適合用在支票等不能多算金額的場合。
+    Truncate,
+    /// 無條件進位，朝正無窮大的方向進位。
+    Ceiling,
+}
+
+impl Default for ChineseRounding {
+    #[inline]
+    fn default() -> Self {
+        ChineseRounding::HalfUp
+    }
+}
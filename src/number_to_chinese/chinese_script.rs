@@ -0,0 +1,22 @@
+/// 數字和單位所使用的文字。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ChineseScript {
+    /// 使用中文數字，依照 `ChineseVariant` 使用繁體或是簡體。
+    Chinese,
+    /// 使用日文的漢數字，例如 **「千二百三万四千五」**。數字、單位和小數點不受 `ChineseVariant` 影響，但正負號、**「非數」** 等數字以外的文字仍然依照 `ChineseVariant` 寫出，例如簡體的 **「负三」**。
+    ///
+    /// * 小寫數字省略 **「十」**、**「百」**、**「千」** 前的 **「一」**；大寫數字（大字）使用 **「壱」**、**「弐」**、**「参」**、**「拾」** 和 **「萬」**，並寫出單位前的 **「壱」**，例如 **「壱萬弐千参拾」**。
+    /// * 數字中間的零不寫出來，例如 **「千一」**。零本身寫成 **「〇」**，大寫數字寫成 **「零」**。
+    /// * 大的單位使用 **「万」**、**「億」**、**「兆」**、**「京」**、**「垓」**、**「𥝱」**、**「穣」** 等字形。
+    /// * 小數部分一律使用 **「点」** 逐位表示，例如 **「三点五」**。日文的 **「分」** 是十分之一，和中文的 **「分」** 不同，所以不使用 `ChineseFractionStyle::Unit`。
+    /// * 分數使用 **「分の」**，例如 **「三分の一」**。
+    /// * 不使用口語的省略寫法、**「兩」** 和古代帳冊上的字形。
+    Japanese,
+}
+
+impl Default for ChineseScript {
+    #[inline]
+    fn default() -> Self {
+        ChineseScript::Chinese
+    }
+}
@@ -0,0 +1,15 @@
+/// 正負號的表示方式。負號和正號所使用的字可以分別用 `ChineseNegativeStyle` 和 `ChinesePositiveStyle` 設定。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ChineseSignStyle {
+    /// 只在負數前加上負號，例如 **「負」**。
+    Negative,
+    /// 在負數前加上負號，並在不為零的正數前加上正號，例如 **「正」**。
+    Explicit,
+}
+
+impl Default for ChineseSignStyle {
+    #[inline]
+    fn default() -> Self {
+        ChineseSignStyle::Negative
+    }
+}
@@ -0,0 +1,15 @@
+/// 新臺幣的名稱中 **「臺」** 的寫法。簡體一律使用 **「台」**。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ChineseTaiStyle {
+    /// 使用 **「臺」**，例如 **「新臺幣壹萬元整」**。政府機關的公文書使用這個寫法。
+    Formal,
+    /// 使用 **「台」**，例如 **「新台幣壹萬元整」**。
+    Common,
+}
+
+impl Default for ChineseTaiStyle {
+    #[inline]
+    fn default() -> Self {
+        ChineseTaiStyle::Formal
+    }
+}
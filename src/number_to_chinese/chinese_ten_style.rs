@@ -0,0 +1,17 @@
+/// **「十」** 前的 **「一」** 的寫法。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ChineseTenStyle {
+    /// 只在整個數值的開頭省略 **「一」**，例如 **「十」**、**「十萬」**、**「一萬零一十」**。
+    Natural,
+    /// 在整個數值以及每個 **「萬」**、**「億」** 等單位的位組開頭都省略 **「一」**，例如 **「十」**、**「一萬零十」**、**「一億零十萬」**。
+    Short,
+    /// 一律寫出 **「一」**，例如 **「一十」**、**「壹拾萬」**。適合用在財務等場合。
+    Full,
+}
+
+impl Default for ChineseTenStyle {
+    #[inline]
+    fn default() -> Self {
+        ChineseTenStyle::Natural
+    }
+}
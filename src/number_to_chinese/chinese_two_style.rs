@@ -0,0 +1,18 @@
+/// 數字二所使用的字形。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ChineseTwoStyle {
+    /// 一律使用 **「二」**。
+    Er,
+    /// 依照口語習慣在單位前使用 **「兩」**，其餘位置仍使用 **「二」**。只有小寫數字有效。
+    ///
+    /// * 繁體：在 **「百」** 以及更大的單位前都使用 **「兩」**，例如 **「兩百」**、**「三千兩百」**、**「兩萬」**。
+    /// * 簡體：在 **「千」** 以及更大的單位前使用 **「两」**，例如 **「两千」**、**「两万」**、**「两亿」**；在 **「百」** 前只有當它是整個數值的第一個位數時才使用 **「两」**，例如 **「两百二十」**，但是 **「三千二百」**。
+    Liang,
+}
+
+impl Default for ChineseTwoStyle {
+    #[inline]
+    fn default() -> Self {
+        ChineseTwoStyle::Er
+    }
+}
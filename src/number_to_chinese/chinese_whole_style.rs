@@ -0,0 +1,15 @@
+/// 金額沒有寫到最小的單位時，結尾所使用的字。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ChineseWholeStyle {
+    /// 使用 **「整」**，例如 **「人民幣壹佰元整」**。
+    Whole,
+    /// 使用 **「正」**，例如 **「人民幣壹佰元正」**。
+    Exact,
+}
+
+impl Default for ChineseWholeStyle {
+    #[inline]
+    fn default() -> Self {
+        ChineseWholeStyle::Whole
+    }
+}
@@ -0,0 +1,15 @@
+/// 金額的主要單位所使用的字。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ChineseYuanStyle {
+    /// 使用 **「元」**，例如 **「人民幣壹佰元整」**。
+    Yuan,
+    /// 使用 **「圓」**，簡體為 **「圆」**，例如 **「人民幣壹佰圓整」**。
+    Round,
+}
+
+impl Default for ChineseYuanStyle {
+    #[inline]
+    fn default() -> Self {
+        ChineseYuanStyle::Yuan
+    }
+}
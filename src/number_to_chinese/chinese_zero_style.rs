@@ -0,0 +1,15 @@
+/// 數字零所使用的字形。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ChineseZeroStyle {
+    /// 使用 **「零」**，大寫數字也使用 **「零」**。
+    Ling,
+    /// 使用 **「〇」**，常見於年份和編號，例如 **「二〇二四」**。
+    Circle,
+}
+
+impl Default for ChineseZeroStyle {
+    #[inline]
+    fn default() -> Self {
+        ChineseZeroStyle::Ling
+    }
+}
@@ -0,0 +1,83 @@
+use core::fmt::Write;
+
+use super::{
+    functions::{write_number, write_unsigned_integer},
+    ChineseCurrencyOptions, ChineseFormatOptions, ChineseTenStyle, ChineseWholeStyle,
+    ChineseYuanStyle, NumberToChineseError,
+};
+use crate::{ChineseCase, ChineseCurrencyUnit};
+
+/// 依照選項將以分為單位的金額寫成票據上所使用的中文大寫金額，例如 **「人民幣壹萬零貳佰元整」**。
+///
+/// * 整數部分一律寫出 **「拾」** 前的 **「壹」**，中間連續的零只寫一個 **「零」**。
+/// * 元位是零而角位不是零時，在 **「元」** 之後寫 **「零」**，例如 **「壹仟陸佰捌拾元零參角貳分」**。港幣不寫，例如 **「港幣壹佰貳拾圓伍毫」**。
+/// * 角位是零而分位不是零時，在 **「元」** 之後寫 **「零」**，例如 **「參佰貳拾伍元零肆分」**。
+/// * 沒有寫到 **「分」** 時，在最後寫 **「整」** 或 **「正」**。港幣只有在沒有寫到 **「毫」** 和 **「仙」** 時才寫，例如 **「港幣壹佰貳拾圓伍毫」**。
+///
+/// 港幣使用 **「毫」** 和 **「仙」** 代替 **「角」** 和 **「分」**。
+pub(crate) fn write_currency<W: Write>(
+    f: &mut W,
+    options: &ChineseCurrencyOptions,
+    amount: i128,
+) -> Result<(), NumberToChineseError> {
+    if amount < 0 {
+        return Err(NumberToChineseError::Underflow);
+    }
+
+    let chinese_variant = options.chinese_variant();
+
+    let format_options = ChineseFormatOptions::new()
+        .with_variant(chinese_variant)
+        .with_case(ChineseCase::Upper)
+        .with_ten_style(ChineseTenStyle::Full)
+        .with_glyph_style(options.glyph_style());
+
+    let amount = amount as u128;
+    let yuan = amount / 100;
+    let jiao = (amount / 10 % 10) as u8;
+    let fen = (amount % 10) as u8;
+
+    let currency = options.currency();
+    let (tenth_unit, hundredth_unit) = currency.minor_units();
+
+    if options.currency_name() {
+        f.write_str(currency.to_str(chinese_variant, options.tai_style()))?;
+    }
+
+    let yuan_unit = match options.yuan_style() {
+        ChineseYuanStyle::Yuan => ChineseCurrencyUnit::元,
+        ChineseYuanStyle::Round => ChineseCurrencyUnit::圓,
+    };
+
+    // 不足一元的金額直接從角或分開始寫
+    if yuan > 0 || (jiao == 0 && fen == 0) {
+        write_unsigned_integer(f, &format_options, yuan)?;
+        f.write_str(yuan_unit.to_str(chinese_variant))?;
+
+        if yuan > 0
+            && ((jiao > 0 && yuan % 10 == 0 && currency.zero_before_tenths())
+                || (jiao == 0 && fen > 0))
+        {
+            write_number(f, &format_options, 0, None, false)?;
+        }
+    }
+
+    if jiao > 0 {
+        write_number(f, &format_options, jiao, None, false)?;
+        f.write_str(tenth_unit.to_str(chinese_variant))?;
+    }
+
+    if fen > 0 {
+        write_number(f, &format_options, fen, None, false)?;
+        f.write_str(hundredth_unit.to_str(chinese_variant))?;
+    } else if jiao == 0 || currency.whole_after_tenths() {
+        let whole = match options.whole_style() {
+            ChineseWholeStyle::Whole => ChineseCurrencyUnit::整,
+            ChineseWholeStyle::Exact => ChineseCurrencyUnit::正,
+        };
+
+        f.write_str(whole.to_str(chinese_variant))?;
+    }
+
+    Ok(())
+}
@@ -1,620 +1,1241 @@
-use alloc::string::{String, ToString};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{self, Display, Write};
 
 use num_bigint::BigUint;
 #[cfg(not(feature = "std"))]
 #[allow(unused_imports)]
 use num_traits::float::FloatCore;
-use num_traits::{FromPrimitive, ToPrimitive, Zero};
+use num_traits::{Num, ToPrimitive, Zero};
+
+use super::{
+    ChineseFormatOptions, ChineseFractionOptions, ChineseFractionStyle, ChineseGlyphStyle,
+    ChineseNegativeStyle, ChinesePositiveStyle, ChineseRounding, ChineseScript, ChineseSignStyle,
+    ChineseTenStyle, ChineseTwoStyle, ChineseZeroStyle, NumberToChineseError,
+};
+use crate::{
+    ChineseCase, ChineseCountMethod, ChineseExponent, ChineseFraction, ChineseNonFinite,
+    ChineseNumber, ChinesePercent, ChinesePoint, ChineseSign, ChineseVariant,
+};
+
+/// 寫出一個位數。`unit` 是緊接在這個位數之後的單位，`leading` 表示這個位數是否為整個數值的第一個位數，兩者用來決定是否要使用 **「兩」**。
+#[inline]
+pub(crate) fn write_number<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    d: u8,
+    unit: Option<ChineseExponent>,
+    leading: bool,
+) -> fmt::Result {
+    let chinese_variant = options.chinese_variant();
+    let chinese_case = options.chinese_case();
+
+    let n = unsafe { ChineseNumber::from_ordinal_unsafe(d) };
+
+    if options.script() == ChineseScript::Japanese {
+        return f.write_str(n.to_japanese_str(chinese_case));
+    }
 
-use crate::{ChineseCase, ChineseCountMethod, ChineseExponent, ChineseNumber, ChineseVariant};
+    let s = match n {
+        ChineseNumber::零 => match options.zero_style() {
+            ChineseZeroStyle::Ling => ChineseNumber::零.to_str(chinese_variant, chinese_case),
+            ChineseZeroStyle::Circle => "〇",
+        },
+        ChineseNumber::二
+            if options.two_style() == ChineseTwoStyle::Liang
+                && chinese_case == ChineseCase::Lower
+                && is_liang_unit(chinese_variant, unit, leading) =>
+        {
+            match chinese_variant {
+                ChineseVariant::Traditional => "兩",
+                ChineseVariant::Simple => "两",
+            }
+        },
+        n => match n.to_ancient_str() {
+            Some(s) if is_ancient(options) => s,
+            _ => n.to_str(chinese_variant, chinese_case),
+        },
+    };
 
-pub(crate) fn unsigned_integer_to_chinese_low(
+    f.write_str(s)
+}
+
+/// 判斷是否要使用古代帳冊上防止竄改的大寫數字。
+#[inline]
+fn is_ancient(options: &ChineseFormatOptions) -> bool {
+    options.chinese_case() == ChineseCase::Upper
+        && options.glyph_style() == ChineseGlyphStyle::Ancient
+}
+
+/// 判斷位於 `unit` 之前的 **「二」** 是否要寫成 **「兩」**。
+///
+/// * 繁體：在 **「百」** 以及更大的單位前都使用 **「兩」**，例如 **「三千兩百」**。
+/// * 簡體：在 **「千」** 以及更大的單位前使用 **「两」**；在 **「百」** 前只有當它是整個數值的第一個位數時才使用 **「两」**，例如 **「两百二十」**、**「三千二百」**。
+#[inline]
+fn is_liang_unit(
     chinese_variant: ChineseVariant,
-    chinese_case: ChineseCase,
-    dependent: bool,
-    mut value: u128,
-) -> String {
-    debug_assert!(value < 1_0000_0000_0000_0000);
+    unit: Option<ChineseExponent>,
+    leading: bool,
+) -> bool {
+    match unit {
+        Some(unit) => match chinese_variant {
+            ChineseVariant::Traditional => unit >= ChineseExponent::百,
+            ChineseVariant::Simple => {
+                unit >= ChineseExponent::千 || (unit == ChineseExponent::百 && leading)
+            },
+        },
+        None => false,
+    }
+}
 
-    let mut s = String::new();
+/// 位組之後的單位只有在整個位組的值是 `2` 時才會影響 **「兩」**，例如 **「兩萬」**、**「十二萬」**，所以其它位組不把單位傳給位組內的位數。
+#[inline]
+fn liang_unit<G: PartialEq + From<u8>>(
+    unit: Option<ChineseExponent>,
+    group: &G,
+) -> Option<ChineseExponent> {
+    if *group == G::from(2) {
+        unit
+    } else {
+        None
+    }
+}
 
-    let mut lower_d = (value % 10) as u8;
-    value /= 10;
+#[inline]
+fn write_zero<W: Write>(f: &mut W, options: &ChineseFormatOptions) -> fmt::Result {
+    write_number(f, options, 0, None, false)
+}
 
-    if lower_d > 0 {
-        s.push_str(
-            unsafe { ChineseNumber::from_ordinal_unsafe(lower_d) }
-                .to_str(chinese_variant, chinese_case),
-        );
-    } else if value == 0 {
-        return ChineseNumber::零.to_str(chinese_variant, chinese_case).to_string();
+#[inline]
+pub(crate) fn write_exponent<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    exponent: ChineseExponent,
+) -> fmt::Result {
+    if options.script() == ChineseScript::Japanese {
+        return f.write_str(exponent.to_japanese_str(options.chinese_case()));
     }
 
-    let d = (value % 10) as u8;
-    value /= 10;
+    match exponent.to_ancient_str() {
+        Some(s) if is_ancient(options) => f.write_str(s),
+        _ => f.write_str(exponent.to_str(options.chinese_variant(), options.chinese_case())),
+    }
+}
 
-    if d > 0 {
-        s.insert_str(0, ChineseExponent::十.to_str(chinese_variant, chinese_case));
+/// 寫出小數點。日文的漢數字使用 **「点」**。
+#[inline]
+pub(crate) fn write_point<W: Write>(f: &mut W, options: &ChineseFormatOptions) -> fmt::Result {
+    let chinese_variant = match options.script() {
+        ChineseScript::Chinese => options.chinese_variant(),
+        ChineseScript::Japanese => ChineseVariant::Simple,
+    };
 
-        if value > 0 || dependent || d > 1 {
-            s.insert_str(
-                0,
-                unsafe { ChineseNumber::from_ordinal_unsafe(d) }
-                    .to_str(chinese_variant, chinese_case),
-            );
-        }
+    f.write_str(ChinesePoint::to_str(chinese_variant))
+}
+
+/// 寫出分數的 **「分之」**。日文的漢數字使用 **「分の」**。
+#[inline]
+pub(crate) fn write_fraction_separator<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+) -> fmt::Result {
+    f.write_str(match options.script() {
+        ChineseScript::Chinese => ChineseFraction::to_str(),
+        ChineseScript::Japanese => ChineseFraction::to_japanese_str(),
+    })
+}
+
+/// 依照選項寫出正負號。零不會有正負號。
+#[inline]
+pub(crate) fn write_sign<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    negative: bool,
+    is_zero: bool,
+) -> fmt::Result {
+    if is_zero {
+        return Ok(());
     }
 
-    if value == 0 {
-        return s;
+    let chinese_variant = options.chinese_variant();
+
+    if negative {
+        f.write_str(match options.negative_style() {
+            ChineseNegativeStyle::Fu => ChineseSign::負.to_str(chinese_variant),
+            ChineseNegativeStyle::Jian => match chinese_variant {
+                ChineseVariant::Traditional => "減",
+                ChineseVariant::Simple => "减",
+            },
+            ChineseNegativeStyle::LingXia => "零下",
+            ChineseNegativeStyle::Minus => "−",
+            ChineseNegativeStyle::HyphenMinus => "-",
+        })
+    } else if options.sign_style() == ChineseSignStyle::Explicit {
+        f.write_str(match options.positive_style() {
+            ChinesePositiveStyle::Zheng => ChineseSign::正.to_str(chinese_variant),
+            ChinesePositiveStyle::Plus => "+",
+        })
+    } else {
+        Ok(())
     }
+}
 
-    lower_d = d;
+/// 由最高位組開始依序寫出每個位組。`groups` 的索引 `0` 為最低位組，且最高位組不能為 `0`。日文的漢數字不在位組之間寫出零。
+///
+/// `write_group` 的參數依序為是否要寫出開頭的 **「一」**、位組的索引、緊接在位組之後的單位和位組的值。
+#[allow(clippy::too_many_arguments)]
+fn write_groups<W: Write, G: Zero>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    dependent: bool,
+    trailing: Option<ChineseExponent>,
+    groups: &[G],
+    first_exponent: ChineseExponent,
+    is_short: impl Fn(usize, &G) -> bool,
+    mut write_group: impl FnMut(&mut W, bool, usize, Option<ChineseExponent>, &G) -> fmt::Result,
+) -> fmt::Result {
+    let length = groups.len();
+
+    for p in (0..length).rev() {
+        let d = &groups[p];
+
+        if d.is_zero() {
+            continue;
+        }
 
-    let mut i = ChineseExponent::百.ordinal();
+        let unit = if p > 0 {
+            Some(unsafe {
+                ChineseExponent::from_ordinal_unsafe(first_exponent.ordinal() + p as u8 - 1)
+            })
+        } else {
+            trailing
+        };
 
-    loop {
-        let d = (value % 10) as u8;
-        value /= 10;
+        write_group(f, dependent || p + 1 < length, p, unit, d)?;
 
-        if d > 0 {
-            if lower_d < 1 && !s.is_empty() {
-                s.insert_str(0, ChineseNumber::零.to_str(chinese_variant, chinese_case));
-            }
+        if p > 0 {
+            write_exponent(f, options, unit.unwrap())?;
 
-            s.insert_str(
-                0,
-                unsafe { ChineseExponent::from_ordinal_unsafe(i) }
-                    .to_str(chinese_variant, chinese_case),
-            );
-
-            s.insert_str(
-                0,
-                unsafe { ChineseNumber::from_ordinal_unsafe(d) }
-                    .to_str(chinese_variant, chinese_case),
-            );
+            if options.script() == ChineseScript::Chinese
+                && is_short(p - 1, &groups[p - 1])
+                && groups[..p].iter().any(|d| !d.is_zero())
+            {
+                write_zero(f, options)?;
+            }
         }
+    }
 
-        if value == 0 {
-            break;
-        }
+    Ok(())
+}
 
-        lower_d = d;
+/// 將 `value` 以 `width` 為單位拆成位組，放進 `groups` 中，並回傳位組的數量。
+#[inline]
+fn split_groups(mut value: u128, width: u128, groups: &mut [u128]) -> usize {
+    let mut length = 0;
 
-        i += 1;
+    while value > 0 {
+        groups[length] = value % width;
+        value /= width;
+
+        length += 1;
     }
 
-    s
+    length
 }
 
-pub(crate) fn unsigned_integer_to_chinese_ten_thousand(
-    chinese_variant: ChineseVariant,
-    chinese_case: ChineseCase,
-    dependent: bool,
-    mut value: u128,
-) -> String {
-    let mut lower_d = value % 1_0000;
-    value /= 1_0000;
-
-    let mut has_more = value > 0;
-
-    let mut s = if lower_d > 0 {
-        unsigned_integer_to_chinese_low(
-            chinese_variant,
-            chinese_case,
-            dependent || has_more,
-            lower_d,
-        )
-    } else if value == 0 {
-        return ChineseNumber::零.to_str(chinese_variant, chinese_case).to_string();
-    } else {
-        String::new()
-    };
+/// 將 `BigUint` 以 `width` 為單位拆成位組。每個位組都必須能放進 `u128` 中。
+#[inline]
+fn split_big_groups(mut value: BigUint, width: u128) -> Vec<u128> {
+    let big_width = BigUint::from(width);
 
-    if !has_more {
-        return s;
+    let mut groups = Vec::new();
+
+    while !value.is_zero() {
+        groups.push((&value % &big_width).to_u128().unwrap());
+        value /= &big_width;
     }
 
-    let mut i = ChineseExponent::萬.ordinal();
+    groups
+}
 
-    loop {
-        let d = value % 1_0000;
-        value /= 1_0000;
+pub(crate) fn write_unsigned_integer_low<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    dependent: bool,
+    trailing: Option<ChineseExponent>,
+    value: u128,
+) -> fmt::Result {
+    debug_assert!(value < 1_0000_0000_0000_0000);
 
-        has_more = value > 0;
+    if value == 0 {
+        return write_zero(f, options);
+    }
 
-        if d > 0 {
-            if lower_d < 1000 && !s.is_empty() {
-                s.insert_str(0, ChineseNumber::零.to_str(chinese_variant, chinese_case));
+    let mut digits = [0u128; 16];
+    let length = split_groups(value, 10, &mut digits);
+
+    write_groups(
+        f,
+        options,
+        dependent,
+        trailing,
+        &digits[..length],
+        ChineseExponent::十,
+        |p, &d| p > 0 && d < 1,
+        |f, dependent, p, unit, &d| {
+            // 是否要省略 **「十」** 前的 **「一」**。日文的小寫數字也省略 **「百」** 和 **「千」** 前的 **「一」**
+            let omit_one = d == 1
+                && match options.script() {
+                    ChineseScript::Chinese => {
+                        p == 1
+                            && match options.ten_style() {
+                                ChineseTenStyle::Natural => !dependent,
+                                // 低數法的 **「萬」** 以上的單位都只有一位，因此較高的 **「百」** 和 **「千」** 都是零時就是位組的開頭
+                                ChineseTenStyle::Short => {
+                                    digits[p + 1..length.min(4)].iter().all(|&d| d == 0)
+                                },
+                                ChineseTenStyle::Full => false,
+                            }
+                    },
+                    ChineseScript::Japanese => {
+                        (1..=3).contains(&p) && options.chinese_case() == ChineseCase::Lower
+                    },
+                };
+
+            if !omit_one {
+                write_number(f, options, d as u8, unit, !dependent)?;
             }
 
-            s.insert_str(
-                0,
-                unsafe { ChineseExponent::from_ordinal_unsafe(i) }
-                    .to_str(chinese_variant, chinese_case),
-            );
-
-            s.insert_str(
-                0,
-                unsigned_integer_to_chinese_low(
-                    chinese_variant,
-                    chinese_case,
-                    dependent || has_more,
-                    d,
-                )
-                .as_str(),
-            );
-        }
+            Ok(())
+        },
+    )
+}
 
-        if !has_more {
-            break;
-        }
+pub(crate) fn write_unsigned_integer_ten_thousand<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    dependent: bool,
+    trailing: Option<ChineseExponent>,
+    value: u128,
+) -> fmt::Result {
+    if value == 0 {
+        return write_zero(f, options);
+    }
+
+    let mut groups = [0u128; 10];
+    let length = split_groups(value, 1_0000, &mut groups);
+
+    write_ten_thousand_groups(f, options, dependent, trailing, &groups[..length])
+}
 
-        lower_d = d;
+#[inline]
+fn write_ten_thousand_groups<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    dependent: bool,
+    trailing: Option<ChineseExponent>,
+    groups: &[u128],
+) -> fmt::Result {
+    write_groups(
+        f,
+        options,
+        dependent,
+        trailing,
+        groups,
+        ChineseExponent::萬,
+        |_, &d| d < 1000,
+        |f, dependent, _, unit, &d| {
+            write_unsigned_integer_low(f, options, dependent, liang_unit(unit, &d), d)
+        },
+    )
+}
 
-        i += 1;
+pub(crate) fn write_big_unsigned_integer_ten_thousand<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    dependent: bool,
+    value: BigUint,
+) -> fmt::Result {
+    debug_assert!(value < BigUint::from(10u8).pow(72));
+
+    if value.is_zero() {
+        return write_zero(f, options);
     }
 
-    s
+    write_ten_thousand_groups(f, options, dependent, None, &split_big_groups(value, 1_0000))
 }
 
-pub(crate) fn big_unsigned_integer_to_chinese_ten_thousand(
-    chinese_variant: ChineseVariant,
-    chinese_case: ChineseCase,
+pub(crate) fn write_unsigned_integer_middle<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
     dependent: bool,
-    mut value: BigUint,
-) -> String {
-    debug_assert!(value < BigUint::from(10u8).pow(52));
+    trailing: Option<ChineseExponent>,
+    value: u128,
+) -> fmt::Result {
+    if value == 0 {
+        return write_zero(f, options);
+    }
 
-    let big_0 = BigUint::zero();
-    let big_1_0000 = BigUint::from(1_0000u16);
+    let mut groups = [0u128; 5];
+    let length = split_groups(value, 1_0000_0000, &mut groups);
 
-    let mut lower_d = (value.clone() % &big_1_0000).to_u128().unwrap();
-    value /= &big_1_0000;
+    write_middle_groups(f, options, dependent, trailing, &groups[..length])
+}
 
-    let mut has_more = value > big_0;
+#[inline]
+fn write_middle_groups<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    dependent: bool,
+    trailing: Option<ChineseExponent>,
+    groups: &[u128],
+) -> fmt::Result {
+    write_groups(
+        f,
+        options,
+        dependent,
+        trailing,
+        groups,
+        ChineseExponent::億,
+        |_, &d| d < 1000_0000,
+        |f, dependent, _, unit, &d| {
+            write_unsigned_integer_ten_thousand(f, options, dependent, liang_unit(unit, &d), d)
+        },
+    )
+}
 
-    let mut s = if lower_d > 0 {
-        unsigned_integer_to_chinese_low(
-            chinese_variant,
-            chinese_case,
-            dependent || has_more,
-            lower_d,
-        )
-    } else if value == big_0 {
-        return ChineseNumber::零.to_str(chinese_variant, chinese_case).to_string();
-    } else {
-        String::new()
-    };
+pub(crate) fn write_big_unsigned_integer_middle<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    dependent: bool,
+    value: BigUint,
+) -> fmt::Result {
+    debug_assert!(value < BigUint::from(10u8).pow(136));
+
+    if value.is_zero() {
+        return write_zero(f, options);
+    }
+
+    write_middle_groups(f, options, dependent, None, &split_big_groups(value, 1_0000_0000))
+}
 
-    if !has_more {
-        return s;
+pub(crate) fn write_unsigned_integer_high<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    dependent: bool,
+    trailing: Option<ChineseExponent>,
+    mut value: u128,
+) -> fmt::Result {
+    if value == 0 {
+        return write_zero(f, options);
     }
 
-    let mut i = ChineseExponent::萬.ordinal();
+    // 最低的兩個位組的寬度為 10^16，之後每個位組的寬度都是前一個位組的寬度的平方
+    let widths: [u128; 3] = [1_0000_0000_0000_0000, 1_0000_0000_0000_0000, 10u128.pow(32)];
+
+    let mut groups = [0u128; 3];
+    let mut length = 0;
 
-    loop {
-        let d = (value.clone() % &big_1_0000).to_u128().unwrap();
-        value /= &big_1_0000;
+    while value > 0 {
+        let w = widths[length];
 
-        has_more = value > big_0;
+        groups[length] = value % w;
+        value /= w;
+
+        length += 1;
+    }
 
-        if d > 0 {
-            if lower_d < 1000 && !s.is_empty() {
-                s.insert_str(0, ChineseNumber::零.to_str(chinese_variant, chinese_case));
+    write_groups(
+        f,
+        options,
+        dependent,
+        trailing,
+        &groups[..length],
+        ChineseExponent::兆,
+        |p, &d| d < widths[p] / 10,
+        |f, dependent, p, unit, &d| {
+            let unit = liang_unit(unit, &d);
+
+            if p == 0 {
+                write_unsigned_integer_middle(f, options, dependent, unit, d)
+            } else {
+                write_unsigned_integer_high(f, options, dependent, unit, d)
             }
+        },
+    )
+}
 
-            s.insert_str(
-                0,
-                unsafe { ChineseExponent::from_ordinal_unsafe(i) }
-                    .to_str(chinese_variant, chinese_case),
-            );
-
-            s.insert_str(
-                0,
-                unsigned_integer_to_chinese_low(
-                    chinese_variant,
-                    chinese_case,
-                    dependent || has_more,
-                    d,
-                )
-                .as_str(),
-            );
-        }
+pub(crate) fn write_big_unsigned_integer_high<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    dependent: bool,
+    trailing: Option<ChineseExponent>,
+    mut value: BigUint,
+) -> fmt::Result {
+    if value.is_zero() {
+        return write_zero(f, options);
+    }
 
-        if !has_more {
-            break;
+    // 最低的兩個位組的寬度為 10^16，之後每個位組的寬度都是前一個位組的寬度的平方
+    let mut widths = Vec::new();
+    let mut groups = Vec::new();
+
+    let mut w = BigUint::from(1_0000_0000_0000_0000u64);
+
+    while !value.is_zero() {
+        if widths.len() > 1 {
+            w = &w * &w;
         }
 
-        lower_d = d;
+        groups.push(&value % &w);
+        value /= &w;
 
-        i += 1;
+        widths.push(w.clone());
     }
 
-    s
+    write_groups(
+        f,
+        options,
+        dependent,
+        trailing,
+        &groups,
+        ChineseExponent::兆,
+        |p, d| d < &(&widths[p] / 10u8),
+        |f, dependent, p, unit, d| {
+            let unit = liang_unit(unit, d);
+
+            if p == 0 {
+                write_unsigned_integer_middle(f, options, dependent, unit, d.to_u128().unwrap())
+            } else {
+                write_big_unsigned_integer_high(f, options, dependent, unit, d.clone())
+            }
+        },
+    )
 }
 
-pub(crate) fn unsigned_integer_to_chinese_middle(
-    chinese_variant: ChineseVariant,
-    chinese_case: ChineseCase,
-    dependent: bool,
-    mut value: u128,
-) -> String {
-    let mut lower_d = value % 1_0000_0000;
-    value /= 1_0000_0000;
-
-    let mut has_more = value > 0;
-
-    let mut s = if lower_d > 0 {
-        unsigned_integer_to_chinese_ten_thousand(
-            chinese_variant,
-            chinese_case,
-            dependent || has_more,
-            lower_d,
-        )
-    } else if value == 0 {
-        return ChineseNumber::零.to_str(chinese_variant, chinese_case).to_string();
-    } else {
-        String::new()
+/// 算出位於第 `position` 位（由 `0` 開始）的最低非零位數之後會寫出的單位是第幾位。
+pub(crate) fn trailing_unit_position(method: ChineseCountMethod, position: usize) -> usize {
+    match method {
+        ChineseCountMethod::Low => position,
+        _ if position < 4 => position,
+        ChineseCountMethod::TenThousand => position / 4 * 4,
+        ChineseCountMethod::Middle => {
+            if position < 8 {
+                4
+            } else {
+                position / 8 * 8
+            }
+        },
+        ChineseCountMethod::High => {
+            let mut unit = 4;
+
+            while unit * 2 <= position {
+                unit *= 2;
+            }
+
+            unit
+        },
+    }
+}
+
+/// 將整數拆成口語省略寫法的前半部分和最後一個位數，例如 `120` 會拆成 `100` 和 `2`，寫成 **「一百二」**。
+///
+/// 只有當最後一個非零位數緊接在前半部分最後寫出的單位之下，且該單位至少是 **「百」** 時才能省略，否則回傳 `None`。
+fn split_colloquial<N: Num + Clone + From<u8> + ToPrimitive>(
+    method: ChineseCountMethod,
+    value: &N,
+) -> Option<(N, u8)> {
+    let ten = N::from(10);
+
+    if value.is_zero() {
+        return None;
+    }
+
+    let mut position = 0;
+    let mut base = N::one();
+
+    let d = loop {
+        let d = value.clone() / base.clone() % ten.clone();
+
+        if !d.is_zero() {
+            break d;
+        }
+
+        position += 1;
+        base = base * ten.clone();
     };
 
-    if !has_more {
-        return s;
+    let rest = value.clone() - d.clone() * base.clone();
+
+    if position == 0 || rest.is_zero() {
+        return None;
     }
 
-    let mut i = ChineseExponent::億.ordinal();
+    let mut rest_position = position + 1;
+    let mut rest_base = base * ten.clone();
 
-    loop {
-        let d = value % 1_0000_0000;
-        value /= 1_0000_0000;
+    while (rest.clone() / rest_base.clone() % ten.clone()).is_zero() {
+        rest_position += 1;
+        rest_base = rest_base * ten.clone();
+    }
 
-        has_more = value > 0;
+    if trailing_unit_position(method, rest_position) == position + 1 {
+        Some((rest, d.to_u8().unwrap()))
+    } else {
+        None
+    }
+}
 
-        if d > 0 {
-            if lower_d < 1000_0000 && !s.is_empty() {
-                s.insert_str(0, ChineseNumber::零.to_str(chinese_variant, chinese_case));
-            }
+/// 依照選項的算術類型寫出 `u128` 整數。使用 **「下數」** 時，數值不能大於或等於 `1_0000_0000_0000_0000`。
+pub(crate) fn write_unsigned_integer<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    value: u128,
+) -> Result<(), NumberToChineseError> {
+    if options.method() == ChineseCountMethod::Low && value >= 1_0000_0000_0000_0000 {
+        return Err(NumberToChineseError::Overflow);
+    }
+
+    write_sign(f, options, false, value == 0)?;
+
+    if options.colloquial() && options.script() == ChineseScript::Chinese {
+        if let Some((value, d)) = split_colloquial(options.method(), &value) {
+            write_full_unsigned_integer(f, options, value)?;
+
+            return Ok(write_number(f, options, d, None, false)?);
+        }
+    }
+
+    write_full_unsigned_integer(f, options, value)?;
+
+    Ok(())
+}
+
+#[inline]
+fn write_full_unsigned_integer<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    value: u128,
+) -> fmt::Result {
+    match options.method() {
+        ChineseCountMethod::Low => write_unsigned_integer_low(f, options, false, None, value),
+        ChineseCountMethod::TenThousand => {
+            write_unsigned_integer_ten_thousand(f, options, false, None, value)
+        },
+        ChineseCountMethod::Middle => write_unsigned_integer_middle(f, options, false, None, value),
+        ChineseCountMethod::High => write_unsigned_integer_high(f, options, false, None, value),
+    }
+}
+
+/// 依照選項寫出分數，分母在前，例如 **「三分之一」**。分母不能是零。使用 **「下數」** 時，分子和分母都不能大於或等於 `1_0000_0000_0000_0000`。
+pub(crate) fn write_fraction<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    negative: bool,
+    numerator: u128,
+    denominator: u128,
+) -> Result<(), NumberToChineseError> {
+    if denominator == 0 {
+        return Err(NumberToChineseError::ZeroDenominator);
+    }
 
-            s.insert_str(
-                0,
-                unsafe { ChineseExponent::from_ordinal_unsafe(i) }
-                    .to_str(chinese_variant, chinese_case),
-            );
-
-            s.insert_str(
-                0,
-                unsigned_integer_to_chinese_ten_thousand(
-                    chinese_variant,
-                    chinese_case,
-                    dependent || has_more,
-                    d,
-                )
-                .as_str(),
-            );
+    if options.method() == ChineseCountMethod::Low {
+        if denominator >= 1_0000_0000_0000_0000 {
+            return Err(NumberToChineseError::Overflow);
         }
 
-        if !has_more {
-            break;
+        if numerator >= 1_0000_0000_0000_0000 {
+            return Err(if negative {
+                NumberToChineseError::Underflow
+            } else {
+                NumberToChineseError::Overflow
+            });
         }
+    }
+
+    write_sign(f, options, negative, numerator == 0)?;
 
-        lower_d = d;
+    let options = &options.with_sign_style(ChineseSignStyle::Negative);
 
-        i += 1;
+    // 分母是 10 的次方時省略開頭的 **「一」**，例如 **「百分之五」**
+    match power_of_ten_exponent(denominator) {
+        Some(exponent) if exponent > 0 => write_power_of_ten(f, options, exponent)?,
+        _ => write_unsigned_integer(f, options, denominator)?,
     }
 
-    s
+    write_fraction_separator(f, options)?;
+    write_unsigned_integer(f, options, numerator)
 }
 
-pub(crate) fn big_unsigned_integer_to_chinese_middle(
-    chinese_variant: ChineseVariant,
-    chinese_case: ChineseCase,
-    dependent: bool,
-    mut value: BigUint,
-) -> String {
-    debug_assert!(value < BigUint::from(10u8).pow(96));
+/// 如果數值是 `10^exponent`，回傳 `exponent`。
+#[inline]
+fn power_of_ten_exponent(mut value: u128) -> Option<usize> {
+    let mut exponent = 0;
 
-    let big_0 = BigUint::zero();
-    let big_1_0000_0000 = BigUint::from(1_0000_0000u32);
+    while value >= 10 && value % 10 == 0 {
+        value /= 10;
+        exponent += 1;
+    }
+
+    if value == 1 {
+        Some(exponent)
+    } else {
+        None
+    }
+}
+
+/// 寫出 `10^exponent` 的單位，省略開頭的 **「一」**，例如 **「百萬」**、**「萬億」**。
+pub(crate) fn write_power_of_ten<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    exponent: usize,
+) -> fmt::Result {
+    let s = to_string_with(|s| {
+        write_big_unsigned_integer(s, options, BigUint::from(10u8).pow(exponent as u32))
+    });
+
+    // 字形和文字系統都會影響 **「一」** 的寫法，例如 **「弌」**、**「壱」**
+    let one = to_string_with(|s| write_number(s, options, 1, None, true));
+
+    f.write_str(s.strip_prefix(one.as_str()).unwrap_or(&s))
+}
 
-    let mut lower_d = (value.clone() % &big_1_0000_0000).to_u128().unwrap();
-    value /= &big_1_0000_0000;
+/// 依照選項的算術類型寫出 `i128` 整數。使用 **「下數」** 時，數值的絕對值不能大於或等於 `1_0000_0000_0000_0000`。
+pub(crate) fn write_signed_integer<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    value: i128,
+) -> Result<(), NumberToChineseError> {
+    if value < 0 {
+        let value = -(value + 1) as u128 + 1;
+
+        if options.method() == ChineseCountMethod::Low && value >= 1_0000_0000_0000_0000 {
+            return Err(NumberToChineseError::Underflow);
+        }
 
-    let mut has_more = value > big_0;
+        write_sign(f, options, true, false)?;
 
-    let mut s = if lower_d > 0 {
-        unsigned_integer_to_chinese_ten_thousand(
-            chinese_variant,
-            chinese_case,
-            dependent || has_more,
-            lower_d,
-        )
-    } else if value == big_0 {
-        return ChineseNumber::零.to_str(chinese_variant, chinese_case).to_string();
+        write_unsigned_integer(f, &options.with_sign_style(ChineseSignStyle::Negative), value)
     } else {
-        String::new()
-    };
+        write_unsigned_integer(f, options, value as u128)
+    }
+}
 
-    if !has_more {
-        return s;
+/// 依照選項的算術類型寫出 `BigUint` 整數，不檢查數值的範圍。
+pub(crate) fn write_big_unsigned_integer<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    value: BigUint,
+) -> fmt::Result {
+    if options.colloquial() && options.script() == ChineseScript::Chinese {
+        if let Some((value, d)) = split_colloquial(options.method(), &value) {
+            write_full_big_unsigned_integer(f, options, value)?;
+
+            return write_number(f, options, d, None, false);
+        }
     }
 
-    let mut i = ChineseExponent::億.ordinal();
+    write_full_big_unsigned_integer(f, options, value)
+}
 
-    loop {
-        let d = (value.clone() % &big_1_0000_0000).to_u128().unwrap();
-        value /= &big_1_0000_0000;
+#[inline]
+fn write_full_big_unsigned_integer<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    value: BigUint,
+) -> fmt::Result {
+    match options.method() {
+        ChineseCountMethod::Low => {
+            write_unsigned_integer_low(f, options, false, None, value.to_u128().unwrap())
+        },
+        ChineseCountMethod::TenThousand => {
+            write_big_unsigned_integer_ten_thousand(f, options, false, value)
+        },
+        ChineseCountMethod::Middle => write_big_unsigned_integer_middle(f, options, false, value),
+        ChineseCountMethod::High => write_big_unsigned_integer_high(f, options, false, None, value),
+    }
+}
 
-        has_more = value > big_0;
+/// 檢查 `BigUint` 整數是否在算術類型能表示的範圍內。
+#[inline]
+pub(crate) fn check_big_unsigned_integer_range(
+    method: ChineseCountMethod,
+    value: &BigUint,
+) -> Result<(), NumberToChineseError> {
+    let exponent = match method {
+        ChineseCountMethod::Low => 16,
+        ChineseCountMethod::TenThousand => 72,
+        ChineseCountMethod::Middle => 136,
+        ChineseCountMethod::High => 16384,
+    };
 
-        if d > 0 {
-            if lower_d < 1000_0000 && !s.is_empty() {
-                s.insert_str(0, ChineseNumber::零.to_str(chinese_variant, chinese_case));
-            }
+    // 2^(3 * exponent) < 10^exponent < 2^(4 * exponent)，只有位元數介於兩者之間時才需要計算 10^exponent
+    let bits = value.bits();
 
-            s.insert_str(
-                0,
-                unsafe { ChineseExponent::from_ordinal_unsafe(i) }
-                    .to_str(chinese_variant, chinese_case),
-            );
-
-            s.insert_str(
-                0,
-                unsigned_integer_to_chinese_ten_thousand(
-                    chinese_variant,
-                    chinese_case,
-                    dependent || has_more,
-                    d,
-                )
-                .as_str(),
-            );
-        }
+    let overflow = if bits <= 3 * exponent as u64 {
+        false
+    } else if bits > 4 * exponent as u64 {
+        true
+    } else {
+        value >= &BigUint::from(10u8).pow(exponent)
+    };
+
+    if overflow {
+        Err(NumberToChineseError::Overflow)
+    } else {
+        Ok(())
+    }
+}
 
-        if !has_more {
-            break;
+/// 依照選項的算術類型寫出 `BigInt` 整數，並檢查數值的範圍。
+#[cfg(feature = "bigint")]
+pub(crate) fn write_big_signed_integer<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    value: num_bigint::BigInt,
+) -> Result<(), NumberToChineseError> {
+    let (sign, value) = value.into_parts();
+    let negative = sign == num_bigint::Sign::Minus;
+
+    check_big_unsigned_integer_range(options.method(), &value).map_err(|err| {
+        if negative {
+            NumberToChineseError::Underflow
+        } else {
+            err
         }
+    })?;
 
-        lower_d = d;
+    write_sign(f, options, negative, value.is_zero())?;
+    write_big_unsigned_integer(f, options, value)?;
 
-        i += 1;
-    }
+    Ok(())
+}
+
+#[inline]
+pub(crate) fn to_string_with(write: impl FnOnce(&mut String) -> fmt::Result) -> String {
+    let mut s = String::new();
+
+    write(&mut s).unwrap();
 
     s
 }
 
-pub(crate) fn unsigned_integer_to_chinese_high(
+pub(crate) fn unsigned_integer_to_chinese_low(
     chinese_variant: ChineseVariant,
     chinese_case: ChineseCase,
     dependent: bool,
-    mut value: u128,
+    value: u128,
 ) -> String {
-    let mut w = 1_0000_0000_0000_0000;
-
-    let mut lower_d = value % w;
-    value /= w;
-
-    let mut has_more = value > 0;
-
-    let mut s = if lower_d > 0 {
-        unsigned_integer_to_chinese_middle(
-            chinese_variant,
-            chinese_case,
-            dependent || has_more,
-            lower_d,
-        )
-    } else if value == 0 {
-        return ChineseNumber::零.to_str(chinese_variant, chinese_case).to_string();
-    } else {
-        String::new()
-    };
+    let options = ChineseFormatOptions::new().with_variant(chinese_variant).with_case(chinese_case);
 
-    if !has_more {
-        return s;
-    }
-
-    let mut i = ChineseExponent::兆.ordinal();
+    to_string_with(|s| write_unsigned_integer_low(s, &options, dependent, None, value))
+}
 
-    let mut previous_w = w;
+pub(crate) fn unsigned_integer_to_chinese_ten_thousand(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    dependent: bool,
+    value: u128,
+) -> String {
+    let options = ChineseFormatOptions::new().with_variant(chinese_variant).with_case(chinese_case);
 
-    loop {
-        let d = value % w;
-        value /= w;
+    to_string_with(|s| write_unsigned_integer_ten_thousand(s, &options, dependent, None, value))
+}
 
-        has_more = value > 0;
+#[cfg(test)]
+pub(crate) fn big_unsigned_integer_to_chinese_ten_thousand(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    dependent: bool,
+    value: BigUint,
+) -> String {
+    let options = ChineseFormatOptions::new().with_variant(chinese_variant).with_case(chinese_case);
 
-        if d > 0 {
-            if lower_d < previous_w / 10 && !s.is_empty() {
-                s.insert_str(0, ChineseNumber::零.to_str(chinese_variant, chinese_case));
-            }
+    to_string_with(|s| write_big_unsigned_integer_ten_thousand(s, &options, dependent, value))
+}
 
-            s.insert_str(
-                0,
-                unsafe { ChineseExponent::from_ordinal_unsafe(i) }
-                    .to_str(chinese_variant, chinese_case),
-            );
-
-            s.insert_str(
-                0,
-                unsigned_integer_to_chinese_high(
-                    chinese_variant,
-                    chinese_case,
-                    dependent || has_more,
-                    d,
-                )
-                .as_str(),
-            );
-        }
+pub(crate) fn unsigned_integer_to_chinese_middle(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    dependent: bool,
+    value: u128,
+) -> String {
+    let options = ChineseFormatOptions::new().with_variant(chinese_variant).with_case(chinese_case);
 
-        if !has_more {
-            break;
-        }
+    to_string_with(|s| write_unsigned_integer_middle(s, &options, dependent, None, value))
+}
 
-        lower_d = d;
+#[cfg(test)]
+pub(crate) fn big_unsigned_integer_to_chinese_middle(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    dependent: bool,
+    value: BigUint,
+) -> String {
+    let options = ChineseFormatOptions::new().with_variant(chinese_variant).with_case(chinese_case);
 
-        i += 1;
+    to_string_with(|s| write_big_unsigned_integer_middle(s, &options, dependent, value))
+}
 
-        previous_w = w;
-        w *= w;
-    }
+pub(crate) fn unsigned_integer_to_chinese_high(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    dependent: bool,
+    value: u128,
+) -> String {
+    let options = ChineseFormatOptions::new().with_variant(chinese_variant).with_case(chinese_case);
 
-    s
+    to_string_with(|s| write_unsigned_integer_high(s, &options, dependent, None, value))
 }
 
+#[cfg(test)]
 pub(crate) fn big_unsigned_integer_to_chinese_high(
     chinese_variant: ChineseVariant,
     chinese_case: ChineseCase,
     dependent: bool,
-    mut value: BigUint,
+    value: BigUint,
 ) -> String {
-    let big_0 = BigUint::zero();
-    let big_10 = BigUint::from(10u8);
+    let options = ChineseFormatOptions::new().with_variant(chinese_variant).with_case(chinese_case);
 
-    let mut w = BigUint::from(1_0000_0000_0000_0000u64);
+    to_string_with(|s| write_big_unsigned_integer_high(s, &options, dependent, None, value))
+}
+
+#[cfg(feature = "bigint")]
+pub(crate) fn big_unsigned_integer_to_chinese(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    method: ChineseCountMethod,
+    value: BigUint,
+) -> String {
+    let options = ChineseFormatOptions::from((chinese_variant, chinese_case, method));
 
-    let mut lower_d = value.clone() % &w;
-    value /= &w;
+    to_string_with(|s| write_big_unsigned_integer(s, &options, value))
+}
 
-    let mut has_more = value > big_0;
+/// 寫出 **「非數」** 或 **「無窮大」**。
+#[inline]
+pub(crate) fn write_non_finite<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    value: f64,
+) -> fmt::Result {
+    let chinese_variant = options.chinese_variant();
 
-    let mut s = if lower_d > big_0 {
-        unsigned_integer_to_chinese_middle(
-            chinese_variant,
-            chinese_case,
-            dependent || has_more,
-            lower_d.to_u128().unwrap(),
-        )
-    } else if value == big_0 {
-        return ChineseNumber::零.to_str(chinese_variant, chinese_case).to_string();
+    if value.is_nan() {
+        f.write_str(ChineseNonFinite::非數.to_str(chinese_variant))
     } else {
-        String::new()
-    };
+        write_sign(f, options, value < 0.0, false)?;
 
-    if !has_more {
-        return s;
+        f.write_str(ChineseNonFinite::無窮大.to_str(chinese_variant))
     }
+}
 
-    let mut i = ChineseExponent::兆.ordinal();
+/// 將有限的浮點數拆成正負號、整數部分和小數部分的每個位數，使用和 `Display` 相同、能夠還原該數值的最短十進位表示法。
+pub(crate) fn float_to_decimal<F: Display>(value: F) -> (bool, BigUint, Vec<u8>) {
+    let s = value.to_string();
 
-    let mut previous_w = w.clone();
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s.as_str()),
+    };
 
-    loop {
-        let d = value.clone() % &w;
-        value /= &w;
+    let (integer, fraction) = match s.split_once('.') {
+        Some((integer, fraction)) => (integer, fraction),
+        None => (s, ""),
+    };
 
-        has_more = value > big_0;
+    let integer = BigUint::parse_bytes(integer.as_bytes(), 10).unwrap();
+    let fraction = fraction.bytes().map(|b| b - b'0').collect();
 
-        if d > big_0 {
-            if lower_d < previous_w / &big_10 && !s.is_empty() {
-                s.insert_str(0, ChineseNumber::零.to_str(chinese_variant, chinese_case));
-            }
+    (negative, integer, fraction)
+}
+
+/// 依照指定的位數和進位方式，捨去十進位小數多餘的位數。進位時可能會影響到整數部分。
+pub(crate) fn round_decimal(
+    mut integer: BigUint,
+    mut fraction: Vec<u8>,
+    precision: usize,
+    rounding: ChineseRounding,
+    negative: bool,
+) -> (BigUint, Vec<u8>) {
+    if fraction.len() <= precision {
+        return (integer, fraction);
+    }
+
+    let dropped = fraction.split_off(precision);
+
+    let round_up = match rounding {
+        ChineseRounding::HalfUp => dropped[0] >= 5,
+        ChineseRounding::HalfEven => match dropped[0] {
+            0..=4 => false,
+            5 if dropped[1..].iter().all(|&d| d == 0) => match fraction.last() {
+                Some(d) => d % 2 == 1,
+                None => integer.bit(0),
+            },
+            _ => true,
+        },
+        ChineseRounding::Truncate => false,
+        ChineseRounding::Ceiling => !negative && dropped.iter().any(|&d| d > 0),
+    };
 
-            s.insert_str(
-                0,
-                unsafe { ChineseExponent::from_ordinal_unsafe(i) }
-                    .to_str(chinese_variant, chinese_case),
-            );
-
-            s.insert_str(
-                0,
-                big_unsigned_integer_to_chinese_high(
-                    chinese_variant,
-                    chinese_case,
-                    dependent || has_more,
-                    d.clone(),
-                )
-                .as_str(),
-            );
+    if round_up {
+        let mut carry = true;
+
+        for d in fraction.iter_mut().rev() {
+            if *d == 9 {
+                *d = 0;
+            } else {
+                *d += 1;
+                carry = false;
+                break;
+            }
         }
 
-        if !has_more {
-            break;
+        if carry {
+            integer += 1u8;
         }
+    }
+
+    (integer, fraction)
+}
 
-        lower_d = d;
+/// 小數部分由大到小所使用的單位，**「分」** 以下的單位只有在指定的小數位數超過兩位時才會用到。
+const FRACTION_EXPONENTS: [ChineseExponent; 10] = [
+    ChineseExponent::角,
+    ChineseExponent::分,
+    ChineseExponent::厘,
+    ChineseExponent::毫,
+    ChineseExponent::絲,
+    ChineseExponent::忽,
+    ChineseExponent::微,
+    ChineseExponent::纖,
+    ChineseExponent::沙,
+    ChineseExponent::塵,
+];
+
+/// 依照選項取得浮點數小數部分的表示方式。日文的 **「分」** 是十分之一，和中文的 **「分」** 不同，所以日文的漢數字一律使用 **「点」**。
+#[inline]
+fn fraction_style(options: &ChineseFormatOptions) -> ChineseFractionStyle {
+    match options.script() {
+        ChineseScript::Chinese => options.fraction_style(),
+        ChineseScript::Japanese => ChineseFractionStyle::Decimal,
+    }
+}
 
-        i += 1;
+/// 依照選項寫出有限浮點數的小數部分已經處理好的十進位表示法。
+fn write_decimal<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    negative: bool,
+    integer: BigUint,
+    fraction: &[u8],
+) -> fmt::Result {
+    // 小數部分不寫出結尾的零
+    let length = fraction.iter().rposition(|&d| d > 0).map_or(0, |i| i + 1);
+    let fraction = &fraction[..length];
+
+    let is_zero = integer.is_zero() && fraction.is_empty();
+
+    // 浮點數不使用口語的省略寫法，以免和小數部分混淆
+    let options = &options.with_colloquial(false);
+
+    write_sign(f, options, negative, is_zero)?;
+
+    match fraction_style(options) {
+        ChineseFractionStyle::Unit => {
+            if !integer.is_zero() {
+                write_big_unsigned_integer(f, options, integer)?;
+            }
+
+            for (&d, &exponent) in fraction.iter().zip(FRACTION_EXPONENTS.iter()) {
+                if d > 0 {
+                    write_number(f, options, d, Some(exponent), false)?;
+                    write_exponent(f, options, exponent)?;
+                }
+            }
 
-        previous_w = w.clone();
-        w = w.clone() * w;
+            if is_zero {
+                write_zero(f, options)?;
+            }
+        },
+        ChineseFractionStyle::Decimal => {
+            write_big_unsigned_integer(f, options, integer)?;
+
+            if !fraction.is_empty() {
+                write_point(f, options)?;
+
+                for &d in fraction {
+                    write_number(f, options, d, None, false)?;
+                }
+            }
+        },
     }
 
-    s
+    Ok(())
 }
 
-pub(crate) fn positive_float_to_chinese(
-    chinese_variant: ChineseVariant,
-    chinese_case: ChineseCase,
-    method: ChineseCountMethod,
-    value: f64,
-) -> String {
-    let (integer, fraction) = {
-        let integer = BigUint::from_f64(value.trunc()).unwrap();
-        let fraction = ((value.fract() * 100.0).round() % 100f64) as u8;
+/// 依照選項寫出浮點數，不檢查數值的範圍。NaN 和無窮大會被寫成 **「非數」** 和 **「無窮大」**。
+pub(crate) fn write_float<W: Write, F: Copy + Display + Into<f64>>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    value: F,
+) -> fmt::Result {
+    if !value.into().is_finite() {
+        return write_non_finite(f, options, value.into());
+    }
 
-        (integer, fraction)
+    let (negative, integer, fraction) = float_to_decimal(value);
+
+    let (integer, fraction) = match (fraction_style(options), options.fraction_options()) {
+        (ChineseFractionStyle::Unit, fraction_options) => {
+            let fraction_options = fraction_options.unwrap_or_default();
+
+            round_decimal(
+                integer,
+                fraction,
+                fraction_options.precision().min(FRACTION_EXPONENTS.len()),
+                fraction_options.rounding(),
+                negative,
+            )
+        },
+        (ChineseFractionStyle::Decimal, Some(fraction_options)) => round_decimal(
+            integer,
+            fraction,
+            fraction_options.precision(),
+            fraction_options.rounding(),
+            negative,
+        ),
+        (ChineseFractionStyle::Decimal, None) => (integer, fraction),
     };
 
-    let big_0 = BigUint::zero();
-
-    let mut s = if integer > big_0 {
-        match method {
-            ChineseCountMethod::Low => unsigned_integer_to_chinese_low(
-                chinese_variant,
-                chinese_case,
-                false,
-                integer.to_u128().unwrap(),
-            ),
-            ChineseCountMethod::TenThousand => big_unsigned_integer_to_chinese_ten_thousand(
-                chinese_variant,
-                chinese_case,
-                false,
-                integer.clone(),
-            ),
-            ChineseCountMethod::Middle => big_unsigned_integer_to_chinese_middle(
-                chinese_variant,
-                chinese_case,
-                false,
-                integer.clone(),
-            ),
-            ChineseCountMethod::High => big_unsigned_integer_to_chinese_high(
-                chinese_variant,
-                chinese_case,
-                false,
-                integer.clone(),
-            ),
-        }
+    write_decimal(f, options, negative, integer, &fraction)
+}
+
+/// 將能以 `Display` 寫成十進位表示法的數值（整數或浮點數）拆成正負號、整數部分和小數部分的每個位數。
+///
+/// NaN 和無窮大如果被選項允許，會直接寫成 **「非數」** 和 **「無窮大」** 並回傳 `None`，否則回傳錯誤。
+pub(crate) fn display_to_decimal<W: Write, V: Display>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    value: V,
+) -> Result<Option<(bool, BigUint, Vec<u8>)>, NumberToChineseError> {
+    let s = value.to_string();
+
+    let non_finite = match s.as_str() {
+        "NaN" => Some(f64::NAN),
+        "inf" => Some(f64::INFINITY),
+        "-inf" => Some(f64::NEG_INFINITY),
+        _ => None,
+    };
+
+    match non_finite {
+        Some(value) => {
+            if options.fraction_options().map_or(false, |options| options.non_finite()) {
+                write_non_finite(f, options, value)?;
+
+                Ok(None)
+            } else if value.is_nan() {
+                Err(NumberToChineseError::NotANumber)
+            } else {
+                Err(NumberToChineseError::Infinite)
+            }
+        },
+        None => Ok(Some(float_to_decimal(s))),
+    }
+}
+
+/// 檢查數值能否寫成序數。數值必須是大於或等於 `1` 的整數，以 `Display` 的十進位表示法判斷，因此沒有小數部分的浮點數也可以使用。
+pub(crate) fn check_ordinal<V: Display>(value: &V) -> Result<(), NumberToChineseError> {
+    let s = value.to_string();
+
+    if s.bytes().all(|b| b.is_ascii_digit()) && s.bytes().any(|b| b != b'0') {
+        Ok(())
     } else {
-        String::new()
+        Err(NumberToChineseError::NotAnOrdinal)
+    }
+}
+
+/// 依照選項將數值寫成百分數、千分數或萬分數，例如 **「百分之十五點三」**。數值會先依照 `Display` 的十進位表示法移動小數點，再檢查範圍和捨去多餘的位數，小數部分一律使用 **「點」** 逐位表示。
+pub(crate) fn write_percent<W: Write, V: Display>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    percent: ChinesePercent,
+    value: V,
+) -> Result<(), NumberToChineseError> {
+    let (negative, mut integer, mut fraction) = match display_to_decimal(f, options, value)? {
+        Some(decimal) => decimal,
+        None => return Ok(()),
     };
 
-    if fraction >= 10 {
-        let msd = fraction / 10;
-        let lsd = fraction % 10;
+    let digits = percent.digits();
+
+    if fraction.len() < digits {
+        fraction.resize(digits, 0);
+    }
 
-        s.push_str(
-            unsafe { ChineseNumber::from_ordinal_unsafe(msd) }
-                .to_str(chinese_variant, chinese_case),
-        );
+    let rest = fraction.split_off(digits);
 
-        s.push_str(ChineseExponent::角.to_str(chinese_variant, chinese_case));
+    for d in fraction {
+        integer = integer * 10u8 + d;
+    }
 
-        if lsd > 0 {
-            s.push_str(
-                unsafe { ChineseNumber::from_ordinal_unsafe(lsd) }
-                    .to_str(chinese_variant, chinese_case),
-            );
+    let (integer, fraction) = match options.fraction_options() {
+        Some(fraction_options) => round_decimal(
+            integer,
+            rest,
+            fraction_options.precision(),
+            fraction_options.rounding(),
+            negative,
+        ),
+        None => (integer, rest),
+    };
 
-            s.push_str(ChineseExponent::分.to_str(chinese_variant, chinese_case));
+    check_big_unsigned_integer_range(options.method(), &integer).map_err(|err| {
+        if negative {
+            NumberToChineseError::Underflow
+        } else {
+            err
         }
-    } else if fraction >= 1 {
-        s.push_str(
-            unsafe { ChineseNumber::from_ordinal_unsafe(fraction) }
-                .to_str(chinese_variant, chinese_case),
-        );
+    })?;
 
-        s.push_str(ChineseExponent::分.to_str(chinese_variant, chinese_case));
-    } else if integer == big_0 {
-        s.push_str(ChineseNumber::零.to_str(chinese_variant, chinese_case));
-    }
+    let is_zero = integer.is_zero() && fraction.iter().all(|&d| d == 0);
 
-    s
+    write_sign(f, options, negative, is_zero)?;
+    write_exponent(f, options, percent.exponent())?;
+    write_fraction_separator(f, options)?;
+
+    let options = &options
+        .with_fraction_style(ChineseFractionStyle::Decimal)
+        .with_sign_style(ChineseSignStyle::Negative);
+
+    Ok(write_decimal(f, options, false, integer, &fraction)?)
+}
+
+/// 將浮點數轉成中文數字，小數部分使用 **「角」** 和 **「分」** 表示，並依照選項捨去多餘的位數。
+pub(crate) fn float_to_chinese<F: Copy + Display + Into<f64>>(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    method: ChineseCountMethod,
+    value: F,
+    fraction_options: &ChineseFractionOptions,
+) -> String {
+    let options = ChineseFormatOptions::from((chinese_variant, chinese_case, method))
+        .with_fraction_options(*fraction_options);
+
+    to_string_with(|s| write_float(s, &options, value))
+}
+
+/// 將浮點數轉成中文數字，小數部分使用 **「點」** 逐位表示。
+pub(crate) fn float_to_chinese_decimal<F: Copy + Display + Into<f64>>(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    method: ChineseCountMethod,
+    value: F,
+) -> String {
+    let options = ChineseFormatOptions::from((chinese_variant, chinese_case, method))
+        .with_fraction_style(ChineseFractionStyle::Decimal);
+
+    to_string_with(|s| write_float(s, &options, value))
 }
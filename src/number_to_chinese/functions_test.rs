@@ -220,11 +220,12 @@ fn test_fraction_compat() {
         ($expect:expr, $value:expr) => {
             assert_eq!(
                 $expect,
-                positive_float_to_chinese(
+                float_to_chinese(
                     ChineseVariant::Traditional,
                     ChineseCase::Lower,
                     ChineseCountMethod::TenThousand,
-                    $value
+                    $value,
+                    &ChineseFractionOptions::new()
                 )
             );
         };
@@ -235,4 +236,74 @@ fn test_fraction_compat() {
     test!("一角", 0.1);
     test!("五角五分", 0.55);
     test!("九十九九角九分", 99.99);
+    test!("一", 0.999);
+    test!("二分", 0.015);
+    test!("一千兆一角", 1e15 + 0.07);
+}
+
+#[test]
+fn test_fraction_decimal() {
+    macro_rules! test {
+        ($expect:expr, $value:expr) => {
+            assert_eq!(
+                $expect,
+                float_to_chinese_decimal(
+                    ChineseVariant::Traditional,
+                    ChineseCase::Lower,
+                    ChineseCountMethod::TenThousand,
+                    $value
+                )
+            );
+        };
+    }
+
+    test!("零", 0.0);
+    test!("零點零一", 0.01);
+    test!("零點一", 0.1);
+    test!("一點二三四五", 1.2345);
+    test!("九十九點九九九", 99.999);
+    test!("十萬零一點二五", 10_0001.25);
+    test!("零點一", 0.1f32);
+    test!("一千兆點一", 1e15 + 0.07);
+}
+
+#[test]
+fn test_round_decimal() {
+    macro_rules! test {
+        (
+            $expect:expr,
+            $integer:expr,
+            $fraction:expr,
+            $precision:expr,
+            $rounding:expr,
+            $negative:expr
+        ) => {
+            assert_eq!(
+                (
+                    num_bigint::BigUint::from($expect.0 as u128),
+                    $expect.1.iter().copied().collect::<alloc::vec::Vec<u8>>()
+                ),
+                round_decimal(
+                    ($integer as u128).into(),
+                    $fraction.to_vec(),
+                    $precision,
+                    $rounding,
+                    $negative
+                )
+            );
+        };
+    }
+
+    test!((1, [2]), 1, [2], 2, ChineseRounding::HalfUp, false);
+    test!((1, [3]), 1, [2, 5], 1, ChineseRounding::HalfUp, false);
+    test!((1, [2]), 1, [2, 5], 1, ChineseRounding::HalfEven, false);
+    test!((1, [4]), 1, [3, 5], 1, ChineseRounding::HalfEven, false);
+    test!((1, [3]), 1, [2, 5, 1], 1, ChineseRounding::HalfEven, false);
+    test!((2, [0u8; 0]), 2, [5], 0, ChineseRounding::HalfEven, false);
+    test!((4, [0u8; 0]), 3, [5], 0, ChineseRounding::HalfEven, false);
+    test!((1, [9, 9]), 1, [9, 9, 9], 2, ChineseRounding::Truncate, false);
+    test!((2, [0, 0]), 1, [9, 9, 9], 2, ChineseRounding::HalfUp, false);
+    test!((1, [2]), 1, [1, 0, 1], 1, ChineseRounding::Ceiling, false);
+    test!((1, [1]), 1, [1, 0, 1], 1, ChineseRounding::Ceiling, true);
+    test!((1, [1]), 1, [1, 0, 0], 1, ChineseRounding::Ceiling, false);
 }
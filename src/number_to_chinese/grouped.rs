@@ -0,0 +1,56 @@
+use core::fmt::{Display, Write};
+
+use num_traits::Zero;
+
+use super::{
+    functions::{display_to_decimal, round_decimal},
+    mixed::{split_digit_groups, write_arabic_digits, write_arabic_sign},
+    ChineseFormatOptions, NumberToChineseError,
+};
+use crate::ChineseDigitSeparator;
+
+/// 依照選項的算術類型將數值寫成以分隔符號分成位組的阿拉伯數字，例如 **「12,3456,7890」**。每個位組的邊界都對應到一個單位，所以不檢查數值的範圍。
+pub(crate) fn write_grouped<W: Write, V: Display>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    separator: ChineseDigitSeparator,
+    value: V,
+) -> Result<(), NumberToChineseError> {
+    let (negative, integer, fraction) = match display_to_decimal(f, options, value)? {
+        Some(decimal) => decimal,
+        None => return Ok(()),
+    };
+
+    let (integer, fraction) = match options.fraction_options() {
+        Some(fraction_options) => round_decimal(
+            integer,
+            fraction,
+            fraction_options.precision(),
+            fraction_options.rounding(),
+            negative,
+        ),
+        None => (integer, fraction),
+    };
+
+    let length = fraction.iter().rposition(|&d| d > 0).map_or(0, |i| i + 1);
+
+    write_arabic_sign(f, options, negative, integer.is_zero() && length == 0)?;
+
+    let digits = integer.to_radix_be(10);
+    let groups = split_digit_groups(&digits, options.method());
+
+    for (i, group) in groups.iter().rev().enumerate() {
+        if i > 0 {
+            f.write_char(separator.to_char())?;
+        }
+
+        write_arabic_digits(f, group)?;
+    }
+
+    if length > 0 {
+        f.write_char('.')?;
+        write_arabic_digits(f, &fraction[..length])?;
+    }
+
+    Ok(())
+}
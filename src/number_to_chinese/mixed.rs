@@ -0,0 +1,307 @@
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Write};
+
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use super::{
+    functions::{
+        check_big_unsigned_integer_range, display_to_decimal, round_decimal, write_exponent,
+    },
+    ChineseFormatOptions, ChineseSignStyle, NumberToChineseError,
+};
+use crate::{ChineseCountMethod, ChineseExponent};
+
+/// 寫出阿拉伯數字所使用的正負號。負數寫出 **「-」**，選項的正負號樣式為 `Explicit` 時，正數寫出 **「+」**，零不寫出正負號。
+#[inline]
+pub(crate) fn write_arabic_sign<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    negative: bool,
+    is_zero: bool,
+) -> fmt::Result {
+    if !is_zero {
+        if negative {
+            f.write_char('-')?;
+        } else if options.sign_style() == ChineseSignStyle::Explicit {
+            f.write_char('+')?;
+        }
+    }
+
+    Ok(())
+}
+
+/// 寫出阿拉伯數字。`digits` 由最高位開始。
+#[inline]
+pub(crate) fn write_arabic_digits<W: Write>(f: &mut W, digits: &[u8]) -> fmt::Result {
+    for &d in digits {
+        f.write_char((b'0' + d) as char)?;
+    }
+
+    Ok(())
+}
+
+#[inline]
+fn exponent_after(exponent: ChineseExponent, offset: usize) -> ChineseExponent {
+    unsafe { ChineseExponent::from_ordinal_unsafe(exponent.ordinal() + offset as u8) }
+}
+
+/// 由最低位開始依照算術類型將位數拆成位組。`digits` 由最高位開始，回傳的位組由最低位組開始。
+pub(crate) fn split_digit_groups(digits: &[u8], method: ChineseCountMethod) -> Vec<&[u8]> {
+    let mut groups = Vec::new();
+    let mut end = digits.len();
+
+    while end > 0 {
+        let start = end.saturating_sub(method.digit_group_width(groups.len()));
+
+        groups.push(&digits[start..end]);
+
+        end = start;
+    }
+
+    groups
+}
+
+/// 依照算術類型寫出整數，每個位組的係數使用阿拉伯數字，例如 **「12億3456萬7890」**。`digits` 由最高位開始，且不能為零。
+///
+/// `dependent` 表示前面已經寫出較高的位組，此時係數會補零到位組的寬度，例如 **「1億0005萬」**。
+fn write_mixed_digits<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    method: ChineseCountMethod,
+    digits: &[u8],
+    dependent: bool,
+) -> fmt::Result {
+    let groups = split_digit_groups(digits, method);
+
+    let first_exponent = match method {
+        ChineseCountMethod::Low | ChineseCountMethod::TenThousand => ChineseExponent::萬,
+        ChineseCountMethod::Middle => ChineseExponent::億,
+        ChineseCountMethod::High => ChineseExponent::兆,
+    };
+
+    let mut written = false;
+
+    for p in (0..groups.len()).rev() {
+        let group = groups[p];
+
+        if group.iter().all(|&d| d == 0) {
+            continue;
+        }
+
+        let dependent = dependent || written;
+
+        match method {
+            ChineseCountMethod::Low | ChineseCountMethod::TenThousand => {
+                if dependent {
+                    write_arabic_digits(f, group)?;
+                } else {
+                    let start = group.iter().position(|&d| d > 0).unwrap();
+
+                    write_arabic_digits(f, &group[start..])?;
+                }
+            },
+            ChineseCountMethod::Middle => {
+                write_mixed_digits(f, options, ChineseCountMethod::TenThousand, group, dependent)?
+            },
+            ChineseCountMethod::High => {
+                let inner =
+                    if p == 0 { ChineseCountMethod::Middle } else { ChineseCountMethod::High };
+
+                write_mixed_digits(f, options, inner, group, dependent)?
+            },
+        }
+
+        if p > 0 {
+            write_exponent(f, options, exponent_after(first_exponent, p - 1))?;
+        }
+
+        written = true;
+    }
+
+    Ok(())
+}
+
+/// 最高位數位於第 `position` 位（由 `0` 開始）時，近似寫法所使用的單位是第幾位。沒有可以使用的單位時回傳 `0`。
+#[inline]
+fn leading_unit_position(method: ChineseCountMethod, position: usize) -> usize {
+    match method {
+        ChineseCountMethod::Low => {
+            if position < 4 {
+                0
+            } else {
+                position.min(15)
+            }
+        },
+        ChineseCountMethod::TenThousand => (position / 4 * 4).min(68),
+        ChineseCountMethod::Middle => (position / 4 * 4).min(132),
+        ChineseCountMethod::High => position / 4 * 4,
+    }
+}
+
+/// 寫出位於第 `position` 位的單位。**「中數」** 和 **「上數」** 的單位可能是由多個單位組成的，例如 **「萬億」**。
+fn write_mixed_unit<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    method: ChineseCountMethod,
+    position: usize,
+) -> fmt::Result {
+    match method {
+        ChineseCountMethod::Low => {
+            write_exponent(f, options, exponent_after(ChineseExponent::萬, position - 4))
+        },
+        ChineseCountMethod::TenThousand => {
+            write_exponent(f, options, exponent_after(ChineseExponent::萬, position / 4 - 1))
+        },
+        ChineseCountMethod::Middle => {
+            let k = position / 4;
+
+            if k % 2 == 1 {
+                write_exponent(f, options, ChineseExponent::萬)?;
+            }
+
+            if k >= 2 {
+                write_exponent(f, options, exponent_after(ChineseExponent::億, k / 2 - 1))?;
+            }
+
+            Ok(())
+        },
+        ChineseCountMethod::High => {
+            let k = position / 4;
+
+            for j in 0..(usize::BITS - k.leading_zeros()) as usize {
+                if (k >> j) & 1 == 1 {
+                    write_exponent(f, options, exponent_after(ChineseExponent::萬, j))?;
+                }
+            }
+
+            Ok(())
+        },
+    }
+}
+
+/// 只寫出最大的單位，係數保留 `significant_digits` 個有效位數，例如 **「1.23億」**。係數的整數部分不會被捨去，所以 `significant_digits` 小於整數部分的位數時（包括 `0`），都只會把係數進位到整數。`digits` 由最高位開始，且至少要有一個單位。
+fn write_mixed_significant<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    negative: bool,
+    mut digits: Vec<u8>,
+    mut fraction: Vec<u8>,
+    significant_digits: usize,
+) -> fmt::Result {
+    let method = options.method();
+    let rounding = options.fraction_options().unwrap_or_default().rounding();
+
+    loop {
+        let position = leading_unit_position(method, digits.len() - 1);
+        let length = digits.len() - position;
+
+        let mut rest = digits.split_off(length);
+        rest.append(&mut fraction);
+
+        let (coefficient, rest) = round_decimal(
+            BigUint::from_radix_be(&digits, 10).unwrap(),
+            rest,
+            significant_digits.saturating_sub(length),
+            rounding,
+            negative,
+        );
+
+        let coefficient = coefficient.to_radix_be(10);
+
+        // 進位後的係數可能會需要使用更大的單位
+        if coefficient.len() > length
+            && leading_unit_position(method, coefficient.len() - 1 + position) != position
+        {
+            digits = coefficient;
+            digits.resize(digits.len() + position, 0);
+
+            continue;
+        }
+
+        let rest_length = rest.iter().rposition(|&d| d > 0).map_or(0, |i| i + 1);
+
+        write_arabic_sign(f, options, negative, false)?;
+        write_arabic_digits(f, &coefficient)?;
+
+        if rest_length > 0 {
+            f.write_char('.')?;
+            write_arabic_digits(f, &rest[..rest_length])?;
+        }
+
+        return write_mixed_unit(f, options, method, position);
+    }
+}
+
+/// 依照選項的算術類型寫出數值，每個位組的係數使用阿拉伯數字，例如 **「12億3456萬7890」**、**「3萬5000.5」**。
+///
+/// 如果有指定 `significant_digits`，就只寫出最大的單位，例如 **「1.23億」**。
+pub(crate) fn write_mixed<W: Write, V: Display>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    value: V,
+    significant_digits: Option<usize>,
+) -> Result<(), NumberToChineseError> {
+    let (negative, integer, fraction) = match display_to_decimal(f, options, value)? {
+        Some(decimal) => decimal,
+        None => return Ok(()),
+    };
+
+    let method = options.method();
+
+    check_big_unsigned_integer_range(method, &integer).map_err(|err| {
+        if negative {
+            NumberToChineseError::Underflow
+        } else {
+            err
+        }
+    })?;
+
+    if let Some(significant_digits) = significant_digits {
+        let digits = integer.to_radix_be(10);
+
+        if !integer.is_zero() && leading_unit_position(method, digits.len() - 1) > 0 {
+            return Ok(write_mixed_significant(
+                f,
+                options,
+                negative,
+                digits,
+                fraction,
+                significant_digits,
+            )?);
+        }
+    }
+
+    let (integer, fraction) = match options.fraction_options() {
+        Some(fraction_options) => round_decimal(
+            integer,
+            fraction,
+            fraction_options.precision(),
+            fraction_options.rounding(),
+            negative,
+        ),
+        None => (integer, fraction),
+    };
+
+    let length = fraction.iter().rposition(|&d| d > 0).map_or(0, |i| i + 1);
+
+    write_arabic_sign(f, options, negative, integer.is_zero() && length == 0)?;
+
+    if integer.is_zero() {
+        f.write_char('0')?;
+    } else {
+        write_mixed_digits(f, options, method, &integer.to_radix_be(10), false)?;
+
+        // 個位數的位組為零時，要補上零才能接著寫出小數部分
+        if length > 0 && (&integer % 1_0000u16).is_zero() {
+            f.write_char('0')?;
+        }
+    }
+
+    if length > 0 {
+        f.write_char('.')?;
+        write_arabic_digits(f, &fraction[..length])?;
+    }
+
+    Ok(())
+}
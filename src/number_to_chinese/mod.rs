@@ -1,4 +1,26 @@
+mod approximate;
+mod chinese_approximate_options;
+mod chinese_currency_options;
+mod chinese_display;
+mod chinese_format_options;
+mod chinese_fraction_options;
+mod chinese_fraction_style;
+mod chinese_glyph_style;
+mod chinese_negative_style;
+mod chinese_positive_style;
+mod chinese_rounding;
+mod chinese_script;
+mod chinese_sign_style;
+mod chinese_tai_style;
+mod chinese_ten_style;
+mod chinese_two_style;
+mod chinese_whole_style;
+mod chinese_yuan_style;
+mod chinese_zero_style;
+mod currency;
 mod functions;
+mod grouped;
+mod mixed;
 mod naive;
 mod number_to_chinese_error;
 mod traits;
@@ -7,12 +29,39 @@ mod functions_test;
 
 use alloc::string::String;
 
+use approximate::*;
+pub use chinese_approximate_options::*;
+pub use chinese_currency_options::*;
+pub use chinese_display::*;
+pub use chinese_format_options::*;
+pub use chinese_fraction_options::*;
+pub use chinese_fraction_style::*;
+pub use chinese_glyph_style::*;
+pub use chinese_negative_style::*;
+pub use chinese_positive_style::*;
+pub use chinese_rounding::*;
+pub use chinese_script::*;
+pub use chinese_sign_style::*;
+pub use chinese_tai_style::*;
+pub use chinese_ten_style::*;
+pub use chinese_two_style::*;
+pub use chinese_whole_style::*;
+pub use chinese_yuan_style::*;
+pub use chinese_zero_style::*;
+use currency::*;
 use functions::*;
+use grouped::*;
+use mixed::*;
 pub use naive::*;
+#[cfg(feature = "bigint")]
+use num_bigint::{BigInt, BigUint};
 pub use number_to_chinese_error::*;
 pub use traits::*;
 
-use crate::{ChineseCase, ChineseCountMethod, ChineseSign, ChineseVariant};
+use crate::{
+    ChineseCase, ChineseCountMethod, ChineseDigitSeparator, ChineseOrdinal, ChinesePercent,
+    ChineseSign, ChineseVariant, FractionInteger,
+};
 
 // TODO unsigned integer
 
@@ -427,118 +476,362 @@ pub fn from_isize_to_chinese_high(
 
 // TODO float
 
-/// 將 `f32` 浮點數轉成中文數字，使用 **「下數」**。數值的絕對值不能大於或等於 `1_0000_0000_0000_0000`。
+/// 將 `f32` 浮點數轉成中文數字，使用 **「下數」**。數值的絕對值不能大於或等於 `1_0000_0000_0000_0000`。數值不能是 NaN 或無窮大。
 #[inline]
 pub fn from_f32_to_chinese_low(
     chinese_variant: ChineseVariant,
     chinese_case: ChineseCase,
     value: f32,
 ) -> Result<String, NumberToChineseError> {
-    from_f64_to_chinese_low(chinese_variant, chinese_case, value as f64)
+    check_f64_range(ChineseCountMethod::Low, value as f64, false)?;
+
+    Ok(float_to_chinese(
+        chinese_variant,
+        chinese_case,
+        ChineseCountMethod::Low,
+        value,
+        &ChineseFractionOptions::new(),
+    ))
 }
 
-/// 將 `f32` 浮點數轉成中文數字，使用 **「萬進」**。
+/// 將 `f32` 浮點數轉成中文數字，使用 **「萬進」**。數值不能是 NaN 或無窮大。
 #[inline]
 pub fn from_f32_to_chinese_ten_thousand(
     chinese_variant: ChineseVariant,
     chinese_case: ChineseCase,
     value: f32,
-) -> String {
-    from_f64_to_chinese_ten_thousand(chinese_variant, chinese_case, value as f64).unwrap()
+) -> Result<String, NumberToChineseError> {
+    check_f64_range(ChineseCountMethod::TenThousand, value as f64, false)?;
+
+    Ok(float_to_chinese(
+        chinese_variant,
+        chinese_case,
+        ChineseCountMethod::TenThousand,
+        value,
+        &ChineseFractionOptions::new(),
+    ))
 }
 
-/// 將 `f32` 浮點數轉成中文數字，使用 **「中數」**。
+/// 將 `f32` 浮點數轉成中文數字，使用 **「中數」**。數值不能是 NaN 或無窮大。
 #[inline]
 pub fn from_f32_to_chinese_middle(
     chinese_variant: ChineseVariant,
     chinese_case: ChineseCase,
     value: f32,
-) -> String {
-    from_f64_to_chinese_middle(chinese_variant, chinese_case, value as f64).unwrap()
+) -> Result<String, NumberToChineseError> {
+    check_f64_range(ChineseCountMethod::Middle, value as f64, false)?;
+
+    Ok(float_to_chinese(
+        chinese_variant,
+        chinese_case,
+        ChineseCountMethod::Middle,
+        value,
+        &ChineseFractionOptions::new(),
+    ))
 }
 
-/// 將 `f32` 浮點數轉成中文數字，使用 **「上數」**。
+/// 將 `f32` 浮點數轉成中文數字，使用 **「上數」**。數值不能是 NaN 或無窮大。
 #[inline]
 pub fn from_f32_to_chinese_high(
     chinese_variant: ChineseVariant,
     chinese_case: ChineseCase,
     value: f32,
-) -> String {
-    from_f64_to_chinese_high(chinese_variant, chinese_case, value as f64)
+) -> Result<String, NumberToChineseError> {
+    check_f64_range(ChineseCountMethod::High, value as f64, false)?;
+
+    Ok(float_to_chinese(
+        chinese_variant,
+        chinese_case,
+        ChineseCountMethod::High,
+        value,
+        &ChineseFractionOptions::new(),
+    ))
 }
 
 #[inline]
-fn from_f64_to_chinese(
-    chinese_variant: ChineseVariant,
-    chinese_case: ChineseCase,
+fn check_f64_range(
     method: ChineseCountMethod,
     value: f64,
-) -> String {
-    if value < 0.0 {
-        let mut s = positive_float_to_chinese(chinese_variant, chinese_case, method, -value);
-
-        s.insert_str(0, ChineseSign::負.to_str(chinese_variant));
+    non_finite: bool,
+) -> Result<(), NumberToChineseError> {
+    if !value.is_finite() {
+        return if non_finite {
+            Ok(())
+        } else if value.is_nan() {
+            Err(NumberToChineseError::NotANumber)
+        } else {
+            Err(NumberToChineseError::Infinite)
+        };
+    }
 
-        s
+    let max = match method {
+        ChineseCountMethod::Low => 1_0000_0000_0000_0000f64,
+        ChineseCountMethod::TenThousand => 1e72,
+        ChineseCountMethod::Middle => 1e136,
+        ChineseCountMethod::High => return Ok(()),
+    };
+
+    if value >= max {
+        Err(NumberToChineseError::Overflow)
+    } else if value <= -max {
+        Err(NumberToChineseError::Underflow)
     } else {
-        positive_float_to_chinese(chinese_variant, chinese_case, method, value)
+        Ok(())
     }
 }
 
-/// 將 `f64` 浮點數轉成中文數字，使用 **「下數」**。數值的絕對值不能大於或等於 `1_0000_0000_0000_0000`。
+/// 將 `f64` 浮點數轉成中文數字，使用 **「下數」**。數值的絕對值不能大於或等於 `1_0000_0000_0000_0000`。數值不能是 NaN 或無窮大。
 #[inline]
 pub fn from_f64_to_chinese_low(
     chinese_variant: ChineseVariant,
     chinese_case: ChineseCase,
     value: f64,
 ) -> Result<String, NumberToChineseError> {
-    if value >= 1_0000_0000_0000_0000f64 {
-        return Err(NumberToChineseError::Overflow);
-    } else if value <= -1_0000_0000_0000_0000f64 {
-        return Err(NumberToChineseError::Underflow);
-    }
+    check_f64_range(ChineseCountMethod::Low, value, false)?;
 
-    Ok(from_f64_to_chinese(chinese_variant, chinese_case, ChineseCountMethod::Low, value))
+    Ok(float_to_chinese(
+        chinese_variant,
+        chinese_case,
+        ChineseCountMethod::Low,
+        value,
+        &ChineseFractionOptions::new(),
+    ))
 }
 
-/// 將 `f64` 浮點數轉成中文數字，使用 **「萬進」**。數值的絕對值不能大於或等於 `1e52`。
+/// 將 `f64` 浮點數轉成中文數字，使用 **「萬進」**。數值的絕對值不能大於或等於 `1e72`。數值不能是 NaN 或無窮大。
 #[inline]
 pub fn from_f64_to_chinese_ten_thousand(
     chinese_variant: ChineseVariant,
     chinese_case: ChineseCase,
     value: f64,
 ) -> Result<String, NumberToChineseError> {
-    if value >= 1e52 {
-        return Err(NumberToChineseError::Overflow);
-    } else if value <= -1e52 {
-        return Err(NumberToChineseError::Underflow);
-    }
+    check_f64_range(ChineseCountMethod::TenThousand, value, false)?;
 
-    Ok(from_f64_to_chinese(chinese_variant, chinese_case, ChineseCountMethod::TenThousand, value))
+    Ok(float_to_chinese(
+        chinese_variant,
+        chinese_case,
+        ChineseCountMethod::TenThousand,
+        value,
+        &ChineseFractionOptions::new(),
+    ))
 }
 
-/// 將 `f64` 浮點數轉成中文數字，使用 **「中數」**。數值的絕對值不能大於或等於 `1e96`。
+/// 將 `f64` 浮點數轉成中文數字，使用 **「中數」**。數值的絕對值不能大於或等於 `1e136`。數值不能是 NaN 或無窮大。
 #[inline]
 pub fn from_f64_to_chinese_middle(
     chinese_variant: ChineseVariant,
     chinese_case: ChineseCase,
     value: f64,
 ) -> Result<String, NumberToChineseError> {
-    if value >= 1e96 {
-        return Err(NumberToChineseError::Overflow);
-    } else if value <= -1e96 {
-        return Err(NumberToChineseError::Underflow);
-    }
+    check_f64_range(ChineseCountMethod::Middle, value, false)?;
 
-    Ok(from_f64_to_chinese(chinese_variant, chinese_case, ChineseCountMethod::Middle, value))
+    Ok(float_to_chinese(
+        chinese_variant,
+        chinese_case,
+        ChineseCountMethod::Middle,
+        value,
+        &ChineseFractionOptions::new(),
+    ))
 }
 
-/// 將 `f64` 浮點數轉成中文數字，使用 **「上數」**。
+/// 將 `f64` 浮點數轉成中文數字，使用 **「上數」**。數值不能是 NaN 或無窮大。
 #[inline]
 pub fn from_f64_to_chinese_high(
     chinese_variant: ChineseVariant,
     chinese_case: ChineseCase,
     value: f64,
-) -> String {
-    from_f64_to_chinese(chinese_variant, chinese_case, ChineseCountMethod::High, value)
+) -> Result<String, NumberToChineseError> {
+    check_f64_range(ChineseCountMethod::High, value, false)?;
+
+    Ok(float_to_chinese(
+        chinese_variant,
+        chinese_case,
+        ChineseCountMethod::High,
+        value,
+        &ChineseFractionOptions::new(),
+    ))
+}
+
+/// 將 `f32` 浮點數轉成中文數字，小數部分使用 **「點」** 逐位表示，而不是 **「角」** 和 **「分」**。數值的範圍限制和 `from_f64_to_chinese_decimal` 相同。數值不能是 NaN 或無窮大。
+#[inline]
+pub fn from_f32_to_chinese_decimal(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    method: ChineseCountMethod,
+    value: f32,
+) -> Result<String, NumberToChineseError> {
+    check_f64_range(method, value as f64, false)?;
+
+    Ok(float_to_chinese_decimal(chinese_variant, chinese_case, method, value))
+}
+
+/// 將 `f64` 浮點數轉成中文數字，小數部分使用 **「點」** 逐位表示，而不是 **「角」** 和 **「分」**。小數的位數不受限制，會使用和 `Display` 相同、能夠還原該數值的最短十進位表示法。數值不能是 NaN 或無窮大。
+///
+/// * 如果使用 **「下數」**，則數值的絕對值不能大於或等於 `1_0000_0000_0000_0000`。
+/// * 如果使用 **「萬進」**，則數值的絕對值不能大於或等於 `1e72`。
+/// * 如果使用 **「中數」**，則數值的絕對值不能大於或等於 `1e136`。
+#[inline]
+pub fn from_f64_to_chinese_decimal(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    method: ChineseCountMethod,
+    value: f64,
+) -> Result<String, NumberToChineseError> {
+    check_f64_range(method, value, false)?;
+
+    Ok(float_to_chinese_decimal(chinese_variant, chinese_case, method, value))
+}
+
+/// 將 `f32` 浮點數轉成中文數字，小數部分使用 **「角」** 和 **「分」** 表示，並依照選項決定要保留的位數和進位方式。數值的範圍限制和 `from_f64_to_chinese_with_fraction` 相同。除非在選項中允許，否則數值不能是 NaN 或無窮大。
+#[inline]
+pub fn from_f32_to_chinese_with_fraction(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    method: ChineseCountMethod,
+    value: f32,
+    options: &ChineseFractionOptions,
+) -> Result<String, NumberToChineseError> {
+    check_f64_range(method, value as f64, options.non_finite())?;
+
+    Ok(float_to_chinese(chinese_variant, chinese_case, method, value, options))
+}
+
+/// 將 `f64` 浮點數轉成中文數字，小數部分使用 **「角」** 和 **「分」** 表示，並依照選項決定要保留的位數和進位方式。除非在選項中允許，否則數值不能是 NaN 或無窮大。
+///
+/// * 如果使用 **「下數」**，則數值的絕對值不能大於或等於 `1_0000_0000_0000_0000`。
+/// * 如果使用 **「萬進」**，則數值的絕對值不能大於或等於 `1e72`。
+/// * 如果使用 **「中數」**，則數值的絕對值不能大於或等於 `1e136`。
+#[inline]
+pub fn from_f64_to_chinese_with_fraction(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    method: ChineseCountMethod,
+    value: f64,
+    options: &ChineseFractionOptions,
+) -> Result<String, NumberToChineseError> {
+    check_f64_range(method, value, options.non_finite())?;
+
+    Ok(float_to_chinese(chinese_variant, chinese_case, method, value, options))
+}
+
+// TODO big integer
+
+#[cfg(feature = "bigint")]
+#[inline]
+fn from_biguint_to_chinese(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    method: ChineseCountMethod,
+    value: BigUint,
+) -> Result<String, NumberToChineseError> {
+    check_big_unsigned_integer_range(method, &value)?;
+
+    Ok(big_unsigned_integer_to_chinese(chinese_variant, chinese_case, method, value))
+}
+
+#[cfg(feature = "bigint")]
+#[inline]
+fn from_bigint_to_chinese(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    method: ChineseCountMethod,
+    value: BigInt,
+) -> Result<String, NumberToChineseError> {
+    let mut s = String::new();
+
+    write_big_signed_integer(
+        &mut s,
+        &ChineseFormatOptions::from((chinese_variant, chinese_case, method)),
+        value,
+    )?;
+
+    Ok(s)
+}
+
+/// 將 `BigUint` 整數轉成中文數字，使用 **「下數」**。數值不能大於或等於 `1_0000_0000_0000_0000`。
+#[cfg(feature = "bigint")]
+#[inline]
+pub fn from_biguint_to_chinese_low(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: BigUint,
+) -> Result<String, NumberToChineseError> {
+    from_biguint_to_chinese(chinese_variant, chinese_case, ChineseCountMethod::Low, value)
+}
+
+/// 將 `BigUint` 整數轉成中文數字，使用 **「萬進」**。數值不能大於或等於 `1e72`。
+#[cfg(feature = "bigint")]
+#[inline]
+pub fn from_biguint_to_chinese_ten_thousand(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: BigUint,
+) -> Result<String, NumberToChineseError> {
+    from_biguint_to_chinese(chinese_variant, chinese_case, ChineseCountMethod::TenThousand, value)
+}
+
+/// 將 `BigUint` 整數轉成中文數字，使用 **「中數」**。數值不能大於或等於 `1e136`。
+#[cfg(feature = "bigint")]
+#[inline]
+pub fn from_biguint_to_chinese_middle(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: BigUint,
+) -> Result<String, NumberToChineseError> {
+    from_biguint_to_chinese(chinese_variant, chinese_case, ChineseCountMethod::Middle, value)
+}
+
+/// 將 `BigUint` 整數轉成中文數字，使用 **「上數」**。數值不能大於或等於 `1e16384`。
+#[cfg(feature = "bigint")]
+#[inline]
+pub fn from_biguint_to_chinese_high(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: BigUint,
+) -> Result<String, NumberToChineseError> {
+    from_biguint_to_chinese(chinese_variant, chinese_case, ChineseCountMethod::High, value)
+}
+
+/// 將 `BigInt` 整數轉成中文數字，使用 **「下數」**。數值的絕對值不能大於或等於 `1_0000_0000_0000_0000`。
+#[cfg(feature = "bigint")]
+#[inline]
+pub fn from_bigint_to_chinese_low(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: BigInt,
+) -> Result<String, NumberToChineseError> {
+    from_bigint_to_chinese(chinese_variant, chinese_case, ChineseCountMethod::Low, value)
+}
+
+/// 將 `BigInt` 整數轉成中文數字，使用 **「萬進」**。數值的絕對值不能大於或等於 `1e72`。
+#[cfg(feature = "bigint")]
+#[inline]
+pub fn from_bigint_to_chinese_ten_thousand(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: BigInt,
+) -> Result<String, NumberToChineseError> {
+    from_bigint_to_chinese(chinese_variant, chinese_case, ChineseCountMethod::TenThousand, value)
+}
+
+/// 將 `BigInt` 整數轉成中文數字，使用 **「中數」**。數值的絕對值不能大於或等於 `1e136`。
+#[cfg(feature = "bigint")]
+#[inline]
+pub fn from_bigint_to_chinese_middle(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: BigInt,
+) -> Result<String, NumberToChineseError> {
+    from_bigint_to_chinese(chinese_variant, chinese_case, ChineseCountMethod::Middle, value)
+}
+
+/// 將 `BigInt` 整數轉成中文數字，使用 **「上數」**。數值的絕對值不能大於或等於 `1e16384`。
+#[cfg(feature = "bigint")]
+#[inline]
+pub fn from_bigint_to_chinese_high(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: BigInt,
+) -> Result<String, NumberToChineseError> {
+    from_bigint_to_chinese(chinese_variant, chinese_case, ChineseCountMethod::High, value)
 }
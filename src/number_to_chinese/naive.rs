@@ -1,98 +1,181 @@
-use alloc::{
-    string::{String, ToString},
-    vec::Vec,
-};
+use alloc::string::String;
+use core::fmt::{self, Display, Write};
 
 use chinese_variant::ChineseVariant;
+#[cfg(feature = "bigint")]
+use num_bigint::BigInt;
 use num_bigint::BigUint;
 #[cfg(not(feature = "std"))]
 #[allow(unused_imports)]
 use num_traits::float::FloatCore;
-use num_traits::{FromPrimitive, ToPrimitive, Zero};
-
-use crate::{
-    chinese_characters::{ChineseNumber, ChinesePoint, ChineseSign},
-    ChineseCase,
+use num_traits::Zero;
+
+use super::{
+    functions::{
+        float_to_decimal, round_decimal, to_string_with, write_fraction_separator,
+        write_non_finite, write_number, write_point, write_sign,
+    },
+    ChineseFormatOptions, ChineseFractionOptions, NumberToChineseError,
 };
+use crate::ChineseCase;
+
+/// 依照選項逐位寫出數字。`digits` 由最高位開始，並依照選項分隔位組。
+fn write_digits<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    digits: impl ExactSizeIterator<Item = u8>,
+) -> fmt::Result {
+    let length = digits.len();
+
+    for (i, d) in digits.enumerate() {
+        if let Some((size, separator)) = options.digit_grouping() {
+            if i > 0 && (length - i) % size == 0 {
+                f.write_char(separator.to_char())?;
+            }
+        }
 
-fn unsigned_integer_to_chinese(
-    chinese_variant: ChineseVariant,
-    chinese_case: ChineseCase,
-    mut value: u128,
-) -> String {
-    if value == 0 {
-        return ChineseNumber::零.to_str(chinese_variant, chinese_case).to_string();
+        write_number(f, options, d, None, false)?;
     }
 
-    let mut numbers: Vec<ChineseNumber> = Vec::with_capacity(1);
+    Ok(())
+}
+
+pub(crate) fn write_unsigned_integer_naive<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    mut value: u128,
+) -> fmt::Result {
+    let mut numbers = [0u8; 39];
+    let mut length = 0;
 
-    while value > 0 {
-        let n = (value % 10) as u8;
+    loop {
+        numbers[length] = (value % 10) as u8;
         value /= 10;
 
-        numbers.push(unsafe { ChineseNumber::from_ordinal_unsafe(n) });
+        length += 1;
+
+        if value == 0 {
+            break;
+        }
     }
 
-    numbers.into_iter().rev().map(|cn| cn.to_str(chinese_variant, chinese_case)).collect()
+    write_digits(f, options, numbers[..length].iter().rev().copied())
 }
 
-fn big_unsigned_integer_to_chinese(
-    chinese_variant: ChineseVariant,
-    chinese_case: ChineseCase,
-    mut value: BigUint,
-) -> String {
-    let big_0 = BigUint::zero();
-    let big_10 = BigUint::from(10u8);
+pub(crate) fn write_signed_integer_naive<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    value: i128,
+) -> fmt::Result {
+    write_sign(f, options, value < 0, value == 0)?;
 
-    if value == big_0 {
-        return ChineseNumber::零.to_str(chinese_variant, chinese_case).to_string();
+    if value < 0 {
+        write_unsigned_integer_naive(f, options, -(value + 1) as u128 + 1)
+    } else {
+        write_unsigned_integer_naive(f, options, value as u128)
     }
+}
 
-    let mut numbers: Vec<ChineseNumber> = Vec::with_capacity(1);
+pub(crate) fn write_big_unsigned_integer_naive<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    value: &BigUint,
+) -> fmt::Result {
+    write_digits(f, options, value.to_radix_be(10).into_iter())
+}
 
-    while value > big_0 {
-        let n = (value.clone() % &big_10).to_u8().unwrap();
-        value /= &big_10;
+#[cfg(feature = "bigint")]
+pub(crate) fn write_big_signed_integer_naive<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    value: &BigInt,
+) -> fmt::Result {
+    write_sign(f, options, value.sign() == num_bigint::Sign::Minus, value.is_zero())?;
+    write_big_unsigned_integer_naive(f, options, value.magnitude())
+}
 
-        numbers.push(unsafe { ChineseNumber::from_ordinal_unsafe(n) });
+/// 依照選項逐位寫出分數，分母在前，例如 **「三分之一」**。分母不能是 `0`。
+pub(crate) fn write_fraction_naive<W: Write>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    negative: bool,
+    numerator: u128,
+    denominator: u128,
+) -> Result<(), NumberToChineseError> {
+    if denominator == 0 {
+        return Err(NumberToChineseError::ZeroDenominator);
     }
 
-    numbers.into_iter().rev().map(|cn| cn.to_str(chinese_variant, chinese_case)).collect()
+    write_sign(f, options, negative, numerator == 0)?;
+    write_unsigned_integer_naive(f, options, denominator)?;
+    write_fraction_separator(f, options)?;
+    Ok(write_unsigned_integer_naive(f, options, numerator)?)
 }
 
-fn positive_float_to_chinese(
-    chinese_variant: ChineseVariant,
-    chinese_case: ChineseCase,
-    value: f64,
-) -> String {
-    let (integer, fraction) = {
-        let integer = BigUint::from_f64(value.trunc()).unwrap();
-        let fraction = ((value.fract() * 100.0).round() % 100f64) as u8;
+/// 依照選項逐位寫出浮點數，小數部分的位數和進位方式使用 `options.fraction_options()`，沒有設定時使用 `ChineseFractionOptions::new()`。NaN 和無窮大只有在選項允許時才會被寫成 **「非數」** 和 **「無窮大」**，否則會回傳錯誤。
+pub(crate) fn write_float_naive<W: Write, F: Copy + Display + Into<f64>>(
+    f: &mut W,
+    options: &ChineseFormatOptions,
+    value: F,
+) -> Result<(), NumberToChineseError> {
+    let fraction_options = options.fraction_options().unwrap_or_default();
+
+    let v = value.into();
 
-        (integer, fraction)
-    };
+    if !v.is_finite() {
+        if fraction_options.non_finite() {
+            return Ok(write_non_finite(f, options, v)?);
+        }
+
+        return Err(if v.is_nan() {
+            NumberToChineseError::NotANumber
+        } else {
+            NumberToChineseError::Infinite
+        });
+    }
 
-    let mut s = big_unsigned_integer_to_chinese(chinese_variant, chinese_case, integer);
+    let (negative, integer, fraction) = float_to_decimal(value);
 
-    if fraction > 0 {
-        s.push_str(ChinesePoint::to_str(chinese_variant));
+    let (integer, fraction) = round_decimal(
+        integer,
+        fraction,
+        fraction_options.precision(),
+        fraction_options.rounding(),
+        negative,
+    );
 
-        s.push_str(
-            unsafe { ChineseNumber::from_ordinal_unsafe(fraction / 10) }
-                .to_str(chinese_variant, chinese_case),
-        );
+    let length = fraction.iter().rposition(|&d| d > 0).map_or(0, |i| i + 1);
 
-        let d = fraction % 10;
+    write_sign(f, options, negative, integer.is_zero() && length == 0)?;
+    write_big_unsigned_integer_naive(f, options, &integer)?;
 
-        if d > 0 {
-            s.push_str(
-                unsafe { ChineseNumber::from_ordinal_unsafe(d) }
-                    .to_str(chinese_variant, chinese_case),
-            );
+    if length > 0 {
+        write_point(f, options)?;
+
+        for &d in &fraction[..length] {
+            write_number(f, options, d, None, false)?;
         }
     }
 
-    s
+    Ok(())
+}
+
+#[inline]
+fn naive_options(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+) -> ChineseFormatOptions {
+    ChineseFormatOptions::new().with_variant(chinese_variant).with_case(chinese_case)
+}
+
+fn unsigned_integer_to_chinese(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: u128,
+) -> String {
+    to_string_with(|s| {
+        write_unsigned_integer_naive(s, &naive_options(chinese_variant, chinese_case), value)
+    })
 }
 
 /// 將 `u8` 整數轉成中文數字，不進行單位計算。
@@ -202,16 +285,9 @@ pub fn from_i128_to_chinese_naive(
     chinese_case: ChineseCase,
     value: i128,
 ) -> String {
-    if value < 0 {
-        let mut s =
-            from_u128_to_chinese_naive(chinese_variant, chinese_case, -(value + 1) as u128 + 1);
-
-        s.insert_str(0, ChineseSign::負.to_str(chinese_variant));
-
-        s
-    } else {
-        from_u128_to_chinese_naive(chinese_variant, chinese_case, value as u128)
-    }
+    to_string_with(|s| {
+        write_signed_integer_naive(s, &naive_options(chinese_variant, chinese_case), value)
+    })
 }
 
 /// 將 `isize` 整數轉成中文數字，不進行單位計算。
@@ -224,39 +300,86 @@ pub fn from_isize_to_chinese_naive(
     from_i128_to_chinese_naive(chinese_variant, chinese_case, value as i128)
 }
 
-/// 將 `f32` 整數轉成中文數字，不進行單位計算。
+/// 將 `f32` 浮點數轉成中文數字，不進行單位計算。數值不能是 NaN 或無窮大。
 #[inline]
 pub fn from_f32_to_chinese_naive(
     chinese_variant: ChineseVariant,
     chinese_case: ChineseCase,
     value: f32,
-) -> String {
-    from_f64_to_chinese_naive(chinese_variant, chinese_case, value as f64)
+) -> Result<String, NumberToChineseError> {
+    float_to_chinese(chinese_variant, chinese_case, value, &ChineseFractionOptions::new())
 }
 
 #[inline]
-fn from_f64_to_chinese(
+fn float_to_chinese<F: Copy + Display + Into<f64>>(
     chinese_variant: ChineseVariant,
     chinese_case: ChineseCase,
-    value: f64,
-) -> String {
-    if value < 0.0 {
-        let mut s = positive_float_to_chinese(chinese_variant, chinese_case, -value);
+    value: F,
+    options: &ChineseFractionOptions,
+) -> Result<String, NumberToChineseError> {
+    let options = naive_options(chinese_variant, chinese_case).with_fraction_options(*options);
 
-        s.insert_str(0, ChineseSign::負.to_str(chinese_variant));
+    let mut s = String::new();
 
-        s
-    } else {
-        positive_float_to_chinese(chinese_variant, chinese_case, value)
-    }
+    write_float_naive(&mut s, &options, value)?;
+
+    Ok(s)
 }
 
-/// 將 `f64` 浮點數轉成中文數字，不進行單位計算。
+/// 將 `f64` 浮點數轉成中文數字，不進行單位計算。數值不能是 NaN 或無窮大。
 #[inline]
 pub fn from_f64_to_chinese_naive(
     chinese_variant: ChineseVariant,
     chinese_case: ChineseCase,
     value: f64,
+) -> Result<String, NumberToChineseError> {
+    float_to_chinese(chinese_variant, chinese_case, value, &ChineseFractionOptions::new())
+}
+
+/// 將 `f32` 浮點數轉成中文數字，不進行單位計算，並依照選項決定要保留的小數位數和進位方式。NaN 和無窮大只有在選項允許時才會被轉成 **「非數」** 和 **「無窮大」**，否則會回傳錯誤。
+#[inline]
+pub fn from_f32_to_chinese_naive_with_fraction(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: f32,
+    options: &ChineseFractionOptions,
+) -> Result<String, NumberToChineseError> {
+    float_to_chinese(chinese_variant, chinese_case, value, options)
+}
+
+/// 將 `f64` 浮點數轉成中文數字，不進行單位計算，並依照選項決定要保留的小數位數和進位方式。小數部分結尾的零會被省略。NaN 和無窮大只有在選項允許時才會被轉成 **「非數」** 和 **「無窮大」**，否則會回傳錯誤。
+#[inline]
+pub fn from_f64_to_chinese_naive_with_fraction(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: f64,
+    options: &ChineseFractionOptions,
+) -> Result<String, NumberToChineseError> {
+    float_to_chinese(chinese_variant, chinese_case, value, options)
+}
+
+/// 將 `BigUint` 整數轉成中文數字，不進行單位計算。
+#[cfg(feature = "bigint")]
+#[inline]
+pub fn from_biguint_to_chinese_naive(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: BigUint,
+) -> String {
+    to_string_with(|s| {
+        write_big_unsigned_integer_naive(s, &naive_options(chinese_variant, chinese_case), &value)
+    })
+}
+
+/// 將 `BigInt` 整數轉成中文數字，不進行單位計算。
+#[cfg(feature = "bigint")]
+#[inline]
+pub fn from_bigint_to_chinese_naive(
+    chinese_variant: ChineseVariant,
+    chinese_case: ChineseCase,
+    value: BigInt,
 ) -> String {
-    from_f64_to_chinese(chinese_variant, chinese_case, value)
+    to_string_with(|s| {
+        write_big_signed_integer_naive(s, &naive_options(chinese_variant, chinese_case), &value)
+    })
 }
@@ -4,9 +4,15 @@ use std::error::Error;
 
 /// 將數值轉成中文數字時發生的錯誤。
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum NumberToChineseError {
     Overflow,
     Underflow,
+    NotANumber,
+    Infinite,
+    NotAnOrdinal,
+    ZeroDenominator,
+    Fmt,
 }
 
 impl Display for NumberToChineseError {
@@ -15,9 +21,21 @@ impl Display for NumberToChineseError {
         match self {
             NumberToChineseError::Overflow => f.write_str("number is too large"),
             NumberToChineseError::Underflow => f.write_str("number is too small"),
+            NumberToChineseError::NotANumber => f.write_str("number is NaN"),
+            NumberToChineseError::Infinite => f.write_str("number is infinite"),
+            NumberToChineseError::NotAnOrdinal => f.write_str("number is not a positive integer"),
+            NumberToChineseError::ZeroDenominator => f.write_str("denominator is zero"),
+            NumberToChineseError::Fmt => f.write_str("failed to write the chinese number"),
         }
     }
 }
 
+impl From<fmt::Error> for NumberToChineseError {
+    #[inline]
+    fn from(_: fmt::Error) -> Self {
+        NumberToChineseError::Fmt
+    }
+}
+
 #[cfg(feature = "std")]
 impl Error for NumberToChineseError {}
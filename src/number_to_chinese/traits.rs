@@ -1,3 +1,8 @@
+use core::fmt::{Display, Write};
+
+#[cfg(feature = "bigint")]
+use num_traits::Zero;
+
 use super::*;
 
 /// 讓 Rust 程式語言的所有基本數值型別擁有轉成中文數字的能力。
@@ -5,8 +10,9 @@ pub trait NumberToChinese {
     /// 將數值轉成中文數字。
     ///
     /// * 如果使用 **「下數」**，則數值的絕對值不能大於或等於 `1_0000_0000_0000_0000`。
-    /// * 如果使用 **「萬進」**，則數值的絕對值不能大於或等於 `1e52`。
-    /// * 如果使用 **「中數」**，則數值的絕對值不能大於或等於 `1e96`。
+    /// * 如果使用 **「萬進」**，則數值的絕對值不能大於或等於 `1e72`。
+    /// * 如果使用 **「中數」**，則數值的絕對值不能大於或等於 `1e136`。
+    /// * 數值不能是 NaN 或無窮大。
     ///
     /// ## 範例
     ///
@@ -22,7 +28,7 @@ pub trait NumberToChinese {
         method: ChineseCountMethod,
     ) -> Result<String, NumberToChineseError>;
 
-    /// 將數值直接轉成中文數字，不進行單位計算。
+    /// 將數值直接轉成中文數字，不進行單位計算。浮點數不能是 NaN 或無窮大。
     ///
     /// ## 範例
     ///
@@ -35,10 +41,724 @@ pub trait NumberToChinese {
     ///     "一二三四五六七八九",
     ///     123456789
     ///         .to_chinese_naive(ChineseVariant::Traditional, ChineseCase::Lower)
+    ///         .unwrap()
+    /// );
+    /// ```
+    fn to_chinese_naive(
+        self,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+    ) -> Result<String, NumberToChineseError>;
+
+    /// 將數值轉成中文數字，小數部分使用 **「點」** 逐位表示，而不是 **「角」** 和 **「分」**。整數的結果和 `to_chinese` 相同。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{
+    ///     ChineseCase, ChineseCountMethod, ChineseVariant, NumberToChinese,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "一點二三四五",
+    ///     1.2345f64
+    ///         .to_chinese_decimal(
+    ///             ChineseVariant::Traditional,
+    ///             ChineseCase::Lower,
+    ///             ChineseCountMethod::TenThousand
+    ///         )
+    ///         .unwrap()
+    /// );
+    /// ```
+    #[inline]
+    fn to_chinese_decimal(
+        self,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+        method: ChineseCountMethod,
+    ) -> Result<String, NumberToChineseError>
+    where
+        Self: Sized, {
+        self.to_chinese(chinese_variant, chinese_case, method)
+    }
+
+    /// 將數值轉成中文數字，小數部分使用 **「角」** 和 **「分」** 表示，並依照選項決定要保留的位數和進位方式。整數的結果和 `to_chinese` 相同。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{
+    ///     ChineseCase, ChineseCountMethod, ChineseFractionOptions,
+    ///     ChineseRounding, ChineseVariant, NumberToChinese,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "九角九分",
+    ///     0.999f64
+    ///         .to_chinese_with_fraction(
+    ///             ChineseVariant::Traditional,
+    ///             ChineseCase::Lower,
+    ///             ChineseCountMethod::TenThousand,
+    ///             &ChineseFractionOptions::new()
+    ///                 .with_rounding(ChineseRounding::Truncate)
+    ///         )
+    ///         .unwrap()
+    /// );
+    /// ```
+    #[inline]
+    fn to_chinese_with_fraction(
+        self,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+        method: ChineseCountMethod,
+        _options: &ChineseFractionOptions,
+    ) -> Result<String, NumberToChineseError>
+    where
+        Self: Sized, {
+        self.to_chinese(chinese_variant, chinese_case, method)
+    }
+
+    /// 將數值直接轉成中文數字，不進行單位計算，並依照選項決定要保留的小數位數和進位方式。整數的結果和 `to_chinese_naive` 相同。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{
+    ///     ChineseCase, ChineseFractionOptions, ChineseRounding, ChineseVariant,
+    ///     NumberToChinese,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "一點二四",
+    ///     1.235f64
+    ///         .to_chinese_naive_with_fraction(
+    ///             ChineseVariant::Traditional,
+    ///             ChineseCase::Lower,
+    ///             &ChineseFractionOptions::new()
+    ///                 .with_rounding(ChineseRounding::HalfEven)
+    ///         )
+    ///         .unwrap()
+    /// );
+    /// ```
+    #[inline]
+    fn to_chinese_naive_with_fraction(
+        self,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+        _options: &ChineseFractionOptions,
+    ) -> Result<String, NumberToChineseError>
+    where
+        Self: Sized, {
+        self.to_chinese_naive(chinese_variant, chinese_case)
+    }
+
+    /// 將數值轉成中文數字，並寫入 `f` 中。基本整數型別在轉換時不會配置額外的記憶體。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use core::fmt::Write;
+    ///
+    /// use chinese_number::{
+    ///     ChineseCase, ChineseCountMethod, ChineseVariant, NumberToChinese,
+    /// };
+    ///
+    /// let mut s = String::from("第");
+    ///
+    /// 123u8
+    ///     .write_chinese(
+    ///         &mut s,
+    ///         ChineseVariant::Traditional,
+    ///         ChineseCase::Lower,
+    ///         ChineseCountMethod::TenThousand,
+    ///     )
+    ///     .unwrap();
+    ///
+    /// s.write_str("名").unwrap();
+    ///
+    /// assert_eq!("第一百二十三名", s);
+    /// ```
+    #[inline]
+    fn write_chinese<W: Write>(
+        self,
+        f: &mut W,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+        method: ChineseCountMethod,
+    ) -> Result<(), NumberToChineseError>
+    where
+        Self: Sized, {
+        self.write_chinese_with(f, &(chinese_variant, chinese_case, method).into())
+    }
+
+    /// 將數值直接轉成中文數字，不進行單位計算，並寫入 `f` 中。基本整數型別在轉換時不會配置額外的記憶體。
+    #[inline]
+    fn write_chinese_naive<W: Write>(
+        self,
+        f: &mut W,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+    ) -> Result<(), NumberToChineseError>
+    where
+        Self: Sized, {
+        self.write_chinese_naive_with(
+            f,
+            &ChineseFormatOptions::new().with_variant(chinese_variant).with_case(chinese_case),
+        )
+    }
+
+    /// 依照選項將數值直接轉成中文數字，不進行單位計算。會使用選項中的繁簡、大小寫、零的字形、正負號和位組的分隔方式，浮點數還會使用小數部分的位數和進位方式。浮點數是 NaN 或無窮大時，只有在選項允許時才會寫成 **「非數」** 和 **「無窮大」**，否則會回傳錯誤。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{
+    ///     ChineseDigitSeparator, ChineseFormatOptions, ChineseZeroStyle,
+    ///     NumberToChinese,
+    /// };
+    ///
+    /// let options =
+    ///     ChineseFormatOptions::new().with_zero_style(ChineseZeroStyle::Circle);
+    ///
+    /// assert_eq!("二〇二四", 2024.to_chinese_naive_with(&options).unwrap());
+    ///
+    /// let options =
+    ///     options.with_digit_grouping(4, ChineseDigitSeparator::FullWidthComma);
+    ///
+    /// assert_eq!(
+    ///     "一二，三四〇〇，五六七八",
+    ///     12_3400_5678u64.to_chinese_naive_with(&options).unwrap()
+    /// );
+    /// ```
+    #[inline]
+    fn to_chinese_naive_with(
+        self,
+        options: &ChineseFormatOptions,
+    ) -> Result<String, NumberToChineseError>
+    where
+        Self: Sized, {
+        let mut s = String::new();
+
+        self.write_chinese_naive_with(&mut s, options)?;
+
+        Ok(s)
+    }
+
+    /// 依照選項將數值直接轉成中文數字，不進行單位計算，並寫入 `f` 中。基本整數型別在轉換時不會配置額外的記憶體。
+    #[inline]
+    fn write_chinese_naive_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError>
+    where
+        Self: Sized, {
+        f.write_str(
+            self.to_chinese_naive(options.chinese_variant(), options.chinese_case())?.as_str(),
+        )?;
+
+        Ok(())
+    }
+
+    /// 將數值包裝成可以直接格式化成中文數字的 `ChineseDisplay`。數值的限制和 `to_chinese` 相同，不符合時會在這裡回傳錯誤，之後的格式化過程就不會失敗。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{
+    ///     ChineseCase, ChineseCountMethod, ChineseVariant, NumberToChinese,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "負一萬零一",
+    ///     (-10001i32)
+    ///         .to_chinese_display(
+    ///             ChineseVariant::Traditional,
+    ///             ChineseCase::Lower,
+    ///             ChineseCountMethod::TenThousand
+    ///         )
+    ///         .unwrap()
+    ///         .to_string()
+    /// );
+    /// ```
+    #[inline]
+    fn to_chinese_display(
+        self,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+        method: ChineseCountMethod,
+    ) -> Result<ChineseDisplay<Self>, NumberToChineseError>
+    where
+        Self: Sized + Clone, {
+        self.to_chinese_display_with(&(chinese_variant, chinese_case, method).into())
+    }
+
+    /// 依照選項將數值轉成中文數字。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{
+    ///     ChineseFormatOptions, ChineseSignStyle, ChineseZeroStyle,
+    ///     NumberToChinese,
+    /// };
+    ///
+    /// let options = ChineseFormatOptions::new()
+    ///     .with_zero_style(ChineseZeroStyle::Circle)
+    ///     .with_sign_style(ChineseSignStyle::Explicit);
+    ///
+    /// assert_eq!("正一萬〇五", 10005.to_chinese_with(&options).unwrap());
+    /// ```
+    #[inline]
+    fn to_chinese_with(
+        self,
+        options: &ChineseFormatOptions,
+    ) -> Result<String, NumberToChineseError>
+    where
+        Self: Sized, {
+        let mut s = String::new();
+
+        self.write_chinese_with(&mut s, options)?;
+
+        Ok(s)
+    }
+
+    /// 依照選項將數值轉成中文數字，並寫入 `f` 中。基本整數型別在轉換時不會配置額外的記憶體。
+    fn write_chinese_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError>
+    where
+        Self: Sized;
+
+    /// 將數值轉成以 **「第」** 開頭的序數，例如 **「第一百二十三」**。數值必須是大於或等於 `1` 的整數，否則會回傳 `NotAnOrdinal` 錯誤，其它限制和 `to_chinese` 相同。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{
+    ///     ChineseCase, ChineseCountMethod, ChineseVariant, NumberToChinese,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "第一百二十三",
+    ///     123u8
+    ///         .to_chinese_ordinal(
+    ///             ChineseVariant::Traditional,
+    ///             ChineseCase::Lower,
+    ///             ChineseCountMethod::TenThousand
+    ///         )
+    ///         .unwrap()
+    /// );
+    /// ```
+    #[inline]
+    fn to_chinese_ordinal(
+        self,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+        method: ChineseCountMethod,
+    ) -> Result<String, NumberToChineseError>
+    where
+        Self: Sized + Display, {
+        self.to_chinese_ordinal_with(&(chinese_variant, chinese_case, method).into(), "")
+    }
+
+    /// 依照選項將數值轉成以 **「第」** 開頭的序數，並在最後加上 `suffix`，例如 **「第二十屆」**。`suffix` 可以是空字串。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{ChineseFormatOptions, NumberToChinese};
+    ///
+    /// let options = ChineseFormatOptions::new();
+    ///
+    /// assert_eq!("第二十屆", 20.to_chinese_ordinal_with(&options, "屆").unwrap());
+    /// assert_eq!("第三版", 3.to_chinese_ordinal_with(&options, "版").unwrap());
+    /// ```
+    #[inline]
+    fn to_chinese_ordinal_with(
+        self,
+        options: &ChineseFormatOptions,
+        suffix: &str,
+    ) -> Result<String, NumberToChineseError>
+    where
+        Self: Sized + Display, {
+        let mut s = String::new();
+
+        self.write_chinese_ordinal_with(&mut s, options, suffix)?;
+
+        Ok(s)
+    }
+
+    /// 依照選項將數值轉成以 **「第」** 開頭的序數，並在最後加上 `suffix`，寫入 `f` 中。
+    #[inline]
+    fn write_chinese_ordinal_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+        suffix: &str,
+    ) -> Result<(), NumberToChineseError>
+    where
+        Self: Sized + Display, {
+        check_ordinal(&self)?;
+
+        f.write_str(ChineseOrdinal::to_str())?;
+
+        self.write_chinese_with(f, &options.with_sign_style(ChineseSignStyle::Negative))?;
+
+        f.write_str(suffix)?;
+
+        Ok(())
+    }
+
+    /// 依照選項將數值轉成百分數、千分數或萬分數，例如 **「百分之十五點三」**。數值會依照它的十進位表示法精確地移動小數點，移動後的數值的限制和 `to_chinese_with` 相同。
+    ///
+    /// 小數部分一律使用 **「點」** 逐位表示，如果選項有設定小數部分的位數和進位方式，會在移動小數點之後套用。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{
+    ///     ChineseCase, ChineseFormatOptions, ChinesePercent, ChineseVariant,
+    ///     NumberToChinese,
+    /// };
+    ///
+    /// let options = ChineseFormatOptions::new();
+    ///
+    /// assert_eq!(
+    ///     "百分之十五點三",
+    ///     0.153
+    ///         .to_chinese_percent_with(&options, ChinesePercent::Percent)
+    ///         .unwrap()
+    /// );
+    /// assert_eq!(
+    ///     "千分之五",
+    ///     0.005
+    ///         .to_chinese_percent_with(&options, ChinesePercent::PerMille)
+    ///         .unwrap()
+    /// );
+    ///
+    /// let options = options
+    ///     .with_variant(ChineseVariant::Simple)
+    ///     .with_case(ChineseCase::Upper);
+    ///
+    /// assert_eq!(
+    ///     "负万分之叁",
+    ///     (-0.0003)
+    ///         .to_chinese_percent_with(&options, ChinesePercent::PerTenThousand)
+    ///         .unwrap()
+    /// );
+    /// ```
+    #[inline]
+    fn to_chinese_percent_with(
+        self,
+        options: &ChineseFormatOptions,
+        percent: ChinesePercent,
+    ) -> Result<String, NumberToChineseError>
+    where
+        Self: Sized + Display, {
+        let mut s = String::new();
+
+        self.write_chinese_percent_with(&mut s, options, percent)?;
+
+        Ok(s)
+    }
+
+    /// 依照選項將數值轉成百分數、千分數或萬分數，並寫入 `f` 中。
+    #[inline]
+    fn write_chinese_percent_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+        percent: ChinesePercent,
+    ) -> Result<(), NumberToChineseError>
+    where
+        Self: Sized + Display, {
+        write_percent(f, options, percent, self)
+    }
+
+    /// 依照選項的算術類型將數值分成位組，每個位組的係數使用阿拉伯數字，單位使用中文，例如 **「12億3456萬7890」**。除了最高的位組之外，係數都會補零到位組的寬度，例如 **「1億0005萬」**。數值的限制和 `to_chinese_with` 相同。
+    ///
+    /// 如果有指定 `significant_digits`，則只使用最大的單位，並讓係數保留至少這麼多個有效位數，例如 **「1.23億」**，進位方式使用選項中的設定。係數的整數部分不會被捨去，因此 `significant_digits` 為 `0` 時和係數整數部分的位數相同。沒有可以使用的單位的數值會寫出完整的數字。
+    ///
+    /// 負數以 **「-」** 開頭，選項的正負號樣式為 `Explicit` 時，正數以 **「+」** 開頭。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{ChineseFormatOptions, NumberToChinese};
+    ///
+    /// let options = ChineseFormatOptions::new();
+    ///
+    /// assert_eq!(
+    ///     "12億3456萬7890",
+    ///     1234567890u64.to_chinese_mixed_with(&options, None).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     "1.23億",
+    ///     123456789.to_chinese_mixed_with(&options, Some(3)).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     "3.5萬",
+    ///     35000.0.to_chinese_mixed_with(&options, Some(2)).unwrap()
     /// );
     /// ```
-    fn to_chinese_naive(self, chinese_variant: ChineseVariant, chinese_case: ChineseCase)
-        -> String;
+    #[inline]
+    fn to_chinese_mixed_with(
+        self,
+        options: &ChineseFormatOptions,
+        significant_digits: Option<usize>,
+    ) -> Result<String, NumberToChineseError>
+    where
+        Self: Sized + Display, {
+        let mut s = String::new();
+
+        self.write_chinese_mixed_with(&mut s, options, significant_digits)?;
+
+        Ok(s)
+    }
+
+    /// 依照選項的算術類型將數值分成位組，每個位組的係數使用阿拉伯數字，並寫入 `f` 中。
+    #[inline]
+    fn write_chinese_mixed_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+        significant_digits: Option<usize>,
+    ) -> Result<(), NumberToChineseError>
+    where
+        Self: Sized + Display, {
+        write_mixed(f, options, self, significant_digits)
+    }
+
+    /// 依照選項的算術類型將數值寫成以 `separator` 分成位組的阿拉伯數字，例如 **「萬進」** 會寫成 **「12,3456,7890」**，讓每個分隔符號都對應到 **「萬」**、**「億」** 等單位。
+    ///
+    /// * 如果使用 **「下數」**，則最低的位組為四位，之後每一位都是一個位組。
+    /// * 如果使用 **「萬進」**，則每四位一組。
+    /// * 如果使用 **「中數」**，則每八位一組。
+    /// * 如果使用 **「上數」**，則最低的兩個位組為十六位，之後每個位組的位數都是前一個位組的兩倍。
+    ///
+    /// 負數以 **「-」** 開頭，選項的正負號樣式為 `Explicit` 時，正數以 **「+」** 開頭。小數部分會依照選項中的小數設定進位，且不會分組。數值不能是 NaN 或無窮大，除非選項允許寫出它們。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{
+    ///     ChineseCountMethod, ChineseDigitSeparator, ChineseFormatOptions,
+    ///     NumberToChinese,
+    /// };
+    ///
+    /// let options = ChineseFormatOptions::new();
+    ///
+    /// assert_eq!(
+    ///     "12,3456,7890",
+    ///     1234567890u64
+    ///         .to_grouped_digits_with(&options, ChineseDigitSeparator::Comma)
+    ///         .unwrap()
+    /// );
+    /// assert_eq!(
+    ///     "-3456，7890.5",
+    ///     (-34567890.5)
+    ///         .to_grouped_digits_with(
+    ///             &options,
+    ///             ChineseDigitSeparator::FullWidthComma
+    ///         )
+    ///         .unwrap()
+    /// );
+    /// assert_eq!(
+    ///     "12 34567890",
+    ///     1234567890u64
+    ///         .to_grouped_digits_with(
+    ///             &options.with_method(ChineseCountMethod::Middle),
+    ///             ChineseDigitSeparator::Space
+    ///         )
+    ///         .unwrap()
+    /// );
+    /// ```
+    #[inline]
+    fn to_grouped_digits_with(
+        self,
+        options: &ChineseFormatOptions,
+        separator: ChineseDigitSeparator,
+    ) -> Result<String, NumberToChineseError>
+    where
+        Self: Sized + Display, {
+        let mut s = String::new();
+
+        self.write_grouped_digits_with(&mut s, options, separator)?;
+
+        Ok(s)
+    }
+
+    /// 依照選項的算術類型將數值寫成以 `separator` 分成位組的阿拉伯數字，並寫入 `f` 中。
+    #[inline]
+    fn write_grouped_digits_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+        separator: ChineseDigitSeparator,
+    ) -> Result<(), NumberToChineseError>
+    where
+        Self: Sized + Display, {
+        write_grouped(f, options, separator, self)
+    }
+
+    /// 依照選項將數值轉成約略的說法，例如 **「三十多」**、**「近三千」**、**「上萬」**、**「數百萬」**。會依照 `approximate_options` 決定要保留的有效位數、使用 **「近」** 的容許範圍，以及是否只表示數量級。數值的限制和 `to_chinese_with` 相同。
+    ///
+    /// 浮點數只會使用整數部分決定單位，小數部分只會影響是否要加上 **「多」**。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{
+    ///     ChineseApproximateOptions, ChineseFormatOptions, ChineseVariant,
+    ///     NumberToChinese,
+    /// };
+    ///
+    /// let options = ChineseFormatOptions::new();
+    /// let approximate_options = ChineseApproximateOptions::new();
+    ///
+    /// assert_eq!(
+    ///     "三十多",
+    ///     35.to_chinese_approximate_with(&options, &approximate_options).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     "近三千",
+    ///     2950.to_chinese_approximate_with(&options, &approximate_options)
+    ///         .unwrap()
+    /// );
+    ///
+    /// let approximate_options = approximate_options.with_magnitude(true);
+    ///
+    /// assert_eq!(
+    ///     "上萬",
+    ///     12345
+    ///         .to_chinese_approximate_with(&options, &approximate_options)
+    ///         .unwrap()
+    /// );
+    /// assert_eq!(
+    ///     "数百万",
+    ///     3_456_789
+    ///         .to_chinese_approximate_with(
+    ///             &options.with_variant(ChineseVariant::Simple),
+    ///             &approximate_options
+    ///         )
+    ///         .unwrap()
+    /// );
+    /// ```
+    #[inline]
+    fn to_chinese_approximate_with(
+        self,
+        options: &ChineseFormatOptions,
+        approximate_options: &ChineseApproximateOptions,
+    ) -> Result<String, NumberToChineseError>
+    where
+        Self: Sized + Display, {
+        let mut s = String::new();
+
+        self.write_chinese_approximate_with(&mut s, options, approximate_options)?;
+
+        Ok(s)
+    }
+
+    /// 依照選項將數值轉成約略的說法，並寫入 `f` 中。
+    #[inline]
+    fn write_chinese_approximate_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+        approximate_options: &ChineseApproximateOptions,
+    ) -> Result<(), NumberToChineseError>
+    where
+        Self: Sized + Display, {
+        write_approximate(f, options, approximate_options, self)
+    }
+
+    /// 將以分為單位的金額轉成支票等票據上所使用的中文大寫金額，例如 `1_020_000` 會轉成 **「人民幣壹萬零貳佰元整」**。貨幣可以使用 `ChineseCurrencyOptions::renminbi`、`ChineseCurrencyOptions::new_taiwan_dollar` 或 `ChineseCurrencyOptions::hong_kong_dollar` 等預設選項，港幣使用 **「毫」** 和 **「仙」** 代替 **「角」** 和 **「分」**。
+    ///
+    /// 整數部分一律寫出 **「拾」** 前的 **「壹」**，中間連續的零只寫一個 **「零」**。元位是零而角位不是零，或是角位是零而分位不是零時，在 **「元」** 之後寫 **「零」**。沒有寫到 **「分」** 時，在最後寫 **「整」** 或 **「正」**。金額不能是負數。
+    ///
+    /// ## 範例
+    ///
+    /// ```rust
+    /// use chinese_number::{
+    ///     ChineseCurrencyOptions, ChineseVariant, NumberToChinese,
+    /// };
+    ///
+    /// let options = ChineseCurrencyOptions::new();
+    ///
+    /// assert_eq!(
+    ///     "人民幣壹萬零貳佰元整",
+    ///     1_020_000.to_chinese_currency_with(&options).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     "人民幣壹仟陸佰捌拾元零參角貳分",
+    ///     168_032.to_chinese_currency_with(&options).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     "新臺幣壹萬貳仟參佰元整",
+    ///     1_230_000
+    ///         .to_chinese_currency_with(
+    ///             &ChineseCurrencyOptions::new_taiwan_dollar()
+    ///         )
+    ///         .unwrap()
+    /// );
+    /// assert_eq!(
+    ///     "港幣壹佰貳拾圓伍毫",
+    ///     12_050
+    ///         .to_chinese_currency_with(
+    ///             &ChineseCurrencyOptions::hong_kong_dollar()
+    ///         )
+    ///         .unwrap()
+    /// );
+    /// assert_eq!(
+    ///     "人民币叁佰贰拾伍元零肆分",
+    ///     32_504i64
+    ///         .to_chinese_currency_with(
+    ///             &options.with_variant(ChineseVariant::Simple)
+    ///         )
+    ///         .unwrap()
+    /// );
+    /// ```
+    #[inline]
+    fn to_chinese_currency_with(
+        self,
+        options: &ChineseCurrencyOptions,
+    ) -> Result<String, NumberToChineseError>
+    where
+        Self: Sized + Into<i128>, {
+        let mut s = String::new();
+
+        self.write_chinese_currency_with(&mut s, options)?;
+
+        Ok(s)
+    }
+
+    /// 將以分為單位的金額轉成中文大寫金額，並寫入 `f` 中。
+    #[inline]
+    fn write_chinese_currency_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseCurrencyOptions,
+    ) -> Result<(), NumberToChineseError>
+    where
+        Self: Sized + Into<i128>, {
+        write_currency(f, options, self.into())
+    }
+
+    /// 依照選項將數值包裝成可以直接格式化成中文數字的 `ChineseDisplay`。數值的限制和 `to_chinese_with` 相同，不符合時會在這裡回傳錯誤，之後的格式化過程就不會失敗。
+    #[inline]
+    fn to_chinese_display_with(
+        self,
+        options: &ChineseFormatOptions,
+    ) -> Result<ChineseDisplay<Self>, NumberToChineseError>
+    where
+        Self: Sized + Clone, {
+        self.clone().write_chinese_with(&mut Discard, options)?;
+
+        Ok(ChineseDisplay::new(self, *options))
+    }
 }
 
 impl NumberToChinese for u8 {
@@ -55,14 +775,188 @@ impl NumberToChinese for u8 {
     #[inline]
     fn to_chinese_naive(
         self,
-        chinese_variant: ChineseVariant,
-        chinese_case: ChineseCase,
-    ) -> String {
-        from_u8_to_chinese_naive(chinese_variant, chinese_case, self)
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+    ) -> Result<String, NumberToChineseError> {
+        Ok(from_u8_to_chinese_naive(chinese_variant, chinese_case, self))
+    }
+
+    #[inline]
+    fn write_chinese_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        write_unsigned_integer(f, options, self as u128)
+    }
+
+    #[inline]
+    fn write_chinese_naive_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        Ok(write_unsigned_integer_naive(f, options, self as u128)?)
+    }
+}
+
+impl NumberToChinese for i8 {
+    #[inline]
+    fn to_chinese(
+        self,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+        method: ChineseCountMethod,
+    ) -> Result<String, NumberToChineseError> {
+        (self as i128).to_chinese(chinese_variant, chinese_case, method)
+    }
+
+    #[inline]
+    fn to_chinese_naive(
+        self,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+    ) -> Result<String, NumberToChineseError> {
+        Ok(from_i8_to_chinese_naive(chinese_variant, chinese_case, self))
+    }
+
+    #[inline]
+    fn write_chinese_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        write_signed_integer(f, options, self as i128)
+    }
+
+    #[inline]
+    fn write_chinese_naive_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        Ok(write_signed_integer_naive(f, options, self as i128)?)
+    }
+}
+
+impl NumberToChinese for u16 {
+    #[inline]
+    fn to_chinese(
+        self,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+        method: ChineseCountMethod,
+    ) -> Result<String, NumberToChineseError> {
+        (self as u128).to_chinese(chinese_variant, chinese_case, method)
+    }
+
+    #[inline]
+    fn to_chinese_naive(
+        self,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+    ) -> Result<String, NumberToChineseError> {
+        Ok(from_u16_to_chinese_naive(chinese_variant, chinese_case, self))
+    }
+
+    #[inline]
+    fn write_chinese_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        write_unsigned_integer(f, options, self as u128)
+    }
+
+    #[inline]
+    fn write_chinese_naive_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        Ok(write_unsigned_integer_naive(f, options, self as u128)?)
+    }
+}
+
+impl NumberToChinese for i16 {
+    #[inline]
+    fn to_chinese(
+        self,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+        method: ChineseCountMethod,
+    ) -> Result<String, NumberToChineseError> {
+        (self as i128).to_chinese(chinese_variant, chinese_case, method)
+    }
+
+    #[inline]
+    fn to_chinese_naive(
+        self,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+    ) -> Result<String, NumberToChineseError> {
+        Ok(from_i16_to_chinese_naive(chinese_variant, chinese_case, self))
+    }
+
+    #[inline]
+    fn write_chinese_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        write_signed_integer(f, options, self as i128)
+    }
+
+    #[inline]
+    fn write_chinese_naive_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        Ok(write_signed_integer_naive(f, options, self as i128)?)
+    }
+}
+
+impl NumberToChinese for u32 {
+    #[inline]
+    fn to_chinese(
+        self,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+        method: ChineseCountMethod,
+    ) -> Result<String, NumberToChineseError> {
+        (self as u128).to_chinese(chinese_variant, chinese_case, method)
+    }
+
+    #[inline]
+    fn to_chinese_naive(
+        self,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+    ) -> Result<String, NumberToChineseError> {
+        Ok(from_u32_to_chinese_naive(chinese_variant, chinese_case, self))
+    }
+
+    #[inline]
+    fn write_chinese_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        write_unsigned_integer(f, options, self as u128)
+    }
+
+    #[inline]
+    fn write_chinese_naive_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        Ok(write_unsigned_integer_naive(f, options, self as u128)?)
     }
 }
 
-impl NumberToChinese for i8 {
+impl NumberToChinese for i32 {
     #[inline]
     fn to_chinese(
         self,
@@ -78,12 +972,30 @@ impl NumberToChinese for i8 {
         self,
         chinese_variant: ChineseVariant,
         chinese_case: ChineseCase,
-    ) -> String {
-        from_i8_to_chinese_naive(chinese_variant, chinese_case, self)
+    ) -> Result<String, NumberToChineseError> {
+        Ok(from_i32_to_chinese_naive(chinese_variant, chinese_case, self))
+    }
+
+    #[inline]
+    fn write_chinese_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        write_signed_integer(f, options, self as i128)
+    }
+
+    #[inline]
+    fn write_chinese_naive_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        Ok(write_signed_integer_naive(f, options, self as i128)?)
     }
 }
 
-impl NumberToChinese for u16 {
+impl NumberToChinese for u64 {
     #[inline]
     fn to_chinese(
         self,
@@ -99,12 +1011,30 @@ impl NumberToChinese for u16 {
         self,
         chinese_variant: ChineseVariant,
         chinese_case: ChineseCase,
-    ) -> String {
-        from_u16_to_chinese_naive(chinese_variant, chinese_case, self)
+    ) -> Result<String, NumberToChineseError> {
+        Ok(from_u64_to_chinese_naive(chinese_variant, chinese_case, self))
+    }
+
+    #[inline]
+    fn write_chinese_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        write_unsigned_integer(f, options, self as u128)
+    }
+
+    #[inline]
+    fn write_chinese_naive_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        Ok(write_unsigned_integer_naive(f, options, self as u128)?)
     }
 }
 
-impl NumberToChinese for i16 {
+impl NumberToChinese for i64 {
     #[inline]
     fn to_chinese(
         self,
@@ -120,12 +1050,30 @@ impl NumberToChinese for i16 {
         self,
         chinese_variant: ChineseVariant,
         chinese_case: ChineseCase,
-    ) -> String {
-        from_i16_to_chinese_naive(chinese_variant, chinese_case, self)
+    ) -> Result<String, NumberToChineseError> {
+        Ok(from_i64_to_chinese_naive(chinese_variant, chinese_case, self))
+    }
+
+    #[inline]
+    fn write_chinese_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        write_signed_integer(f, options, self as i128)
+    }
+
+    #[inline]
+    fn write_chinese_naive_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        Ok(write_signed_integer_naive(f, options, self as i128)?)
     }
 }
 
-impl NumberToChinese for u32 {
+impl NumberToChinese for u128 {
     #[inline]
     fn to_chinese(
         self,
@@ -133,7 +1081,20 @@ impl NumberToChinese for u32 {
         chinese_case: ChineseCase,
         method: ChineseCountMethod,
     ) -> Result<String, NumberToChineseError> {
-        (self as u128).to_chinese(chinese_variant, chinese_case, method)
+        match method {
+            ChineseCountMethod::Low => {
+                from_u128_to_chinese_low(chinese_variant, chinese_case, self)
+            },
+            ChineseCountMethod::TenThousand => {
+                Ok(from_u128_to_chinese_ten_thousand(chinese_variant, chinese_case, self))
+            },
+            ChineseCountMethod::Middle => {
+                Ok(from_u128_to_chinese_middle(chinese_variant, chinese_case, self))
+            },
+            ChineseCountMethod::High => {
+                Ok(from_u128_to_chinese_high(chinese_variant, chinese_case, self))
+            },
+        }
     }
 
     #[inline]
@@ -141,12 +1102,30 @@ impl NumberToChinese for u32 {
         self,
         chinese_variant: ChineseVariant,
         chinese_case: ChineseCase,
-    ) -> String {
-        from_u32_to_chinese_naive(chinese_variant, chinese_case, self)
+    ) -> Result<String, NumberToChineseError> {
+        Ok(from_u128_to_chinese_naive(chinese_variant, chinese_case, self))
+    }
+
+    #[inline]
+    fn write_chinese_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        write_unsigned_integer(f, options, self)
+    }
+
+    #[inline]
+    fn write_chinese_naive_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        Ok(write_unsigned_integer_naive(f, options, self)?)
     }
 }
 
-impl NumberToChinese for i32 {
+impl NumberToChinese for i128 {
     #[inline]
     fn to_chinese(
         self,
@@ -154,7 +1133,20 @@ impl NumberToChinese for i32 {
         chinese_case: ChineseCase,
         method: ChineseCountMethod,
     ) -> Result<String, NumberToChineseError> {
-        (self as i128).to_chinese(chinese_variant, chinese_case, method)
+        match method {
+            ChineseCountMethod::Low => {
+                from_i128_to_chinese_low(chinese_variant, chinese_case, self)
+            },
+            ChineseCountMethod::TenThousand => {
+                Ok(from_i128_to_chinese_ten_thousand(chinese_variant, chinese_case, self))
+            },
+            ChineseCountMethod::Middle => {
+                Ok(from_i128_to_chinese_middle(chinese_variant, chinese_case, self))
+            },
+            ChineseCountMethod::High => {
+                Ok(from_i128_to_chinese_high(chinese_variant, chinese_case, self))
+            },
+        }
     }
 
     #[inline]
@@ -162,12 +1154,30 @@ impl NumberToChinese for i32 {
         self,
         chinese_variant: ChineseVariant,
         chinese_case: ChineseCase,
-    ) -> String {
-        from_i32_to_chinese_naive(chinese_variant, chinese_case, self)
+    ) -> Result<String, NumberToChineseError> {
+        Ok(from_i128_to_chinese_naive(chinese_variant, chinese_case, self))
+    }
+
+    #[inline]
+    fn write_chinese_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        write_signed_integer(f, options, self)
+    }
+
+    #[inline]
+    fn write_chinese_naive_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        Ok(write_signed_integer_naive(f, options, self)?)
     }
 }
 
-impl NumberToChinese for u64 {
+impl NumberToChinese for usize {
     #[inline]
     fn to_chinese(
         self,
@@ -183,12 +1193,30 @@ impl NumberToChinese for u64 {
         self,
         chinese_variant: ChineseVariant,
         chinese_case: ChineseCase,
-    ) -> String {
-        from_u64_to_chinese_naive(chinese_variant, chinese_case, self)
+    ) -> Result<String, NumberToChineseError> {
+        Ok(from_usize_to_chinese_naive(chinese_variant, chinese_case, self))
+    }
+
+    #[inline]
+    fn write_chinese_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        write_unsigned_integer(f, options, self as u128)
+    }
+
+    #[inline]
+    fn write_chinese_naive_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        Ok(write_unsigned_integer_naive(f, options, self as u128)?)
     }
 }
 
-impl NumberToChinese for i64 {
+impl NumberToChinese for isize {
     #[inline]
     fn to_chinese(
         self,
@@ -204,12 +1232,30 @@ impl NumberToChinese for i64 {
         self,
         chinese_variant: ChineseVariant,
         chinese_case: ChineseCase,
-    ) -> String {
-        from_i64_to_chinese_naive(chinese_variant, chinese_case, self)
+    ) -> Result<String, NumberToChineseError> {
+        Ok(from_isize_to_chinese_naive(chinese_variant, chinese_case, self))
+    }
+
+    #[inline]
+    fn write_chinese_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        write_signed_integer(f, options, self as i128)
+    }
+
+    #[inline]
+    fn write_chinese_naive_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        Ok(write_signed_integer_naive(f, options, self as i128)?)
     }
 }
 
-impl NumberToChinese for u128 {
+impl NumberToChinese for f32 {
     #[inline]
     fn to_chinese(
         self,
@@ -218,17 +1264,15 @@ impl NumberToChinese for u128 {
         method: ChineseCountMethod,
     ) -> Result<String, NumberToChineseError> {
         match method {
-            ChineseCountMethod::Low => {
-                from_u128_to_chinese_low(chinese_variant, chinese_case, self)
-            },
+            ChineseCountMethod::Low => from_f32_to_chinese_low(chinese_variant, chinese_case, self),
             ChineseCountMethod::TenThousand => {
-                Ok(from_u128_to_chinese_ten_thousand(chinese_variant, chinese_case, self))
+                from_f32_to_chinese_ten_thousand(chinese_variant, chinese_case, self)
             },
             ChineseCountMethod::Middle => {
-                Ok(from_u128_to_chinese_middle(chinese_variant, chinese_case, self))
+                from_f32_to_chinese_middle(chinese_variant, chinese_case, self)
             },
             ChineseCountMethod::High => {
-                Ok(from_u128_to_chinese_high(chinese_variant, chinese_case, self))
+                from_f32_to_chinese_high(chinese_variant, chinese_case, self)
             },
         }
     }
@@ -238,12 +1282,69 @@ impl NumberToChinese for u128 {
         self,
         chinese_variant: ChineseVariant,
         chinese_case: ChineseCase,
-    ) -> String {
-        from_u128_to_chinese_naive(chinese_variant, chinese_case, self)
+    ) -> Result<String, NumberToChineseError> {
+        from_f32_to_chinese_naive(chinese_variant, chinese_case, self)
+    }
+
+    #[inline]
+    fn to_chinese_decimal(
+        self,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+        method: ChineseCountMethod,
+    ) -> Result<String, NumberToChineseError> {
+        from_f32_to_chinese_decimal(chinese_variant, chinese_case, method, self)
+    }
+
+    #[inline]
+    fn to_chinese_with_fraction(
+        self,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+        method: ChineseCountMethod,
+        options: &ChineseFractionOptions,
+    ) -> Result<String, NumberToChineseError> {
+        from_f32_to_chinese_with_fraction(chinese_variant, chinese_case, method, self, options)
+    }
+
+    #[inline]
+    fn to_chinese_naive_with_fraction(
+        self,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+        options: &ChineseFractionOptions,
+    ) -> Result<String, NumberToChineseError> {
+        from_f32_to_chinese_naive_with_fraction(chinese_variant, chinese_case, self, options)
+    }
+
+    #[inline]
+    fn write_chinese_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        check_f64_range(
+            options.method(),
+            self as f64,
+            options.fraction_options().map_or(false, |options| options.non_finite()),
+        )?;
+
+        write_float(f, options, self)?;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn write_chinese_naive_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        write_float_naive(f, options, self)
     }
 }
 
-impl NumberToChinese for i128 {
+impl NumberToChinese for f64 {
     #[inline]
     fn to_chinese(
         self,
@@ -252,17 +1353,15 @@ impl NumberToChinese for i128 {
         method: ChineseCountMethod,
     ) -> Result<String, NumberToChineseError> {
         match method {
-            ChineseCountMethod::Low => {
-                from_i128_to_chinese_low(chinese_variant, chinese_case, self)
-            },
+            ChineseCountMethod::Low => from_f64_to_chinese_low(chinese_variant, chinese_case, self),
             ChineseCountMethod::TenThousand => {
-                Ok(from_i128_to_chinese_ten_thousand(chinese_variant, chinese_case, self))
+                from_f64_to_chinese_ten_thousand(chinese_variant, chinese_case, self)
             },
             ChineseCountMethod::Middle => {
-                Ok(from_i128_to_chinese_middle(chinese_variant, chinese_case, self))
+                from_f64_to_chinese_middle(chinese_variant, chinese_case, self)
             },
             ChineseCountMethod::High => {
-                Ok(from_i128_to_chinese_high(chinese_variant, chinese_case, self))
+                from_f64_to_chinese_high(chinese_variant, chinese_case, self)
             },
         }
     }
@@ -272,54 +1371,70 @@ impl NumberToChinese for i128 {
         self,
         chinese_variant: ChineseVariant,
         chinese_case: ChineseCase,
-    ) -> String {
-        from_i128_to_chinese_naive(chinese_variant, chinese_case, self)
+    ) -> Result<String, NumberToChineseError> {
+        from_f64_to_chinese_naive(chinese_variant, chinese_case, self)
     }
-}
 
-impl NumberToChinese for usize {
     #[inline]
-    fn to_chinese(
+    fn to_chinese_decimal(
         self,
         chinese_variant: ChineseVariant,
         chinese_case: ChineseCase,
         method: ChineseCountMethod,
     ) -> Result<String, NumberToChineseError> {
-        (self as u128).to_chinese(chinese_variant, chinese_case, method)
+        from_f64_to_chinese_decimal(chinese_variant, chinese_case, method, self)
     }
 
     #[inline]
-    fn to_chinese_naive(
+    fn to_chinese_with_fraction(
         self,
         chinese_variant: ChineseVariant,
         chinese_case: ChineseCase,
-    ) -> String {
-        from_usize_to_chinese_naive(chinese_variant, chinese_case, self)
+        method: ChineseCountMethod,
+        options: &ChineseFractionOptions,
+    ) -> Result<String, NumberToChineseError> {
+        from_f64_to_chinese_with_fraction(chinese_variant, chinese_case, method, self, options)
     }
-}
 
-impl NumberToChinese for isize {
     #[inline]
-    fn to_chinese(
+    fn to_chinese_naive_with_fraction(
         self,
         chinese_variant: ChineseVariant,
         chinese_case: ChineseCase,
-        method: ChineseCountMethod,
+        options: &ChineseFractionOptions,
     ) -> Result<String, NumberToChineseError> {
-        (self as i128).to_chinese(chinese_variant, chinese_case, method)
+        from_f64_to_chinese_naive_with_fraction(chinese_variant, chinese_case, self, options)
     }
 
     #[inline]
-    fn to_chinese_naive(
+    fn write_chinese_with<W: Write>(
         self,
-        chinese_variant: ChineseVariant,
-        chinese_case: ChineseCase,
-    ) -> String {
-        from_isize_to_chinese_naive(chinese_variant, chinese_case, self)
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        check_f64_range(
+            options.method(),
+            self,
+            options.fraction_options().map_or(false, |options| options.non_finite()),
+        )?;
+
+        write_float(f, options, self)?;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn write_chinese_naive_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        write_float_naive(f, options, self)
     }
 }
 
-impl NumberToChinese for f32 {
+#[cfg(feature = "bigint")]
+impl NumberToChinese for BigUint {
     #[inline]
     fn to_chinese(
         self,
@@ -327,7 +1442,20 @@ impl NumberToChinese for f32 {
         chinese_case: ChineseCase,
         method: ChineseCountMethod,
     ) -> Result<String, NumberToChineseError> {
-        (self as f64).to_chinese(chinese_variant, chinese_case, method)
+        match method {
+            ChineseCountMethod::Low => {
+                from_biguint_to_chinese_low(chinese_variant, chinese_case, self)
+            },
+            ChineseCountMethod::TenThousand => {
+                from_biguint_to_chinese_ten_thousand(chinese_variant, chinese_case, self)
+            },
+            ChineseCountMethod::Middle => {
+                from_biguint_to_chinese_middle(chinese_variant, chinese_case, self)
+            },
+            ChineseCountMethod::High => {
+                from_biguint_to_chinese_high(chinese_variant, chinese_case, self)
+            },
+        }
     }
 
     #[inline]
@@ -335,12 +1463,36 @@ impl NumberToChinese for f32 {
         self,
         chinese_variant: ChineseVariant,
         chinese_case: ChineseCase,
-    ) -> String {
-        from_f32_to_chinese_naive(chinese_variant, chinese_case, self)
+    ) -> Result<String, NumberToChineseError> {
+        Ok(from_biguint_to_chinese_naive(chinese_variant, chinese_case, self))
+    }
+
+    #[inline]
+    fn write_chinese_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        check_big_unsigned_integer_range(options.method(), &self)?;
+
+        write_sign(f, options, false, self.is_zero())?;
+        write_big_unsigned_integer(f, options, self)?;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn write_chinese_naive_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        Ok(write_big_unsigned_integer_naive(f, options, &self)?)
     }
 }
 
-impl NumberToChinese for f64 {
+#[cfg(feature = "bigint")]
+impl NumberToChinese for BigInt {
     #[inline]
     fn to_chinese(
         self,
@@ -349,15 +1501,17 @@ impl NumberToChinese for f64 {
         method: ChineseCountMethod,
     ) -> Result<String, NumberToChineseError> {
         match method {
-            ChineseCountMethod::Low => from_f64_to_chinese_low(chinese_variant, chinese_case, self),
+            ChineseCountMethod::Low => {
+                from_bigint_to_chinese_low(chinese_variant, chinese_case, self)
+            },
             ChineseCountMethod::TenThousand => {
-                from_f64_to_chinese_ten_thousand(chinese_variant, chinese_case, self)
+                from_bigint_to_chinese_ten_thousand(chinese_variant, chinese_case, self)
             },
             ChineseCountMethod::Middle => {
-                from_f64_to_chinese_middle(chinese_variant, chinese_case, self)
+                from_bigint_to_chinese_middle(chinese_variant, chinese_case, self)
             },
             ChineseCountMethod::High => {
-                Ok(from_f64_to_chinese_high(chinese_variant, chinese_case, self))
+                from_bigint_to_chinese_high(chinese_variant, chinese_case, self)
             },
         }
     }
@@ -367,7 +1521,93 @@ impl NumberToChinese for f64 {
         self,
         chinese_variant: ChineseVariant,
         chinese_case: ChineseCase,
-    ) -> String {
-        from_f64_to_chinese_naive(chinese_variant, chinese_case, self)
+    ) -> Result<String, NumberToChineseError> {
+        Ok(from_bigint_to_chinese_naive(chinese_variant, chinese_case, self))
+    }
+
+    #[inline]
+    fn write_chinese_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        write_big_signed_integer(f, options, self)
+    }
+
+    #[inline]
+    fn write_chinese_naive_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        Ok(write_big_signed_integer_naive(f, options, &self)?)
+    }
+}
+
+/// 將 `(分子, 分母)` 轉成中文的分數，分母在前，例如 **「三分之一」**。分子和分母的正負號會合併寫在最前面，例如 **「負五分之二」**。分母是 10 的次方時會省略開頭的 **「一」**，例如 **「百分之五」**。
+///
+/// ## 範例
+///
+/// ```rust
+/// use chinese_number::{ChineseFormatOptions, NumberToChinese};
+///
+/// let options = ChineseFormatOptions::new();
+///
+/// assert_eq!("三分之一", (1, 3).to_chinese_with(&options).unwrap());
+/// assert_eq!("負五分之二", (2, -5).to_chinese_with(&options).unwrap());
+/// assert_eq!("百分之五", (5u8, 100u8).to_chinese_with(&options).unwrap());
+/// ```
+impl<T: FractionInteger> NumberToChinese for (T, T) {
+    #[inline]
+    fn to_chinese(
+        self,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+        method: ChineseCountMethod,
+    ) -> Result<String, NumberToChineseError> {
+        self.to_chinese_with(&(chinese_variant, chinese_case, method).into())
+    }
+
+    #[inline]
+    fn to_chinese_naive(
+        self,
+        chinese_variant: ChineseVariant,
+        chinese_case: ChineseCase,
+    ) -> Result<String, NumberToChineseError> {
+        self.to_chinese_naive_with(
+            &ChineseFormatOptions::new().with_variant(chinese_variant).with_case(chinese_case),
+        )
+    }
+
+    #[inline]
+    fn write_chinese_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        let (negative, numerator, denominator) = fraction_to_sign_magnitude(self);
+
+        write_fraction(f, options, negative, numerator, denominator)
     }
+
+    #[inline]
+    fn write_chinese_naive_with<W: Write>(
+        self,
+        f: &mut W,
+        options: &ChineseFormatOptions,
+    ) -> Result<(), NumberToChineseError> {
+        let (negative, numerator, denominator) = fraction_to_sign_magnitude(self);
+
+        write_fraction_naive(f, options, negative, numerator, denominator)
+    }
+}
+
+#[inline]
+fn fraction_to_sign_magnitude<T: FractionInteger>(
+    (numerator, denominator): (T, T),
+) -> (bool, u128, u128) {
+    let (numerator_negative, numerator) = numerator.into_sign_magnitude();
+    let (denominator_negative, denominator) = denominator.into_sign_magnitude();
+
+    (numerator_negative != denominator_negative, numerator, denominator)
 }
@@ -0,0 +1,222 @@
+#![cfg(all(feature = "bigint", feature = "chinese-to-number", feature = "number-to-chinese"))]
+
+use chinese_number::{
+    ChineseCase, ChineseCountMethod, ChineseFormatOptions, ChineseToNumber, ChineseToNumberError,
+    ChineseVariant, NumberToChinese, NumberToChineseError,
+};
+use num_bigint::{BigInt, BigUint};
+
+#[test]
+fn biguint_to_chinese() {
+    macro_rules! test {
+        ($expect:expr, $value:expr, $method:expr) => {
+            assert_eq!(
+                $expect,
+                $value
+                    .to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, $method)
+                    .unwrap()
+            );
+        };
+    }
+
+    test!("零", BigUint::from(0u8), ChineseCountMethod::TenThousand);
+    test!("一百二十三", BigUint::from(123u8), ChineseCountMethod::TenThousand);
+    test!("一極", BigUint::from(10u8).pow(48), ChineseCountMethod::TenThousand);
+    test!("一萬極", BigUint::from(10u8).pow(92), ChineseCountMethod::Middle);
+    test!("一恆河沙", BigUint::from(10u8).pow(52), ChineseCountMethod::TenThousand);
+    test!(
+        "一那由他零一阿僧祇",
+        BigUint::from(10u8).pow(60) + BigUint::from(10u8).pow(56),
+        ChineseCountMethod::TenThousand
+    );
+    test!("一不可思議", BigUint::from(10u8).pow(64), ChineseCountMethod::TenThousand);
+    test!("一無量大數", BigUint::from(10u8).pow(68), ChineseCountMethod::TenThousand);
+    test!("一恆河沙", BigUint::from(10u8).pow(96), ChineseCountMethod::Middle);
+    test!("一萬無量大數", BigUint::from(10u8).pow(132), ChineseCountMethod::Middle);
+    test!("一極零一", BigUint::from(10u8).pow(8192) + 1u8, ChineseCountMethod::High);
+    test!("一垓零二萬三千", BigUint::from(100023000u32), ChineseCountMethod::Low);
+
+    assert_eq!(
+        "一二三四五六七八九零一二三四五六七八九零一二三四五六七八九零一二三四五六七八九零",
+        "1234567890123456789012345678901234567890"
+            .parse::<BigUint>()
+            .unwrap()
+            .to_chinese_naive(ChineseVariant::Traditional, ChineseCase::Lower)
+            .unwrap()
+    );
+
+    assert_eq!(
+        Err(NumberToChineseError::Overflow),
+        BigUint::from(10u8).pow(16).to_chinese(
+            ChineseVariant::Traditional,
+            ChineseCase::Lower,
+            ChineseCountMethod::Low
+        )
+    );
+    assert_eq!(
+        Err(NumberToChineseError::Overflow),
+        BigUint::from(10u8).pow(72).to_chinese(
+            ChineseVariant::Traditional,
+            ChineseCase::Lower,
+            ChineseCountMethod::TenThousand
+        )
+    );
+    assert_eq!(
+        Err(NumberToChineseError::Overflow),
+        BigUint::from(10u8).pow(136).to_chinese(
+            ChineseVariant::Traditional,
+            ChineseCase::Lower,
+            ChineseCountMethod::Middle
+        )
+    );
+    assert_eq!(
+        Err(NumberToChineseError::Overflow),
+        BigUint::from(10u8).pow(16384).to_chinese(
+            ChineseVariant::Traditional,
+            ChineseCase::Lower,
+            ChineseCountMethod::High
+        )
+    );
+}
+
+#[test]
+fn bigint_to_chinese() {
+    assert_eq!(
+        "負一極",
+        "-1000000000000000000000000000000000000000000000000"
+            .parse::<BigInt>()
+            .unwrap()
+            .to_chinese(
+                ChineseVariant::Traditional,
+                ChineseCase::Lower,
+                ChineseCountMethod::TenThousand
+            )
+            .unwrap()
+    );
+    assert_eq!(
+        "負一二三",
+        BigInt::from(-123)
+            .to_chinese_naive(ChineseVariant::Traditional, ChineseCase::Lower)
+            .unwrap()
+    );
+    assert_eq!(
+        Err(NumberToChineseError::Underflow),
+        (-BigInt::from(10u8).pow(72)).to_chinese(
+            ChineseVariant::Traditional,
+            ChineseCase::Lower,
+            ChineseCountMethod::TenThousand
+        )
+    );
+}
+
+#[test]
+fn chinese_to_big_integer() {
+    assert_eq!(
+        "1000000000000000000000000000000000000000000000000".parse::<BigUint>().unwrap(),
+        "一極".to_number(ChineseCountMethod::TenThousand).unwrap()
+    );
+    assert_eq!(
+        BigUint::from(10u8).pow(92),
+        "一萬極".to_number(ChineseCountMethod::Middle).unwrap()
+    );
+    assert_eq!(
+        BigUint::from(10u8).pow(8192) * 2u8 + 3u8,
+        "二極零三".to_number(ChineseCountMethod::High).unwrap()
+    );
+    assert_eq!(
+        BigUint::from(10u8).pow(60) * 3u8 + BigUint::from(10u8).pow(52) * 5u8,
+        "三那由他零五恆河沙".to_number(ChineseCountMethod::TenThousand).unwrap()
+    );
+    assert_eq!(
+        BigUint::from(10u8).pow(67) * 15u8,
+        "一无量大数五".to_number(ChineseCountMethod::TenThousand).unwrap()
+    );
+    assert_eq!(
+        BigUint::from(10u8).pow(132) + BigUint::from(10u8).pow(120) * 2u8,
+        "一萬無量大數零二不可思議".to_number(ChineseCountMethod::Middle).unwrap()
+    );
+    assert_eq!(
+        Err(ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 3
+        }),
+        ChineseToNumber::<BigUint>::to_number(&"一恆河沙", ChineseCountMethod::Low)
+    );
+    assert_eq!(
+        Err(ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 0
+        }),
+        ChineseToNumber::<BigUint>::to_number(&"阿僧祇", ChineseCountMethod::TenThousand)
+    );
+    assert_eq!(
+        "-1234567890123456789012345678901234567890".parse::<BigInt>().unwrap(),
+        "負一二三四五六七八九零一二三四五六七八九零一二三四五六七八九零一二三四五六七八九零"
+            .to_number_naive()
+            .unwrap()
+    );
+    assert_eq!(
+        BigInt::from(-30303),
+        "負三萬零三百零三".to_number(ChineseCountMethod::TenThousand).unwrap()
+    );
+    assert_eq!(
+        Err(ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 1
+        }),
+        ChineseToNumber::<BigInt>::to_number(&"負負一", ChineseCountMethod::TenThousand)
+    );
+}
+
+#[test]
+fn round_trip() {
+    use ChineseCountMethod::{High, Low, Middle, TenThousand};
+
+    for (n, methods) in [
+        (BigUint::from(0u8), &[Low, TenThousand, Middle, High][..]),
+        (BigUint::from(u128::MAX), &[TenThousand, Middle, High]),
+        (BigUint::from(u128::MAX) * BigUint::from(u128::MAX), &[Middle, High]),
+        (BigUint::from(10u8).pow(40) + 1u8, &[TenThousand, Middle, High]),
+    ] {
+        for method in ChineseCountMethod::variants() {
+            let result =
+                n.clone().to_chinese(ChineseVariant::Traditional, ChineseCase::Upper, method);
+
+            if methods.contains(&method) {
+                let s = result.unwrap();
+
+                assert_eq!(n, s.to_number(method).unwrap(), "{}", s);
+            } else {
+                assert_eq!(Err(NumberToChineseError::Overflow), result, "{:?}", method);
+            }
+        }
+    }
+}
+
+#[test]
+fn colloquial() {
+    let options =
+        ChineseFormatOptions::new().with_method(ChineseCountMethod::High).with_colloquial(true);
+
+    let n = BigUint::from(10u8).pow(64) + BigUint::from(5u8) * BigUint::from(10u8).pow(63);
+
+    let s = n.clone().to_chinese_with(&options).unwrap();
+
+    assert_eq!("一垓五", s);
+    assert_eq!(n, s.to_number(ChineseCountMethod::High).unwrap());
+
+    assert_eq!(
+        "負三萬四",
+        BigInt::from(-3_4000)
+            .to_chinese_with(&options.with_method(ChineseCountMethod::TenThousand))
+            .unwrap()
+    );
+}
+
+#[test]
+fn mixed() {
+    let options = ChineseFormatOptions::new().with_method(ChineseCountMethod::High);
+
+    let value = BigUint::from(10u8).pow(64) * 3u8 + 5u8;
+
+    assert_eq!("3垓0005", value.clone().to_chinese_mixed_with(&options, None).unwrap());
+    assert_eq!("3垓", value.to_chinese_mixed_with(&options, Some(3)).unwrap());
+    assert_eq!("-1萬0005", BigInt::from(-1_0005).to_chinese_mixed_with(&options, None).unwrap());
+}
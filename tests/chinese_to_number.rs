@@ -114,6 +114,8 @@ fn to_number_low() {
     test_float!(-123.46f32, "負壹佰貳拾參肆角陸分");
     test_float!(123.46f64, "壹佰貳拾參肆角陸分");
     test_float!(-123.46f64, "負壹佰貳拾參肆角陸分");
+    test_float!(123.456f64, "壹佰貳拾參點肆伍陸");
+    test_float!(-123.456f64, "負壹佰貳拾參點肆伍陸");
 
     test_err!(ChineseToNumberError::ChineseNumberEmpty, "");
 
@@ -158,6 +160,45 @@ fn to_number_ten_thousand() {
     test_float!(-123.46f32, "負壹佰貳拾參肆角陸分");
     test_float!(123.46f64, "壹佰貳拾參肆角陸分");
     test_float!(-123.46f64, "負壹佰貳拾參肆角陸分");
+    test_float!(123.456f64, "壹佰貳拾參點肆伍陸");
+    test_float!(-123.456f64, "負壹佰貳拾參點肆伍陸");
+    test_float!(3.5f64, "三圓五毫");
+    test_float!(3.52f64, "三圓五毫二仙");
+    test_float!(-3.5f64, "負三圓五毫");
+    test_float!(0.0005f64, "五毫");
+    test_float!(0.0001f64, "一毫");
+    test_float!(3.0001f64, "三一毫");
+    test_float!(0.5001f64, "五角一毫");
+    test_float!(3.5f64, "三元五角");
+    test_float!(3.05f64, "三元零五分");
+    test_float!(3f64, "三元");
+    test_float!(120.5f64, "壹佰貳拾圓伍毫");
+    test_float!(0.003f64, "三厘");
+    test_float!(0.053f64, "五分三厘");
+    test_float!(0.0532f64, "五分三厘二毫");
+    test_float!(1.0532f64, "一元五分三厘二毫");
+    test_float!(0.3532f64, "三角五分三厘二毫");
+    test_float!(0.00051f64, "五毫一絲");
+    test_float!(0.00051f64, "五毫一丝");
+    test_float!(0.0000001234f64, "一微二纖三沙四塵");
+    test_float!(0.0000001234f64, "一微二纤三沙四尘");
+    test_float!(1e52f64, "一恆河沙");
+    test_float!(3e52f64, "三恒河沙");
+    test_float!(2e68f64 + 5e56, "二無量大數零五阿僧祇");
+    test_err!(
+        f64,
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 1
+        },
+        "三厘五分"
+    );
+    test_err!(
+        u32,
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 1
+        },
+        "三厘"
+    );
 
     test_err!(ChineseToNumberError::ChineseNumberEmpty, "");
 
@@ -338,3 +379,623 @@ fn to_number_naive() {
     test_err!(i8, ChineseToNumberError::Overflow, "壹貳捌");
     test_err!(i8, ChineseToNumberError::Underflow, "負壹貳玖");
 }
+
+#[test]
+fn to_number_naive_grouped() {
+    test_group_naive!();
+
+    test!(2024u16, "二〇二四");
+    test!(12_3456_7890u64, "一二，三四五六，七八九〇");
+    test!(123_456_789u32, "一二三,四五六,七八九");
+    test!(-1234_5678i32, "負一二三四 五六七八");
+    test!(1234u16, "一，二三四");
+    test_float!(1234_5678.25f64, "一二三四，五六七八點二五");
+
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 0
+        },
+        "，一二三"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 3
+        },
+        "一二，，三四"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 2
+        },
+        "一二，三"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 3
+        },
+        "一二三，四五"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 8
+        },
+        "一，二三四，五六，七八九"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 3
+        },
+        "一二三，"
+    );
+}
+
+#[test]
+fn to_number_ordinal() {
+    assert_eq!(123u8, "第一百二十三".to_number_ordinal(ChineseCountMethod::TenThousand).unwrap());
+    assert_eq!(20i32, "第二十".to_number_ordinal(ChineseCountMethod::TenThousand).unwrap());
+    assert_eq!(
+        1_0001u64,
+        " 第壹萬零壹".to_number_ordinal(ChineseCountMethod::TenThousand).unwrap()
+    );
+    assert_eq!(15_0000u32, "第一億五".to_number_ordinal(ChineseCountMethod::Low).unwrap());
+
+    assert_eq!(
+        Err::<u8, _>(ChineseToNumberError::ChineseNumberEmpty),
+        "".to_number_ordinal(ChineseCountMethod::TenThousand)
+    );
+    assert_eq!(
+        Err::<u8, _>(ChineseToNumberError::ChineseNumberEmpty),
+        "第".to_number_ordinal(ChineseCountMethod::TenThousand)
+    );
+    assert_eq!(
+        Err::<u8, _>(ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 0
+        }),
+        "一百".to_number_ordinal(ChineseCountMethod::TenThousand)
+    );
+    assert_eq!(
+        Err::<u8, _>(ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 3
+        }),
+        "第一百名".to_number_ordinal(ChineseCountMethod::TenThousand)
+    );
+    assert_eq!(
+        Err::<i32, _>(ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 1
+        }),
+        "第負三".to_number_ordinal(ChineseCountMethod::TenThousand)
+    );
+    assert_eq!(
+        Err::<i32, _>(ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 1
+        }),
+        "第零下三".to_number_ordinal(ChineseCountMethod::TenThousand)
+    );
+    assert_eq!(
+        Err::<u8, _>(ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 1
+        }),
+        "第零".to_number_ordinal(ChineseCountMethod::TenThousand)
+    );
+    assert_eq!(
+        Err::<u8, _>(ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 1
+        }),
+        "第〇".to_number_ordinal(ChineseCountMethod::TenThousand)
+    );
+    assert_eq!(
+        Err::<f64, _>(ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 2
+        }),
+        "第三點五".to_number_ordinal(ChineseCountMethod::TenThousand)
+    );
+    assert_eq!(
+        Err::<f64, _>(ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 2
+        }),
+        "第三角".to_number_ordinal(ChineseCountMethod::TenThousand)
+    );
+}
+
+#[test]
+fn to_number_fraction() {
+    macro_rules! test {
+        ($expect:expr, $value:expr) => {
+            assert_eq!($expect, $value.to_number(ChineseCountMethod::TenThousand).unwrap());
+        };
+    }
+
+    test!((1u8, 3u8), "三分之一");
+    test!((-2i32, 5i32), "負五分之二");
+    test!((5u16, 100u16), "一百分之五");
+    test!((5u16, 100u16), "百分之五");
+    test!((3u16, 1000u16), "千分之三");
+    test!((1u32, 1_0000u32), "萬分之一");
+    test!((1u32, 100_0000u32), "百萬分之一");
+    test!((3u16, 1000u16), "仟分之參");
+    test!((1u8, 3u8), "三分の一");
+    test!((5u16, 100u16), "百分の五");
+    test!((8i64, 10i64), "十之八");
+    test!((1234u32, 1_0000u32), "一萬分之一千二百三十四");
+    test!((1u8, 3u8), "參分之壹");
+    test!((0i8, 3i8), "負三分之零");
+    test!((i8::MIN, 2i8), "負二分之一百二十八");
+
+    assert_eq!((-12i32, 100i32), "負一〇〇分之一二".to_number_naive().unwrap());
+    assert_eq!((1u8, 3u8), "三分之一".to_number_naive().unwrap());
+    assert_eq!(
+        (1u64, 1_0000_0000_0000u64),
+        "萬億分之一".to_number(ChineseCountMethod::Middle).unwrap()
+    );
+
+    macro_rules! test_err {
+        ($typ:ty, $expect:expr, $value:expr) => {
+            assert_eq!(
+                Err::<($typ, $typ), _>($expect),
+                $value.to_number(ChineseCountMethod::TenThousand)
+            );
+        };
+    }
+
+    test_err!(u8, ChineseToNumberError::ChineseNumberEmpty, "");
+    test_err!(
+        u8,
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 0
+        },
+        "三"
+    );
+    test_err!(
+        u8,
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 0
+        },
+        "分之一"
+    );
+    test_err!(
+        u8,
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 2
+        },
+        "三分之"
+    );
+    test_err!(
+        u8,
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 4
+        },
+        "三分之一a"
+    );
+    test_err!(
+        u8,
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 0
+        },
+        "零分之一"
+    );
+    test_err!(
+        i8,
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 1
+        },
+        "負零分之一"
+    );
+    test_err!(
+        u8,
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 2
+        },
+        "負百a分之一"
+    );
+    test_err!(u8, ChineseToNumberError::Underflow, "負三分之一");
+    test_err!(i8, ChineseToNumberError::Underflow, "負三分之一百二十九");
+    test_err!(i8, ChineseToNumberError::Overflow, "一百二十八分之一");
+}
+
+#[test]
+fn to_number_percent() {
+    macro_rules! test {
+        ($expect:expr, $value:expr) => {
+            assert_eq_float!(
+                $expect,
+                $value.to_number_percent(ChineseCountMethod::TenThousand).unwrap()
+            );
+        };
+    }
+
+    test!(0.153f64, "百分之十五點三");
+    test!(1f64, "百分之一百");
+    test!(0f64, "百分之零");
+    test!(-0.5f64, "負百分之五十");
+    test!(0.005f64, "千分之五");
+    test!(0.005f64, "仟分之伍");
+    test!(0.0003f64, "萬分之三");
+    test!(0.0003f32, "万分之三");
+    test!(0.153f64, "佰分之拾伍點參");
+    test!(0.1535f64, "百分之十五點三五");
+    test!(0.153f64, "百分の十五点三");
+
+    macro_rules! test_err {
+        ($expect:expr, $value:expr) => {
+            assert_eq!(
+                Err::<f64, _>($expect),
+                $value.to_number_percent(ChineseCountMethod::TenThousand)
+            );
+        };
+    }
+
+    test_err!(ChineseToNumberError::ChineseNumberEmpty, "");
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 0
+        },
+        "十五"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 0
+        },
+        "十分之五"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 1
+        },
+        "負百之五"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 2
+        },
+        "百分之"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 3
+        },
+        "百分之負五"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 4
+        },
+        "百分之五點"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 5
+        },
+        "百分之十五角"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 4
+        },
+        "百分之三元"
+    );
+}
+
+#[test]
+fn to_number_grouped() {
+    macro_rules! test {
+        ($expect:expr, $value:expr) => {
+            test!($expect, $value, ChineseCountMethod::TenThousand);
+        };
+        ($expect:expr, $value:expr, $method:expr) => {
+            assert_eq!($expect, $value.to_number_grouped($method).unwrap());
+        };
+    }
+
+    test!(0u8, "0");
+    test!(0u8, "-0");
+    test!(1234u16, "1234");
+    test!(12345u32, "1,2345");
+    test!(1234567890u64, "12,3456,7890");
+    test!(1234567890u64, "12，3456，7890");
+    test!(1234567890u64, "12 3456 7890");
+    test!(1234567890u64, " 12,3456,7890 ");
+    test!(-12345i32, "-1,2345");
+    test!(12345i32, "+1,2345");
+    test!(-12345.5f64, "-1,2345.5");
+    test!(0.25f64, "0.25");
+    test!(-0.25f64, "-0.25");
+    test!(12345u32, "1,2345", ChineseCountMethod::Low);
+    test!(1234567u32, "1,2,3,4567", ChineseCountMethod::Low);
+    test!(1234567890u64, "12,34567890", ChineseCountMethod::Middle);
+    test!(12345678901234567u64, "1,2345678901234567", ChineseCountMethod::High);
+
+    macro_rules! test_err {
+        ($expect:expr, $value:expr) => {
+            assert_eq!(
+                Err::<u32, _>($expect),
+                $value.to_number_grouped(ChineseCountMethod::TenThousand)
+            );
+        };
+    }
+
+    test_err!(ChineseToNumberError::ChineseNumberEmpty, "");
+    test_err!(ChineseToNumberError::Overflow, "42,9496,7296");
+    test_err!(ChineseToNumberError::Underflow, "-1");
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 0
+        },
+        "-"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 0
+        },
+        "12345"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 2
+        },
+        "12,345"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 0
+        },
+        ",2345"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 2
+        },
+        "12，3456,7890"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 1
+        },
+        "1a"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 0
+        },
+        "0012,3456"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 1
+        },
+        "-01"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 0
+        },
+        "0,0000"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 6
+        },
+        "1,2345.5"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 1
+        },
+        "1."
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 3
+        },
+        "1.5,0"
+    );
+}
+
+#[test]
+fn to_number_currency() {
+    macro_rules! test {
+        ($expect:expr, $value:expr) => {
+            assert_eq!($expect, $value.to_number_currency().unwrap());
+        };
+    }
+
+    test!(0u8, "人民幣零元整");
+    test!(5u8, "人民幣伍分");
+    test!(50u8, "人民幣伍角整");
+    test!(53u8, "伍角參分");
+    test!(1000u16, "人民幣壹拾元整");
+    test!(1_020_000u32, "人民幣壹萬零貳佰元整");
+    test!(168_032u32, "人民幣壹仟陸佰捌拾元零參角貳分");
+    test!(168_032u32, "人民幣壹仟陸佰捌拾元參角貳分");
+    test!(1_640_902u32, "人民币壹万陆仟肆佰零玖元零贰分");
+    test!(10_000u32, "人民幣壹佰圓正");
+    test!(10_000u32, "人民币壹佰圆整");
+    test!(10_000u32, "壹佰元");
+    test!(1_230_000i64, "新臺幣壹萬貳仟參佰元整");
+    test!(1_230_000i64, "新台幣壹萬貳仟參佰元整");
+    test!(1_230_000i64, "新台币壹万贰仟叁佰元整");
+    test!(1_230_000i64, "新臺幣 壹萬貳仟參佰元整");
+    test!(12_300u64, "一百二十三元整");
+    test!(12_050u32, "港幣壹佰貳拾圓伍毫");
+    test!(10_000u32, "港幣壹佰圓正");
+    test!(12_052u32, "港币壹佰贰拾圆伍毫贰仙");
+    test!(10_005u32, "港幣壹佰圓零伍仙");
+
+    macro_rules! test_err {
+        ($expect:expr, $value:expr) => {
+            assert_eq!(Err::<u32, _>($expect), $value.to_number_currency());
+        };
+    }
+
+    test_err!(ChineseToNumberError::ChineseNumberEmpty, "");
+    test_err!(ChineseToNumberError::Overflow, "人民幣肆仟參佰萬元整");
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 2
+        },
+        "人民幣"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 3
+        },
+        "人民幣整"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 3
+        },
+        "人民幣元整"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 3
+        },
+        "人民幣伍"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 6
+        },
+        "人民幣壹佰元零"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 8
+        },
+        "人民幣壹佰元壹分伍角"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 6
+        },
+        "人民幣壹佰元拾角"
+    );
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 1
+        },
+        "美金壹佰元整"
+    );
+}
+
+#[test]
+fn to_number_sign() {
+    test_group!(ChineseCountMethod::TenThousand);
+
+    test!(-5i32, "負五");
+    test!(-5i32, "负五");
+    test!(-5i32, "減五");
+    test!(-5i32, "减五");
+    test!(-5i32, "零下五");
+    test!(-5i32, "−五");
+    test!(-5i32, "-五");
+    test!(3i32, "正三");
+    test!(3i32, "+三");
+    test_float!(-5.5f64, "零下五點五");
+    test_float!(-0.3f64, "減三角");
+    test_float!(3.5f64, "+三點五");
+
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 1
+        },
+        "零上五"
+    );
+    test_err!(i32, ChineseToNumberError::ChineseNumberEmpty, "零下");
+    test_err!(i8, ChineseToNumberError::Underflow, "零下一百二十九");
+}
+
+#[test]
+fn to_number_ancient_glyph() {
+    test_group!(ChineseCountMethod::TenThousand);
+
+    test!(123u32, "弌陌弍什弎");
+    test!(3456_0000u32, "弎阡肆陌伍什陸萬");
+    test!(11u8, "什弌");
+    test!(22u8, "貮拾貮");
+    test!(3021u16, "叄仟零弍什弌");
+    test!(-2i8, "負弍");
+    test_float!(2.3f64, "弍點弎");
+
+    test_err!(
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 1
+        },
+        "弌陌弍陌"
+    );
+
+    assert_eq!(1203u16, "弌弍〇弎".to_number_naive().unwrap());
+    assert_eq!(12030u32, "人民幣弌陌弍什元零弎角整".to_number_currency().unwrap());
+}
+
+#[test]
+fn to_number_japanese() {
+    macro_rules! test {
+        ($expect:expr, $value:expr) => {
+            assert_eq!($expect, $value.to_number_japanese().unwrap());
+        };
+    }
+
+    macro_rules! test_err {
+        ($typ:ty, $expect:expr, $value:expr) => {
+            assert_eq!(Err::<$typ, _>($expect), $value.to_number_japanese());
+        };
+    }
+
+    test!(0u8, "〇");
+    test!(0u8, "零");
+    test!(10u8, "十");
+    test!(110u8, "百十");
+    test!(1001u16, "千一");
+    test!(1234u16, "千二百三十四");
+    test!(1234u16, "壱千弐百参拾四");
+    test!(1_0001u32, "一万一");
+    test!(1_0001u32, "壱萬壱");
+    test!(10_0000u32, "十万");
+    test!(1000_0000u32, "千万");
+    test!(1203_4005u32, "千二百三万四千五");
+    test!(2_3000_0005u32, "二億三千万五");
+    test!(12345678901234567890123456u128, "十二𥝱三千四百五十六垓七千八百九十京千二百三十四兆五千六百七十八億九千十二万三千四百五十六");
+    test!(3_0000_0000_0000_0000_0000_0000_0000u128, "三穣");
+    test!(-20i8, "負二十");
+
+    test_err!(u32, ChineseToNumberError::ChineseNumberEmpty, "");
+    test_err!(
+        u32,
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 0
+        },
+        "万"
+    );
+    test_err!(
+        u32,
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 0
+        },
+        "一二"
+    );
+    test_err!(
+        u32,
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 0
+        },
+        "千千"
+    );
+    test_err!(
+        u32,
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 1
+        },
+        "一〇一"
+    );
+    test_err!(
+        u64,
+        ChineseToNumberError::ChineseNumberIncorrect {
+            char_index: 2
+        },
+        "一億万"
+    );
+    test_err!(i8, ChineseToNumberError::Overflow, "百二十八");
+    test_err!(i8, ChineseToNumberError::Underflow, "負百二十九");
+    test_err!(u128, ChineseToNumberError::Overflow, "一無量大数");
+}
@@ -1,7 +1,12 @@
 #![cfg(feature = "number-to-chinese")]
 
 use chinese_number::{
-    ChineseCase, ChineseCountMethod, ChineseVariant, NumberToChinese, NumberToChineseError,
+    ChineseApproximateOptions, ChineseCase, ChineseCountMethod, ChineseCurrencyOptions,
+    ChineseDigitSeparator, ChineseFormatOptions, ChineseFractionOptions, ChineseFractionStyle,
+    ChineseGlyphStyle, ChineseNegativeStyle, ChinesePercent, ChinesePositiveStyle, ChineseRounding,
+    ChineseScript, ChineseSignStyle, ChineseTaiStyle, ChineseTenStyle, ChineseTwoStyle,
+    ChineseVariant, ChineseWholeStyle, ChineseYuanStyle, ChineseZeroStyle, NumberToChinese,
+    NumberToChineseError,
 };
 
 macro_rules! test_group {
@@ -31,7 +36,10 @@ macro_rules! test_group_naive {
     ($case:expr) => {
         macro_rules! test {
             ($expect: expr,$value: expr) => {
-                assert_eq!($expect, $value.to_chinese_naive(ChineseVariant::Traditional, $case));
+                assert_eq!(
+                    $expect,
+                    $value.to_chinese_naive(ChineseVariant::Traditional, $case).unwrap()
+                );
             };
         }
     };
@@ -99,10 +107,14 @@ fn to_uppercase_ten_thousand() {
     test!("壹佰貳拾參肆角陸分", 123.456f64);
     test!("負壹佰貳拾參肆角陸分", -123.456f64);
 
-    test!("參佰肆拾澗貳仟捌佰貳拾參溝肆仟陸佰陸拾參穰捌仟伍佰貳拾捌秭捌仟伍佰玖拾捌垓壹仟壹佰柒拾京肆仟壹佰捌拾參兆肆仟捌佰肆拾伍億壹仟陸佰玖拾貳萬伍仟肆佰肆拾",f32::MAX);
-    test!("負參佰肆拾澗貳仟捌佰貳拾參溝肆仟陸佰陸拾參穰捌仟伍佰貳拾捌秭捌仟伍佰玖拾捌垓壹仟壹佰柒拾京肆仟壹佰捌拾參兆肆仟捌佰肆拾伍億壹仟陸佰玖拾貳萬伍仟肆佰肆拾",f32::MIN);
-    test!("玖仟玖佰玖拾玖極玖仟玖佰玖拾玖載玖仟玖佰玖拾玖正玖仟玖佰捌拾玖澗貳仟玖佰捌拾參溝捌仟伍佰伍拾貳穰零肆佰陸拾肆秭貳仟捌佰玖拾貳垓玖仟陸佰伍拾參京肆仟壹佰陸拾兆陸仟零貳拾壹億柒仟陸佰陸拾捌萬肆仟零參拾貳",1e52f64 - 1e37);
-    test!("負玖仟玖佰玖拾玖極玖仟玖佰玖拾玖載玖仟玖佰玖拾玖正玖仟玖佰捌拾玖澗貳仟玖佰捌拾參溝捌仟伍佰伍拾貳穰零肆佰陸拾肆秭貳仟捌佰玖拾貳垓玖仟陸佰伍拾參京肆仟壹佰陸拾兆陸仟零貳拾壹億柒仟陸佰陸拾捌萬肆仟零參拾貳",-1e52f64 + 1e37);
+    test!("參佰肆拾澗貳仟捌佰貳拾參溝伍仟穰", f32::MAX);
+    test!("負參佰肆拾澗貳仟捌佰貳拾參溝伍仟穰", f32::MIN);
+    test!("玖仟玖佰玖拾玖極玖仟玖佰玖拾玖載玖仟玖佰玖拾玖正玖仟玖佰捌拾玖澗", 1e52f64 - 1e37);
+    test!("負玖仟玖佰玖拾玖極玖仟玖佰玖拾玖載玖仟玖佰玖拾玖正玖仟玖佰捌拾玖澗", -1e52f64 + 1e37);
+    test!("壹恆河沙", 1e52f64);
+    test!("負壹無量大數", -1e68f64);
+
+    test_err!(NumberToChineseError::Overflow, 1e72f64);
 
     test_err!(NumberToChineseError::Overflow, f64::MAX);
     test_err!(NumberToChineseError::Underflow, f64::MIN);
@@ -133,10 +145,15 @@ fn to_uppercase_middle() {
     test!("壹佰貳拾參肆角陸分", 123.456f64);
     test!("負壹佰貳拾參肆角陸分", -123.456f64);
 
-    test!("參佰肆拾萬貳仟捌佰貳拾參垓肆仟陸佰陸拾參萬捌仟伍佰貳拾捌京捌仟伍佰玖拾捌萬壹仟壹佰柒拾兆肆仟壹佰捌拾參萬肆仟捌佰肆拾伍億壹仟陸佰玖拾貳萬伍仟肆佰肆拾",f32::MAX);
-    test!("負參佰肆拾萬貳仟捌佰貳拾參垓肆仟陸佰陸拾參萬捌仟伍佰貳拾捌京捌仟伍佰玖拾捌萬壹仟壹佰柒拾兆肆仟壹佰捌拾參萬肆仟捌佰肆拾伍億壹仟陸佰玖拾貳萬伍仟肆佰肆拾",f32::MIN);
-    test!("玖仟玖佰玖拾玖萬玖仟玖佰玖拾玖極玖仟玖佰玖拾玖萬玖仟玖佰玖拾壹載零壹佰貳拾玖萬貳仟捌佰伍拾捌正玖仟參佰玖拾捌萬壹仟肆佰陸拾貳澗零壹佰零柒萬壹仟壹佰陸拾柒溝伍仟玖佰貳拾玖萬肆仟貳佰陸拾柒穰壹仟貳佰壹拾萬壹仟柒佰伍拾捌秭柒仟壹佰柒拾捌萬伍仟肆佰玖拾陸垓捌仟肆佰肆拾捌萬柒仟柒佰捌拾柒京捌仟陸佰貳拾柒萬柒仟柒佰陸拾貳兆貳仟伍佰陸拾萬壹仟零壹拾億零伍佰萬零柒佰零肆",1e96f64 - 1e81);
-    test!("負玖仟玖佰玖拾玖萬玖仟玖佰玖拾玖極玖仟玖佰玖拾玖萬玖仟玖佰玖拾壹載零壹佰貳拾玖萬貳仟捌佰伍拾捌正玖仟參佰玖拾捌萬壹仟肆佰陸拾貳澗零壹佰零柒萬壹仟壹佰陸拾柒溝伍仟玖佰貳拾玖萬肆仟貳佰陸拾柒穰壹仟貳佰壹拾萬壹仟柒佰伍拾捌秭柒仟壹佰柒拾捌萬伍仟肆佰玖拾陸垓捌仟肆佰肆拾捌萬柒仟柒佰捌拾柒京捌仟陸佰貳拾柒萬柒仟柒佰陸拾貳兆貳仟伍佰陸拾萬壹仟零壹拾億零伍佰萬零柒佰零肆",-1e96f64 + 1e81);
+    test!("參佰肆拾萬貳仟捌佰貳拾參垓伍仟萬京", f32::MAX);
+    test!("負參佰肆拾萬貳仟捌佰貳拾參垓伍仟萬京", f32::MIN);
+    test!("玖仟玖佰玖拾玖萬玖仟玖佰玖拾玖極玖仟玖佰玖拾玖萬玖仟玖佰玖拾壹載", 1e96f64 - 1e81);
+    test!("負玖仟玖佰玖拾玖萬玖仟玖佰玖拾玖極玖仟玖佰玖拾玖萬玖仟玖佰玖拾壹載", -1e96f64 + 1e81);
+    test!("壹恆河沙", 1e96f64);
+    test!("壹萬阿僧祇", 1e108f64);
+    test!("負壹萬無量大數", -1e132f64);
+
+    test_err!(NumberToChineseError::Overflow, 1e136f64);
 
     test_err!(NumberToChineseError::Overflow, f64::MAX);
     test_err!(NumberToChineseError::Underflow, f64::MIN);
@@ -167,10 +184,10 @@ fn to_uppercase_high() {
     test!("壹佰貳拾參肆角陸分", 123.456f64);
     test!("負壹佰貳拾參肆角陸分", -123.456f64);
 
-    test!("參佰肆拾萬貳仟捌佰貳拾參京肆仟陸佰陸拾參萬捌仟伍佰貳拾捌億捌仟伍佰玖拾捌萬壹仟壹佰柒拾兆肆仟壹佰捌拾參萬肆仟捌佰肆拾伍億壹仟陸佰玖拾貳萬伍仟肆佰肆拾",f32::MAX);
-    test!("負參佰肆拾萬貳仟捌佰貳拾參京肆仟陸佰陸拾參萬捌仟伍佰貳拾捌億捌仟伍佰玖拾捌萬壹仟壹佰柒拾兆肆仟壹佰捌拾參萬肆仟捌佰肆拾伍億壹仟陸佰玖拾貳萬伍仟肆佰肆拾",f32::MIN);
-    test!("壹萬柒仟玖佰柒拾陸兆玖仟參佰壹拾參萬肆仟捌佰陸拾貳億參仟壹佰伍拾柒萬零捌佰壹拾肆京伍仟貳佰柒拾肆萬貳仟參佰柒拾參億壹仟柒佰零肆萬參仟伍佰陸拾柒兆玖仟捌佰零柒萬零伍佰陸拾柒億伍仟貳佰伍拾捌萬肆仟肆佰玖拾玖穰陸仟伍佰玖拾捌萬玖仟壹佰柒拾肆億柒仟陸佰捌拾萬參仟壹佰伍拾柒兆貳仟陸佰零柒萬捌仟零貳億捌仟伍佰參拾捌萬柒仟陸佰零伍京捌仟玖佰伍拾伍萬捌仟陸佰參拾貳億柒仟陸佰陸拾捌萬柒仟捌佰壹拾柒兆壹仟伍佰肆拾萬肆仟伍佰捌拾玖億伍仟參佰伍拾壹萬肆仟參佰捌拾貳垓肆仟陸佰肆拾貳萬參仟肆佰參拾貳億壹仟參佰貳拾陸萬捌仟捌佰玖拾肆兆陸仟肆佰壹拾捌萬貳仟柒佰陸拾捌億肆仟陸佰柒拾伍萬肆仟陸佰柒拾京參仟伍佰參拾柒萬伍仟壹佰陸拾玖億捌仟陸佰零肆萬玖仟玖佰壹拾兆伍仟柒佰陸拾伍萬伍仟壹佰貳拾捌億貳仟零柒拾陸萬貳仟肆佰伍拾肆秭玖仟零玖萬零參佰捌拾玖億參仟貳佰捌拾玖萬肆仟肆佰零柒兆伍仟捌佰陸拾捌萬伍仟零捌拾肆億伍仟伍佰壹拾參萬參仟玖佰肆拾貳京參仟零肆拾伍萬捌仟參佰貳拾參億陸仟玖佰零參萬貳仟貳佰貳拾玖兆肆仟捌佰壹拾陸萬伍仟捌佰零捌億伍仟伍佰玖拾參萬參仟貳佰壹拾貳垓參仟參佰肆拾捌萬貳仟柒佰肆拾柒億玖仟柒佰捌拾貳萬陸仟貳佰零肆兆壹仟肆佰肆拾柒萬貳仟參佰壹拾陸億捌仟柒佰參拾捌萬壹仟柒佰柒拾壹京捌仟零玖拾壹萬玖仟貳佰玖拾玖億捌仟捌佰壹拾貳萬伍仟零肆拾兆肆仟零貳拾陸萬壹仟捌佰肆拾壹億貳仟肆佰捌拾伍萬捌仟參佰陸拾捌",f64::MAX);
-    test!("負壹萬柒仟玖佰柒拾陸兆玖仟參佰壹拾參萬肆仟捌佰陸拾貳億參仟壹佰伍拾柒萬零捌佰壹拾肆京伍仟貳佰柒拾肆萬貳仟參佰柒拾參億壹仟柒佰零肆萬參仟伍佰陸拾柒兆玖仟捌佰零柒萬零伍佰陸拾柒億伍仟貳佰伍拾捌萬肆仟肆佰玖拾玖穰陸仟伍佰玖拾捌萬玖仟壹佰柒拾肆億柒仟陸佰捌拾萬參仟壹佰伍拾柒兆貳仟陸佰零柒萬捌仟零貳億捌仟伍佰參拾捌萬柒仟陸佰零伍京捌仟玖佰伍拾伍萬捌仟陸佰參拾貳億柒仟陸佰陸拾捌萬柒仟捌佰壹拾柒兆壹仟伍佰肆拾萬肆仟伍佰捌拾玖億伍仟參佰伍拾壹萬肆仟參佰捌拾貳垓肆仟陸佰肆拾貳萬參仟肆佰參拾貳億壹仟參佰貳拾陸萬捌仟捌佰玖拾肆兆陸仟肆佰壹拾捌萬貳仟柒佰陸拾捌億肆仟陸佰柒拾伍萬肆仟陸佰柒拾京參仟伍佰參拾柒萬伍仟壹佰陸拾玖億捌仟陸佰零肆萬玖仟玖佰壹拾兆伍仟柒佰陸拾伍萬伍仟壹佰貳拾捌億貳仟零柒拾陸萬貳仟肆佰伍拾肆秭玖仟零玖萬零參佰捌拾玖億參仟貳佰捌拾玖萬肆仟肆佰零柒兆伍仟捌佰陸拾捌萬伍仟零捌拾肆億伍仟伍佰壹拾參萬參仟玖佰肆拾貳京參仟零肆拾伍萬捌仟參佰貳拾參億陸仟玖佰零參萬貳仟貳佰貳拾玖兆肆仟捌佰壹拾陸萬伍仟捌佰零捌億伍仟伍佰玖拾參萬參仟貳佰壹拾貳垓參仟參佰肆拾捌萬貳仟柒佰肆拾柒億玖仟柒佰捌拾貳萬陸仟貳佰零肆兆壹仟肆佰肆拾柒萬貳仟參佰壹拾陸億捌仟柒佰參拾捌萬壹仟柒佰柒拾壹京捌仟零玖拾壹萬玖仟貳佰玖拾玖億捌仟捌佰壹拾貳萬伍仟零肆拾兆肆仟零貳拾陸萬壹仟捌佰肆拾壹億貳仟肆佰捌拾伍萬捌仟參佰陸拾捌",f64::MIN);
+    test!("參佰肆拾萬貳仟捌佰貳拾參京伍仟萬億兆", f32::MAX);
+    test!("負參佰肆拾萬貳仟捌佰貳拾參京伍仟萬億兆", f32::MIN);
+    test!("壹萬柒仟玖佰柒拾陸兆玖仟參佰壹拾參萬肆仟捌佰陸拾貳億參仟壹佰伍拾柒萬京穰", f64::MAX);
+    test!("負壹萬柒仟玖佰柒拾陸兆玖仟參佰壹拾參萬肆仟捌佰陸拾貳億參仟壹佰伍拾柒萬京穰", f64::MIN);
 }
 
 #[test]
@@ -235,10 +252,10 @@ fn to_lowercase_ten_thousand() {
     test!("一百二十三四角六分", 123.456f64);
     test!("負一百二十三四角六分", -123.456f64);
 
-    test!("三百四十澗二千八百二十三溝四千六百六十三穰八千五百二十八秭八千五百九十八垓一千一百七十京四千一百八十三兆四千八百四十五億一千六百九十二萬五千四百四十",f32::MAX);
-    test!("負三百四十澗二千八百二十三溝四千六百六十三穰八千五百二十八秭八千五百九十八垓一千一百七十京四千一百八十三兆四千八百四十五億一千六百九十二萬五千四百四十",f32::MIN);
-    test!("九千九百九十九極九千九百九十九載九千九百九十九正九千九百八十九澗二千九百八十三溝八千五百五十二穰零四百六十四秭二千八百九十二垓九千六百五十三京四千一百六十兆六千零二十一億七千六百六十八萬四千零三十二",1e52f64 - 1e37);
-    test!("負九千九百九十九極九千九百九十九載九千九百九十九正九千九百八十九澗二千九百八十三溝八千五百五十二穰零四百六十四秭二千八百九十二垓九千六百五十三京四千一百六十兆六千零二十一億七千六百六十八萬四千零三十二",-1e52f64 + 1e37);
+    test!("三百四十澗二千八百二十三溝五千穰", f32::MAX);
+    test!("負三百四十澗二千八百二十三溝五千穰", f32::MIN);
+    test!("九千九百九十九極九千九百九十九載九千九百九十九正九千九百八十九澗", 1e52f64 - 1e37);
+    test!("負九千九百九十九極九千九百九十九載九千九百九十九正九千九百八十九澗", -1e52f64 + 1e37);
 
     test_err!(NumberToChineseError::Overflow, f64::MAX);
     test_err!(NumberToChineseError::Underflow, f64::MIN);
@@ -269,10 +286,10 @@ fn to_lowercase_middle() {
     test!("一百二十三四角六分", 123.456f64);
     test!("負一百二十三四角六分", -123.456f64);
 
-    test!("三百四十萬二千八百二十三垓四千六百六十三萬八千五百二十八京八千五百九十八萬一千一百七十兆四千一百八十三萬四千八百四十五億一千六百九十二萬五千四百四十",f32::MAX);
-    test!("負三百四十萬二千八百二十三垓四千六百六十三萬八千五百二十八京八千五百九十八萬一千一百七十兆四千一百八十三萬四千八百四十五億一千六百九十二萬五千四百四十",f32::MIN);
-    test!("九千九百九十九萬九千九百九十九極九千九百九十九萬九千九百九十一載零一百二十九萬二千八百五十八正九千三百九十八萬一千四百六十二澗零一百零七萬一千一百六十七溝五千九百二十九萬四千二百六十七穰一千二百一十萬一千七百五十八秭七千一百七十八萬五千四百九十六垓八千四百四十八萬七千七百八十七京八千六百二十七萬七千七百六十二兆二千五百六十萬一千零一十億零五百萬零七百零四",1e96f64 - 1e81);
-    test!("負九千九百九十九萬九千九百九十九極九千九百九十九萬九千九百九十一載零一百二十九萬二千八百五十八正九千三百九十八萬一千四百六十二澗零一百零七萬一千一百六十七溝五千九百二十九萬四千二百六十七穰一千二百一十萬一千七百五十八秭七千一百七十八萬五千四百九十六垓八千四百四十八萬七千七百八十七京八千六百二十七萬七千七百六十二兆二千五百六十萬一千零一十億零五百萬零七百零四",-1e96f64 + 1e81);
+    test!("三百四十萬二千八百二十三垓五千萬京", f32::MAX);
+    test!("負三百四十萬二千八百二十三垓五千萬京", f32::MIN);
+    test!("九千九百九十九萬九千九百九十九極九千九百九十九萬九千九百九十一載", 1e96f64 - 1e81);
+    test!("負九千九百九十九萬九千九百九十九極九千九百九十九萬九千九百九十一載", -1e96f64 + 1e81);
 
     test_err!(NumberToChineseError::Overflow, f64::MAX);
     test_err!(NumberToChineseError::Underflow, f64::MIN);
@@ -339,13 +356,1546 @@ fn to_uppercase_naive() {
     test!("負壹貳參點肆陸", -123.456f64);
 
     test!(
-        "參肆零貳捌貳參肆陸陸參捌伍貳捌捌伍玖捌壹壹柒零肆壹捌參肆捌肆伍壹陸玖貳伍肆肆零",
+        "參肆零貳捌貳參伍零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零",
         f32::MAX
     );
     test!(
-        "負參肆零貳捌貳參肆陸陸參捌伍貳捌捌伍玖捌壹壹柒零肆壹捌參肆捌肆伍壹陸玖貳伍肆肆零",
+        "負參肆零貳捌貳參伍零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零",
         f32::MIN
     );
-    test!("壹柒玖柒陸玖參壹參肆捌陸貳參壹伍柒零捌壹肆伍貳柒肆貳參柒參壹柒零肆參伍陸柒玖捌零柒零伍陸柒伍貳伍捌肆肆玖玖陸伍玖捌玖壹柒肆柒陸捌零參壹伍柒貳陸零柒捌零零貳捌伍參捌柒陸零伍捌玖伍伍捌陸參貳柒陸陸捌柒捌壹柒壹伍肆零肆伍捌玖伍參伍壹肆參捌貳肆陸肆貳參肆參貳壹參貳陸捌捌玖肆陸肆壹捌貳柒陸捌肆陸柒伍肆陸柒零參伍參柒伍壹陸玖捌陸零肆玖玖壹零伍柒陸伍伍壹貳捌貳零柒陸貳肆伍肆玖零零玖零參捌玖參貳捌玖肆肆零柒伍捌陸捌伍零捌肆伍伍壹參參玖肆貳參零肆伍捌參貳參陸玖零參貳貳貳玖肆捌壹陸伍捌零捌伍伍玖參參貳壹貳參參肆捌貳柒肆柒玖柒捌貳陸貳零肆壹肆肆柒貳參壹陸捌柒參捌壹柒柒壹捌零玖壹玖貳玖玖捌捌壹貳伍零肆零肆零貳陸壹捌肆壹貳肆捌伍捌參陸捌",f64::MAX);
-    test!("負壹柒玖柒陸玖參壹參肆捌陸貳參壹伍柒零捌壹肆伍貳柒肆貳參柒參壹柒零肆參伍陸柒玖捌零柒零伍陸柒伍貳伍捌肆肆玖玖陸伍玖捌玖壹柒肆柒陸捌零參壹伍柒貳陸零柒捌零零貳捌伍參捌柒陸零伍捌玖伍伍捌陸參貳柒陸陸捌柒捌壹柒壹伍肆零肆伍捌玖伍參伍壹肆參捌貳肆陸肆貳參肆參貳壹參貳陸捌捌玖肆陸肆壹捌貳柒陸捌肆陸柒伍肆陸柒零參伍參柒伍壹陸玖捌陸零肆玖玖壹零伍柒陸伍伍壹貳捌貳零柒陸貳肆伍肆玖零零玖零參捌玖參貳捌玖肆肆零柒伍捌陸捌伍零捌肆伍伍壹參參玖肆貳參零肆伍捌參貳參陸玖零參貳貳貳玖肆捌壹陸伍捌零捌伍伍玖參參貳壹貳參參肆捌貳柒肆柒玖柒捌貳陸貳零肆壹肆肆柒貳參壹陸捌柒參捌壹柒柒壹捌零玖壹玖貳玖玖捌捌壹貳伍零肆零肆零貳陸壹捌肆壹貳肆捌伍捌參陸捌",f64::MIN);
+    test!("壹柒玖柒陸玖參壹參肆捌陸貳參壹伍柒零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零",f64::MAX);
+    test!("負壹柒玖柒陸玖參壹參肆捌陸貳參壹伍柒零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零零",f64::MIN);
+}
+
+#[test]
+fn to_chinese_decimal() {
+    macro_rules! test {
+        ($expect:expr, $value:expr, $case:expr, $method:expr) => {
+            assert_eq!(
+                $expect,
+                $value.to_chinese_decimal(ChineseVariant::Traditional, $case, $method).unwrap()
+            );
+        };
+    }
+
+    test!("一點二三四五", 1.2345f64, ChineseCase::Lower, ChineseCountMethod::TenThousand);
+    test!("負一點二三四五", -1.2345f64, ChineseCase::Lower, ChineseCountMethod::TenThousand);
+    test!("壹點貳參肆伍", 1.2345f64, ChineseCase::Upper, ChineseCountMethod::TenThousand);
+    test!("零點五", 0.5f64, ChineseCase::Lower, ChineseCountMethod::TenThousand);
+    test!("零點零零零零零零一", 1e-7f64, ChineseCase::Lower, ChineseCountMethod::TenThousand);
+    test!("一點二", 1.2f64, ChineseCase::Lower, ChineseCountMethod::TenThousand);
+    test!("零點一", 0.1f32, ChineseCase::Lower, ChineseCountMethod::TenThousand);
+    test!("一點一", 1.1f32, ChineseCase::Lower, ChineseCountMethod::TenThousand);
+    test!("一千兆點一", 1e15f64 + 0.07, ChineseCase::Lower, ChineseCountMethod::TenThousand);
+    test!("零", 0f64, ChineseCase::Lower, ChineseCountMethod::TenThousand);
+    test!(
+        "十二萬三千四百五十六點七八九",
+        123456.789f64,
+        ChineseCase::Lower,
+        ChineseCountMethod::TenThousand
+    );
+    test!(
+        "一億二萬三千四百五十六點七八九",
+        123456.789f64,
+        ChineseCase::Lower,
+        ChineseCountMethod::Low
+    );
+    test!("一萬億零一點五", 1_0000_0000_0001.5f64, ChineseCase::Lower, ChineseCountMethod::Middle);
+    test!("一百二十三", 123i32, ChineseCase::Lower, ChineseCountMethod::TenThousand);
+
+    assert_eq!(
+        Err(NumberToChineseError::Overflow),
+        1e16f64.to_chinese_decimal(
+            ChineseVariant::Traditional,
+            ChineseCase::Lower,
+            ChineseCountMethod::Low
+        )
+    );
+    assert_eq!(
+        Err(NumberToChineseError::Underflow),
+        (-1e72f64).to_chinese_decimal(
+            ChineseVariant::Traditional,
+            ChineseCase::Lower,
+            ChineseCountMethod::TenThousand
+        )
+    );
+}
+
+#[test]
+fn to_chinese_with_fraction() {
+    macro_rules! test {
+        ($expect:expr, $value:expr, $precision:expr, $rounding:expr) => {
+            assert_eq!(
+                $expect,
+                $value
+                    .to_chinese_with_fraction(
+                        ChineseVariant::Traditional,
+                        ChineseCase::Lower,
+                        ChineseCountMethod::TenThousand,
+                        &ChineseFractionOptions::new()
+                            .with_precision($precision)
+                            .with_rounding($rounding)
+                    )
+                    .unwrap()
+            );
+        };
+    }
+
+    test!("一百二十三四角六分", 123.456f64, 2, ChineseRounding::HalfUp);
+    test!("一百二十三四角五分", 123.456f64, 2, ChineseRounding::Truncate);
+    test!("一百二十三五角", 123.456f64, 1, ChineseRounding::HalfUp);
+    test!("一百二十三", 123.456f64, 0, ChineseRounding::HalfUp);
+    test!("一百二十四", 123.5f64, 0, ChineseRounding::HalfUp);
+    test!("一百二十四", 123.5f64, 0, ChineseRounding::HalfEven);
+    test!("一百二十二", 122.5f64, 0, ChineseRounding::HalfEven);
+    test!("一百二十二", 122.5f64, 0, ChineseRounding::Truncate);
+    test!("二角九分", 0.29f64, 2, ChineseRounding::Truncate);
+    test!("一角一分", 0.101f64, 2, ChineseRounding::Ceiling);
+    test!("負一角", -0.101f64, 2, ChineseRounding::Ceiling);
+    test!("一", 0.999f64, 2, ChineseRounding::HalfUp);
+    test!("零", -0.001f64, 2, ChineseRounding::HalfUp);
+    test!("一角", 0.1f32, 2, ChineseRounding::Ceiling);
+    test!("一一角", 1.1f32, 2, ChineseRounding::Ceiling);
+    test!("一百二十三四角五分六厘", 123.456f64, 5, ChineseRounding::HalfUp);
+    test!("一百二十三四角六分", 123.456f64, 2, ChineseRounding::HalfUp);
+    test!("三厘", 0.003f64, 3, ChineseRounding::HalfUp);
+    test!("五分三厘", 0.0534f64, 3, ChineseRounding::HalfUp);
+    test!("五分三厘四毫", 0.0534f64, 4, ChineseRounding::HalfUp);
+    test!("一絲二忽", 0.000012f64, 6, ChineseRounding::HalfUp);
+    test!("一微二纖三沙四塵", 0.0000001234f64, 12, ChineseRounding::HalfUp);
+    test!("一塵", 0.00000000005f64, 10, ChineseRounding::HalfUp);
+    test!("一百二十三", 123i32, 0, ChineseRounding::HalfUp);
+
+    assert_eq!(
+        Err(NumberToChineseError::Overflow),
+        1e16f64.to_chinese_with_fraction(
+            ChineseVariant::Traditional,
+            ChineseCase::Lower,
+            ChineseCountMethod::Low,
+            &ChineseFractionOptions::new()
+        )
+    );
+}
+
+#[test]
+fn to_chinese_naive_with_fraction() {
+    macro_rules! test {
+        ($expect:expr, $value:expr, $precision:expr, $rounding:expr) => {
+            assert_eq!(
+                $expect,
+                $value
+                    .to_chinese_naive_with_fraction(
+                        ChineseVariant::Traditional,
+                        ChineseCase::Lower,
+                        &ChineseFractionOptions::new()
+                            .with_precision($precision)
+                            .with_rounding($rounding)
+                    )
+                    .unwrap()
+            );
+        };
+    }
+
+    test!("一二三點四六", 123.456f64, 2, ChineseRounding::HalfUp);
+    test!("一二三點四五", 123.456f64, 2, ChineseRounding::Truncate);
+    test!("一二三點四五六", 123.456f64, 5, ChineseRounding::HalfUp);
+    test!("一二三", 123.456f64, 0, ChineseRounding::Truncate);
+    test!("一二四", 123.456f64, 0, ChineseRounding::Ceiling);
+    test!("負一二三", -123.456f64, 0, ChineseRounding::Ceiling);
+    test!("一點二四", 1.235f64, 2, ChineseRounding::HalfEven);
+    test!("一點二", 1.25f64, 1, ChineseRounding::HalfEven);
+    test!("零點零五", 0.05f64, 2, ChineseRounding::HalfUp);
+    test!("一二三", 123i32, 0, ChineseRounding::HalfUp);
+}
+
+#[test]
+fn non_finite() {
+    for method in ChineseCountMethod::variants() {
+        for value in [f64::NAN, -f64::NAN] {
+            assert_eq!(
+                Err(NumberToChineseError::NotANumber),
+                value.to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, method)
+            );
+            assert_eq!(
+                Err(NumberToChineseError::NotANumber),
+                (value as f32).to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, method)
+            );
+            assert_eq!(
+                Err(NumberToChineseError::NotANumber),
+                value.to_chinese_decimal(ChineseVariant::Traditional, ChineseCase::Lower, method)
+            );
+        }
+
+        for value in [f64::INFINITY, f64::NEG_INFINITY] {
+            assert_eq!(
+                Err(NumberToChineseError::Infinite),
+                value.to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, method)
+            );
+            assert_eq!(
+                Err(NumberToChineseError::Infinite),
+                (value as f32).to_chinese(ChineseVariant::Traditional, ChineseCase::Lower, method)
+            );
+            assert_eq!(
+                Err(NumberToChineseError::Infinite),
+                value.to_chinese_decimal(ChineseVariant::Traditional, ChineseCase::Lower, method)
+            );
+        }
+
+        let options = ChineseFractionOptions::new().with_non_finite(true);
+
+        assert_eq!(
+            "非數",
+            f64::NAN
+                .to_chinese_with_fraction(
+                    ChineseVariant::Traditional,
+                    ChineseCase::Lower,
+                    method,
+                    &options
+                )
+                .unwrap()
+        );
+        assert_eq!(
+            "無窮大",
+            f64::INFINITY
+                .to_chinese_with_fraction(
+                    ChineseVariant::Traditional,
+                    ChineseCase::Lower,
+                    method,
+                    &options
+                )
+                .unwrap()
+        );
+        assert_eq!(
+            "負無窮大",
+            f32::NEG_INFINITY
+                .to_chinese_with_fraction(
+                    ChineseVariant::Traditional,
+                    ChineseCase::Upper,
+                    method,
+                    &options
+                )
+                .unwrap()
+        );
+        assert_eq!(
+            "负无穷大",
+            f64::NEG_INFINITY
+                .to_chinese_with_fraction(
+                    ChineseVariant::Simple,
+                    ChineseCase::Lower,
+                    method,
+                    &options
+                )
+                .unwrap()
+        );
+    }
+
+    assert_eq!(
+        Err(NumberToChineseError::NotANumber),
+        f64::NAN.to_chinese_naive(ChineseVariant::Traditional, ChineseCase::Lower)
+    );
+    assert_eq!(
+        Err(NumberToChineseError::Infinite),
+        f32::INFINITY.to_chinese_naive(ChineseVariant::Simple, ChineseCase::Lower)
+    );
+    assert_eq!(
+        Err(NumberToChineseError::Infinite),
+        f64::NEG_INFINITY.to_chinese_naive_with(
+            &ChineseFormatOptions::new().with_fraction_options(ChineseFractionOptions::new())
+        )
+    );
+    assert_eq!(
+        Err(NumberToChineseError::NotANumber),
+        chinese_number::from_f64_to_chinese_naive(
+            ChineseVariant::Traditional,
+            ChineseCase::Lower,
+            f64::NAN
+        )
+    );
+
+    let options = ChineseFractionOptions::new().with_non_finite(true);
+
+    assert_eq!(
+        "非數",
+        f64::NAN
+            .to_chinese_naive_with_fraction(
+                ChineseVariant::Traditional,
+                ChineseCase::Lower,
+                &options
+            )
+            .unwrap()
+    );
+    assert_eq!(
+        "非数",
+        f32::NAN
+            .to_chinese_naive_with_fraction(ChineseVariant::Simple, ChineseCase::Lower, &options)
+            .unwrap()
+    );
+    assert_eq!(
+        "負無窮大",
+        f64::NEG_INFINITY
+            .to_chinese_naive_with(&ChineseFormatOptions::new().with_fraction_options(options))
+            .unwrap()
+    );
+}
+
+#[test]
+fn write_chinese() {
+    use core::fmt::Write;
+
+    for method in ChineseCountMethod::variants() {
+        for value in [0i128, 7, 10, 1_0001, -30_0003, 1234_5678_9012_3456, -9999_9999_9999_9999] {
+            let mut s = String::from("[");
+
+            value
+                .write_chinese(&mut s, ChineseVariant::Traditional, ChineseCase::Upper, method)
+                .unwrap();
+            s.write_char(']').unwrap();
+
+            assert_eq!(
+                format!(
+                    "[{}]",
+                    value
+                        .to_chinese(ChineseVariant::Traditional, ChineseCase::Upper, method)
+                        .unwrap()
+                ),
+                s
+            );
+        }
+
+        for value in [0u128, 1, 1_0000_0000_0000_0000_0000, u128::MAX] {
+            let mut s = String::new();
+
+            if let Ok(expect) = value.to_chinese(ChineseVariant::Simple, ChineseCase::Lower, method)
+            {
+                value
+                    .write_chinese(&mut s, ChineseVariant::Simple, ChineseCase::Lower, method)
+                    .unwrap();
+
+                assert_eq!(expect, s);
+            } else {
+                assert_eq!(
+                    Err(NumberToChineseError::Overflow),
+                    value.write_chinese(&mut s, ChineseVariant::Simple, ChineseCase::Lower, method)
+                );
+            }
+        }
+    }
+
+    let mut s = String::new();
+
+    assert_eq!(
+        Err(NumberToChineseError::Underflow),
+        i64::MIN.write_chinese(
+            &mut s,
+            ChineseVariant::Traditional,
+            ChineseCase::Lower,
+            ChineseCountMethod::Low
+        )
+    );
+    assert_eq!("", s);
+
+    1.25f64
+        .write_chinese(
+            &mut s,
+            ChineseVariant::Traditional,
+            ChineseCase::Lower,
+            ChineseCountMethod::TenThousand,
+        )
+        .unwrap();
+    assert_eq!("一二角五分", s);
+
+    let mut s = String::new();
+
+    (-1203i16)
+        .write_chinese_naive(&mut s, ChineseVariant::Traditional, ChineseCase::Lower)
+        .unwrap();
+    u128::MAX.write_chinese_naive(&mut s, ChineseVariant::Traditional, ChineseCase::Lower).unwrap();
+    assert_eq!(
+        format!(
+            "負一二零三{}",
+            u128::MAX.to_chinese_naive(ChineseVariant::Traditional, ChineseCase::Lower).unwrap()
+        ),
+        s
+    );
+}
+
+#[test]
+fn chinese_display() {
+    assert_eq!(
+        "壹萬零壹拾元",
+        format!(
+            "{}元",
+            10010u32
+                .to_chinese_display(
+                    ChineseVariant::Traditional,
+                    ChineseCase::Upper,
+                    ChineseCountMethod::TenThousand
+                )
+                .unwrap()
+        )
+    );
+    assert_eq!(
+        "负一万亿",
+        (-1_0000_0000_0000i64)
+            .to_chinese_display(
+                ChineseVariant::Simple,
+                ChineseCase::Lower,
+                ChineseCountMethod::Middle
+            )
+            .unwrap()
+            .to_string()
+    );
+    assert_eq!(
+        "一五角",
+        format!(
+            "{}",
+            1.5f32
+                .to_chinese_display(
+                    ChineseVariant::Traditional,
+                    ChineseCase::Lower,
+                    ChineseCountMethod::TenThousand
+                )
+                .unwrap()
+        )
+    );
+
+    assert_eq!(
+        Err(NumberToChineseError::Overflow),
+        u64::MAX.to_chinese_display(
+            ChineseVariant::Traditional,
+            ChineseCase::Lower,
+            ChineseCountMethod::Low
+        )
+    );
+    assert_eq!(
+        Err(NumberToChineseError::NotANumber),
+        f64::NAN.to_chinese_display(
+            ChineseVariant::Traditional,
+            ChineseCase::Lower,
+            ChineseCountMethod::TenThousand
+        )
+    );
+}
+
+#[test]
+fn to_chinese_with() {
+    let options = ChineseFormatOptions::new();
+
+    for method in ChineseCountMethod::variants() {
+        let options = options.with_method(method).with_case(ChineseCase::Upper);
+
+        for value in [0i64, 12, -3_0405, 1_0000_0000_0001] {
+            assert_eq!(
+                value.to_chinese(ChineseVariant::Traditional, ChineseCase::Upper, method),
+                value.to_chinese_with(&options)
+            );
+        }
+
+        assert_eq!(
+            12.34f64.to_chinese(ChineseVariant::Traditional, ChineseCase::Upper, method),
+            12.34f64.to_chinese_with(&options)
+        );
+    }
+
+    assert_eq!("一萬零一", 10001u16.to_chinese_with(&options).unwrap());
+    assert_eq!(
+        "一万零一",
+        10001u16.to_chinese_with(&options.with_variant(ChineseVariant::Simple)).unwrap()
+    );
+    assert_eq!(
+        "二无量大数",
+        2e68f64.to_chinese_with(&options.with_variant(ChineseVariant::Simple)).unwrap()
+    );
+    assert_eq!(
+        "三恒河沙",
+        3e52f64.to_chinese_with(&options.with_variant(ChineseVariant::Simple)).unwrap()
+    );
+    assert_eq!(
+        "一兆",
+        1_0000_0000_0000_0000u64
+            .to_chinese_with(&options.with_method(ChineseCountMethod::High))
+            .unwrap()
+    );
+
+    let circle = options.with_zero_style(ChineseZeroStyle::Circle);
+
+    assert_eq!("〇", 0u8.to_chinese_with(&circle).unwrap());
+    assert_eq!("二千〇二十四", 2024.to_chinese_with(&circle).unwrap());
+    assert_eq!(
+        "〇點〇五",
+        0.05.to_chinese_with(&circle.with_fraction_style(ChineseFractionStyle::Decimal)).unwrap()
+    );
+
+    let liang = options.with_two_style(ChineseTwoStyle::Liang);
+
+    assert_eq!("二", 2.to_chinese_with(&liang).unwrap());
+    assert_eq!("二十二", 22.to_chinese_with(&liang).unwrap());
+    assert_eq!("兩百二十", 220.to_chinese_with(&liang).unwrap());
+    assert_eq!("兩萬兩千零二", 2_2002.to_chinese_with(&liang).unwrap());
+    assert_eq!("二十萬", 20_0000.to_chinese_with(&liang).unwrap());
+    assert_eq!("兩億", 2_0000_0000.to_chinese_with(&liang).unwrap());
+    assert_eq!(
+        "两亿",
+        2_0000_0000.to_chinese_with(&liang.with_variant(ChineseVariant::Simple)).unwrap()
+    );
+    assert_eq!("貳萬", 2_0000.to_chinese_with(&liang.with_case(ChineseCase::Upper)).unwrap());
+    assert_eq!("二角二分", 0.22.to_chinese_with(&liang).unwrap());
+
+    let explicit = options.with_sign_style(ChineseSignStyle::Explicit);
+
+    assert_eq!("正三", 3u8.to_chinese_with(&explicit).unwrap());
+    assert_eq!("負三", (-3i8).to_chinese_with(&explicit).unwrap());
+    assert_eq!("零", 0i8.to_chinese_with(&explicit).unwrap());
+    assert_eq!("零", (-0.001).to_chinese_with(&explicit).unwrap());
+    assert_eq!("正一角", 0.1f32.to_chinese_with(&explicit).unwrap());
+    assert_eq!(
+        "负三",
+        (-3i8).to_chinese_with(&explicit.with_variant(ChineseVariant::Simple)).unwrap()
+    );
+    assert_eq!(
+        "+三",
+        3u8.to_chinese_with(&explicit.with_positive_style(ChinesePositiveStyle::Plus)).unwrap()
+    );
+    assert_eq!(
+        "三",
+        3u8.to_chinese_with(&options.with_positive_style(ChinesePositiveStyle::Plus)).unwrap()
+    );
+    assert_eq!(
+        "零下五",
+        (-5i8)
+            .to_chinese_with(&options.with_negative_style(ChineseNegativeStyle::LingXia))
+            .unwrap()
+    );
+    assert_eq!(
+        "零下五點五",
+        (-5.5f64)
+            .to_chinese_with(
+                &options
+                    .with_fraction_style(ChineseFractionStyle::Decimal)
+                    .with_negative_style(ChineseNegativeStyle::LingXia)
+            )
+            .unwrap()
+    );
+    assert_eq!(
+        "減三角",
+        (-0.3f64)
+            .to_chinese_with(&options.with_negative_style(ChineseNegativeStyle::Jian))
+            .unwrap()
+    );
+    assert_eq!(
+        "减三",
+        (-3i8)
+            .to_chinese_with(
+                &options
+                    .with_variant(ChineseVariant::Simple)
+                    .with_negative_style(ChineseNegativeStyle::Jian)
+            )
+            .unwrap()
+    );
+    assert_eq!(
+        "−三",
+        (-3i8).to_chinese_with(&options.with_negative_style(ChineseNegativeStyle::Minus)).unwrap()
+    );
+    assert_eq!(
+        "-三",
+        (-3i8)
+            .to_chinese_with(&options.with_negative_style(ChineseNegativeStyle::HyphenMinus))
+            .unwrap()
+    );
+    assert_eq!(
+        "零",
+        0i8.to_chinese_with(&options.with_negative_style(ChineseNegativeStyle::LingXia)).unwrap()
+    );
+
+    let decimal = options.with_fraction_style(ChineseFractionStyle::Decimal);
+
+    assert_eq!("一點二三四五", 1.2345.to_chinese_with(&decimal).unwrap());
+    assert_eq!(
+        "一點二三",
+        1.2345
+            .to_chinese_with(&decimal.with_fraction_options(ChineseFractionOptions::new()))
+            .unwrap()
+    );
+    assert_eq!(
+        "一點二三四",
+        1.2345
+            .to_chinese_with(
+                &decimal.with_fraction_options(
+                    ChineseFractionOptions::new()
+                        .with_precision(3)
+                        .with_rounding(ChineseRounding::HalfEven)
+                )
+            )
+            .unwrap()
+    );
+    assert_eq!(
+        "一二角三分",
+        1.235
+            .to_chinese_with(&options.with_fraction_options(
+                ChineseFractionOptions::new().with_rounding(ChineseRounding::Truncate)
+            ))
+            .unwrap()
+    );
+    assert_eq!(
+        "二分三厘四毫五丝",
+        0.02345
+            .to_chinese_with(
+                &options
+                    .with_variant(ChineseVariant::Simple)
+                    .with_fraction_options(ChineseFractionOptions::new().with_precision(5))
+            )
+            .unwrap()
+    );
+
+    assert_eq!(Err(NumberToChineseError::NotANumber), f64::NAN.to_chinese_with(&options));
+    assert_eq!(
+        "正無窮大",
+        f64::INFINITY
+            .to_chinese_with(
+                &explicit
+                    .with_fraction_options(ChineseFractionOptions::new().with_non_finite(true))
+            )
+            .unwrap()
+    );
+    assert_eq!(
+        Err(NumberToChineseError::Overflow),
+        u64::MAX.to_chinese_with(&options.with_method(ChineseCountMethod::Low))
+    );
+    assert_eq!(
+        Err(NumberToChineseError::Underflow),
+        i64::MIN.to_chinese_with(&options.with_method(ChineseCountMethod::Low))
+    );
+
+    assert_eq!(
+        "兩千〇二十四年",
+        format!(
+            "{}年",
+            2024.to_chinese_display_with(&liang.with_zero_style(ChineseZeroStyle::Circle)).unwrap()
+        )
+    );
+}
+
+#[test]
+fn two_style() {
+    let options = ChineseFormatOptions::new().with_two_style(ChineseTwoStyle::Liang);
+
+    macro_rules! test {
+        ($traditional:expr, $simple:expr, $value:expr) => {
+            assert_eq!($traditional, $value.to_chinese_with(&options).unwrap());
+            assert_eq!(
+                $simple,
+                $value.to_chinese_with(&options.with_variant(ChineseVariant::Simple)).unwrap()
+            );
+        };
+    }
+
+    test!("二", "二", 2);
+    test!("二十二", "二十二", 22);
+    test!("兩百", "两百", 200);
+    test!("兩百二十二", "两百二十二", 222);
+    test!("兩千", "两千", 2000);
+    test!("三千兩百", "三千二百", 3200);
+    test!("兩千兩百", "两千二百", 2200);
+    test!("兩萬兩千", "两万两千", 2_2000);
+    test!("一萬零兩百", "一万零二百", 1_0200);
+    test!("二十萬", "二十万", 20_0000);
+    test!("兩百萬", "两百万", 200_0000);
+    test!("兩億零二", "两亿零二", 2_0000_0002);
+    test!("十二萬", "十二万", 12_0000);
+    test!("二十二萬", "二十二万", 22_0000);
+    test!("一百零二萬", "一百零二万", 102_0000);
+    test!("兩千兩百二十二萬兩千兩百二十二", "两千二百二十二万两千二百二十二", 2222_2222);
+    test!("十二億", "十二亿", 12_0000_0000);
+    test!("負兩百", "负两百", -200);
+    test!("兩百二角", "两百二角", 200.2);
+
+    assert_eq!(
+        "兩兆",
+        2_0000_0000_0000_0000u64
+            .to_chinese_with(&options.with_method(ChineseCountMethod::High))
+            .unwrap()
+    );
+    assert_eq!(
+        "十二兆",
+        12_0000_0000_0000_0000u64
+            .to_chinese_with(&options.with_method(ChineseCountMethod::High))
+            .unwrap()
+    );
+    assert_eq!(
+        "十二億",
+        12_0000_0000u64.to_chinese_with(&options.with_method(ChineseCountMethod::Middle)).unwrap()
+    );
+    assert_eq!(
+        "一萬零二億",
+        1_0002_0000_0000u64
+            .to_chinese_with(&options.with_method(ChineseCountMethod::Middle))
+            .unwrap()
+    );
+    assert_eq!(
+        "兩萬億",
+        2_0000_0000_0000u64
+            .to_chinese_with(&options.with_method(ChineseCountMethod::Middle))
+            .unwrap()
+    );
+    assert_eq!(
+        "兩億",
+        20_0000u32.to_chinese_with(&options.with_method(ChineseCountMethod::Low)).unwrap()
+    );
+    assert_eq!("貳佰", 200.to_chinese_with(&options.with_case(ChineseCase::Upper)).unwrap());
+    assert_eq!("二百", 200.to_chinese_with(&ChineseFormatOptions::new()).unwrap());
+}
+
+#[test]
+fn ten_style() {
+    let options = ChineseFormatOptions::new();
+
+    macro_rules! test {
+        ($natural:expr, $short:expr, $full:expr, $value:expr, $method:expr) => {
+            let options = options.with_method($method);
+
+            assert_eq!(
+                $natural,
+                $value.to_chinese_with(&options.with_ten_style(ChineseTenStyle::Natural)).unwrap()
+            );
+            assert_eq!(
+                $short,
+                $value.to_chinese_with(&options.with_ten_style(ChineseTenStyle::Short)).unwrap()
+            );
+            assert_eq!(
+                $full,
+                $value.to_chinese_with(&options.with_ten_style(ChineseTenStyle::Full)).unwrap()
+            );
+        };
+    }
+
+    test!("十", "十", "一十", 10, ChineseCountMethod::Low);
+    test!("十五", "十五", "一十五", 15, ChineseCountMethod::TenThousand);
+    test!("一百一十", "一百一十", "一百一十", 110, ChineseCountMethod::TenThousand);
+    test!("十萬", "十萬", "一十萬", 10_0000, ChineseCountMethod::TenThousand);
+    test!("一萬零一十", "一萬零十", "一萬零一十", 1_0010, ChineseCountMethod::TenThousand);
+    test!(
+        "一億零一十萬",
+        "一億零十萬",
+        "一億零一十萬",
+        1_0010_0000,
+        ChineseCountMethod::TenThousand
+    );
+    test!(
+        "一億零一十萬零一十",
+        "一億零十萬零十",
+        "一億零一十萬零一十",
+        1_0010_0010,
+        ChineseCountMethod::TenThousand
+    );
+    test!("十億", "十億", "一十億", 10_0000_0000, ChineseCountMethod::Middle);
+    test!(
+        "一萬零一十億",
+        "一萬零十億",
+        "一萬零一十億",
+        1_0010_0000_0000u64,
+        ChineseCountMethod::Middle
+    );
+    test!("十兆", "十兆", "一十兆", 10_0000_0000_0000_0000u64, ChineseCountMethod::High);
+    test!("一萬零一十", "一萬零十", "一萬零一十", 1_0010, ChineseCountMethod::Low);
+    test!("一萬一千零一十", "一萬一千零一十", "一萬一千零一十", 1_1010, ChineseCountMethod::Low);
+    test!("一億零一十", "一億零十", "一億零一十", 10_0010, ChineseCountMethod::Low);
+    test!("負十", "負十", "負一十", -10, ChineseCountMethod::TenThousand);
+
+    assert_eq!(
+        "壹拾萬",
+        10_0000
+            .to_chinese_with(
+                &options.with_case(ChineseCase::Upper).with_ten_style(ChineseTenStyle::Full)
+            )
+            .unwrap()
+    );
+}
+
+#[test]
+fn glyph_style() {
+    let options = ChineseFormatOptions::new()
+        .with_case(ChineseCase::Upper)
+        .with_glyph_style(ChineseGlyphStyle::Ancient);
+
+    assert_eq!("弌陌弍什弎", 123.to_chinese_with(&options).unwrap());
+    assert_eq!("弎阡肆陌伍什陸萬", 3456_0000.to_chinese_with(&options).unwrap());
+    assert_eq!("什弌", 11.to_chinese_with(&options).unwrap());
+    assert_eq!(
+        "弌什弌",
+        11.to_chinese_with(&options.with_ten_style(ChineseTenStyle::Full)).unwrap()
+    );
+    assert_eq!(
+        "弍點弎",
+        2.3.to_chinese_with(&options.with_fraction_style(ChineseFractionStyle::Decimal)).unwrap()
+    );
+    assert_eq!(
+        "弌陌弍什弎",
+        123.to_chinese_with(&options.with_variant(ChineseVariant::Simple)).unwrap()
+    );
+    assert_eq!("一百二十三", 123.to_chinese_with(&options.with_case(ChineseCase::Lower)).unwrap());
+    assert_eq!(
+        "壹佰貳拾參",
+        123.to_chinese_with(&ChineseFormatOptions::new().with_case(ChineseCase::Upper)).unwrap()
+    );
+
+    assert_eq!(
+        "人民幣弌陌弍什元零弎角整",
+        12030
+            .to_chinese_currency_with(
+                &ChineseCurrencyOptions::new().with_glyph_style(ChineseGlyphStyle::Ancient)
+            )
+            .unwrap()
+    );
+}
+
+#[test]
+fn japanese() {
+    let options = ChineseFormatOptions::new().with_script(ChineseScript::Japanese);
+    let daiji = options.with_case(ChineseCase::Upper);
+
+    macro_rules! test {
+        ($lower:expr, $upper:expr, $value:expr) => {
+            assert_eq!($lower, $value.to_chinese_with(&options).unwrap());
+            assert_eq!($upper, $value.to_chinese_with(&daiji).unwrap());
+        };
+    }
+
+    test!("〇", "零", 0);
+    test!("十", "壱拾", 10);
+    test!("百十", "壱百壱拾", 110);
+    test!("千一", "壱千壱", 1001);
+    test!("千二百三十四", "壱千弐百参拾四", 1234);
+    test!("一万", "壱萬", 1_0000);
+    test!("一万一", "壱萬壱", 1_0001);
+    test!("千万", "壱千萬", 1000_0000);
+    test!("二億三千万五", "弐億参千萬五", 2_3000_0005);
+    test!("負十五", "負壱拾五", -15);
+    test!(
+        "十二𥝱三千四百五十六垓七千八百九十京千二百三十四兆五千六百七十八億九千十二万三千四百五十六",
+        "壱拾弐𥝱参千四百五拾六垓七千八百九拾京壱千弐百参拾四兆五千六百七拾八億九千壱拾弐萬参千四百五拾六",
+        12345678901234567890123456u128
+    );
+    test!("一無量大数", "壱無量大数", 1e68f64);
+
+    test!("三分の一", "参分の壱", (1, 3));
+    test!("百分の五", "百分の五", (5, 100));
+
+    // 日文的 **「分」** 是十分之一，所以小數部分一律使用 **「点」**
+    assert_eq!("〇点三五", 0.35.to_chinese_with(&options).unwrap());
+    assert_eq!(
+        "〇点三五",
+        0.35.to_chinese_with(&options.with_fraction_style(ChineseFractionStyle::Unit)).unwrap()
+    );
+    assert_eq!(
+        "百分の十五点三",
+        0.153.to_chinese_percent_with(&options, ChinesePercent::Percent).unwrap()
+    );
+    assert_eq!("三分の一", (1, 3).to_chinese_naive_with(&options).unwrap());
+    assert_eq!(
+        "负十五",
+        (-15).to_chinese_with(&options.with_variant(ChineseVariant::Simple)).unwrap()
+    );
+    assert_eq!(
+        "三点三五",
+        3.35.to_chinese_with(&options.with_fraction_style(ChineseFractionStyle::Decimal)).unwrap()
+    );
+    assert_eq!("二〇二四", 2024.to_chinese_naive_with(&options).unwrap());
+    assert_eq!(
+        "一千零一",
+        1001.to_chinese_with(&options.with_script(ChineseScript::Chinese)).unwrap()
+    );
+    assert_eq!("千二", 1002.to_chinese_with(&options.with_colloquial(true)).unwrap());
+    assert_eq!("1億2345万6789", 1_2345_6789.to_chinese_mixed_with(&options, None).unwrap());
+}
+
+#[test]
+fn colloquial() {
+    let options = ChineseFormatOptions::new().with_colloquial(true);
+
+    macro_rules! test {
+        ($expect:expr, $value:expr, $method:expr) => {
+            assert_eq!($expect, $value.to_chinese_with(&options.with_method($method)).unwrap());
+        };
+    }
+
+    test!("一百二", 120, ChineseCountMethod::TenThousand);
+    test!("一百一", 110, ChineseCountMethod::TenThousand);
+    test!("三萬四", 3_4000, ChineseCountMethod::TenThousand);
+    test!("十萬五", 10_5000, ChineseCountMethod::TenThousand);
+    test!("三十萬五", 30_5000, ChineseCountMethod::TenThousand);
+    test!("一億二", 1_2000_0000, ChineseCountMethod::TenThousand);
+    test!("一萬二", 1_2000, ChineseCountMethod::TenThousand);
+    test!("一億五", 15_0000, ChineseCountMethod::Low);
+    test!("一億五", 1_5000_0000, ChineseCountMethod::Middle);
+    test!("一兆五", 1_5000_0000_0000_0000u64, ChineseCountMethod::High);
+    test!("負一百二", -120, ChineseCountMethod::TenThousand);
+
+    // 不能省略的情況
+    test!("十五", 15, ChineseCountMethod::TenThousand);
+    test!("一百零五", 105, ChineseCountMethod::TenThousand);
+    test!("二百", 200, ChineseCountMethod::TenThousand);
+    test!("三十四萬", 34_0000, ChineseCountMethod::TenThousand);
+    test!("一萬零五百", 1_0500, ChineseCountMethod::TenThousand);
+    test!("一百二十三", 123, ChineseCountMethod::TenThousand);
+    test!("一萬五千億", 1_5000_0000_0000u64, ChineseCountMethod::Middle);
+
+    // 浮點數不省略
+    test!("一百二十", 120.0, ChineseCountMethod::TenThousand);
+
+    assert_eq!(
+        "兩千三",
+        2300.to_chinese_with(&options.with_two_style(ChineseTwoStyle::Liang)).unwrap()
+    );
+    assert_eq!("壹佰貳", 120.to_chinese_with(&options.with_case(ChineseCase::Upper)).unwrap());
+}
+
+#[test]
+fn to_chinese_naive_with() {
+    let options = ChineseFormatOptions::new();
+
+    assert_eq!("二零二四", 2024.to_chinese_naive_with(&options).unwrap());
+
+    let options = options.with_zero_style(ChineseZeroStyle::Circle);
+
+    assert_eq!("二〇二四", 2024.to_chinese_naive_with(&options).unwrap());
+    assert_eq!("三〇五", 305u16.to_chinese_naive_with(&options).unwrap());
+    assert_eq!("〇", 0.to_chinese_naive_with(&options).unwrap());
+    assert_eq!("負一〇點〇五", (-10.05).to_chinese_naive_with(&options).unwrap());
+    assert_eq!(
+        "零",
+        0.to_chinese_naive_with(&ChineseFormatOptions::new().with_case(ChineseCase::Upper))
+            .unwrap()
+    );
+
+    let options = options.with_digit_grouping(4, ChineseDigitSeparator::FullWidthComma);
+
+    assert_eq!("一二三四", 1234.to_chinese_naive_with(&options).unwrap());
+    assert_eq!("一，二三四五", 12345.to_chinese_naive_with(&options).unwrap());
+    assert_eq!("負一二三四，五六七八", (-1234_5678).to_chinese_naive_with(&options).unwrap());
+    assert_eq!("一，〇〇〇〇點一二", 10000.12345f64.to_chinese_naive_with(&options).unwrap());
+
+    let options = ChineseFormatOptions::new()
+        .with_case(ChineseCase::Upper)
+        .with_digit_grouping(3, ChineseDigitSeparator::Comma);
+
+    assert_eq!("壹,貳參肆,伍陸柒", 1_234_567u32.to_chinese_naive_with(&options).unwrap());
+    assert_eq!(
+        "壹貳參",
+        123u8
+            .to_chinese_naive_with(&options.with_digit_grouping(0, ChineseDigitSeparator::Comma))
+            .unwrap()
+    );
+
+    let mut s = String::new();
+
+    12345
+        .write_chinese_naive_with(
+            &mut s,
+            &options.with_digit_grouping(2, ChineseDigitSeparator::Space),
+        )
+        .unwrap();
+
+    assert_eq!("壹 貳參 肆伍", s);
+}
+
+#[test]
+fn ordinal() {
+    assert_eq!(
+        "第一百二十三",
+        123.to_chinese_ordinal(
+            ChineseVariant::Traditional,
+            ChineseCase::Lower,
+            ChineseCountMethod::TenThousand
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        "第壹万零壹",
+        10001u32
+            .to_chinese_ordinal(
+                ChineseVariant::Simple,
+                ChineseCase::Upper,
+                ChineseCountMethod::TenThousand
+            )
+            .unwrap()
+    );
+
+    let options = ChineseFormatOptions::new();
+
+    assert_eq!("第一百二十三名", 123.to_chinese_ordinal_with(&options, "名").unwrap());
+    assert_eq!("第二十屆", 20.to_chinese_ordinal_with(&options, "屆").unwrap());
+    assert_eq!("第三版", 3u8.to_chinese_ordinal_with(&options, "版").unwrap());
+    assert_eq!("第三名", 3.0.to_chinese_ordinal_with(&options, "名").unwrap());
+    assert_eq!(
+        "第三名",
+        3.to_chinese_ordinal_with(&options.with_sign_style(ChineseSignStyle::Explicit), "名")
+            .unwrap()
+    );
+    assert_eq!(
+        "第两百名",
+        200.to_chinese_ordinal_with(
+            &options.with_variant(ChineseVariant::Simple).with_two_style(ChineseTwoStyle::Liang),
+            "名"
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        Err(NumberToChineseError::Overflow),
+        u128::MAX.to_chinese_ordinal_with(&options.with_method(ChineseCountMethod::Low), "名")
+    );
+    assert_eq!(Err(NumberToChineseError::NotAnOrdinal), 0.to_chinese_ordinal_with(&options, "章"));
+    assert_eq!(
+        Err(NumberToChineseError::NotAnOrdinal),
+        (-3).to_chinese_ordinal_with(&options, "名")
+    );
+    assert_eq!(
+        Err(NumberToChineseError::NotAnOrdinal),
+        1.5.to_chinese_ordinal_with(&options, "名")
+    );
+    assert_eq!(
+        Err(NumberToChineseError::NotAnOrdinal),
+        f64::NAN.to_chinese_ordinal_with(&options, "名")
+    );
+
+    let mut s = String::new();
+
+    2.write_chinese_ordinal_with(&mut s, &options, "").unwrap();
+
+    assert_eq!("第二", s);
+}
+
+#[test]
+fn fraction() {
+    let options = ChineseFormatOptions::new();
+
+    macro_rules! test {
+        ($expect:expr, $value:expr) => {
+            assert_eq!($expect, $value.to_chinese_with(&options).unwrap());
+        };
+    }
+
+    test!("三分之一", (1, 3));
+    test!("負五分之二", (-2, 5));
+    test!("負五分之二", (2i64, -5i64));
+    test!("五分之二", (-2i8, -5i8));
+    test!("三分之零", (0, -3));
+    test!("百分之五", (5u8, 100u8));
+    test!("十分之八", (8u128, 10u128));
+    test!("千分之三", (3u16, 1000u16));
+    test!("百萬分之一", (1u32, 100_0000u32));
+    test!("一分之一", (1, 1));
+    test!("一百零一分之五", (5, 101));
+    test!("二百分之五", (5, 200));
+    test!("萬分之一千二百三十四", (1234usize, 10000usize));
+    test!("負二分之一百二十八", (i8::MIN, 2));
+    test!("負一分之一", (-1, 1));
+
+    assert_eq!(
+        "正參分之壹",
+        (1, 3)
+            .to_chinese_with(
+                &options.with_case(ChineseCase::Upper).with_sign_style(ChineseSignStyle::Explicit)
+            )
+            .unwrap()
+    );
+
+    assert_eq!(
+        "三分之一",
+        (1, 3).to_chinese_naive(ChineseVariant::Traditional, ChineseCase::Lower).unwrap()
+    );
+    assert_eq!(
+        "負一〇〇分之一二",
+        (-12, 100)
+            .to_chinese_naive_with(&options.with_zero_style(ChineseZeroStyle::Circle))
+            .unwrap()
+    );
+    assert_eq!(
+        Err(NumberToChineseError::Overflow),
+        (1u64, 1_0000_0000_0000_0000u64)
+            .to_chinese_with(&options.with_method(ChineseCountMethod::Low))
+    );
+    assert_eq!(
+        Err(NumberToChineseError::Underflow),
+        (-1_0000_0000_0000_0000i64, 3i64)
+            .to_chinese_with(&options.with_method(ChineseCountMethod::Low))
+    );
+    assert_eq!(
+        "仟分之參",
+        (3, 1000).to_chinese_with(&options.with_case(ChineseCase::Upper)).unwrap()
+    );
+    assert_eq!(
+        "十分之八",
+        (8, 10).to_chinese_with(&options.with_ten_style(ChineseTenStyle::Full)).unwrap()
+    );
+    assert_eq!(
+        "萬億分之一",
+        (1u64, 1_0000_0000_0000u64)
+            .to_chinese_with(&options.with_method(ChineseCountMethod::Middle))
+            .unwrap()
+    );
+    assert_eq!(Err(NumberToChineseError::ZeroDenominator), (1, 0).to_chinese_with(&options));
+    assert_eq!(Err(NumberToChineseError::ZeroDenominator), (1, 0).to_chinese_naive_with(&options));
+}
+
+#[test]
+fn percent() {
+    let options = ChineseFormatOptions::new();
+
+    macro_rules! test {
+        ($expect:expr, $value:expr, $percent:expr) => {
+            assert_eq!($expect, $value.to_chinese_percent_with(&options, $percent).unwrap());
+        };
+    }
+
+    test!("百分之十五點三", 0.153, ChinesePercent::Percent);
+    test!("百分之零", 0.0, ChinesePercent::Percent);
+    test!("百分之零", -0.0, ChinesePercent::Percent);
+    test!("百分之零點五", 0.005, ChinesePercent::Percent);
+    test!("百分之一百", 1, ChinesePercent::Percent);
+    test!("百分之一百二十五", 1.25f32, ChinesePercent::Percent);
+    test!("負百分之五十", -0.5, ChinesePercent::Percent);
+    test!("千分之五", 0.005, ChinesePercent::PerMille);
+    test!("千分之一點二三", 0.00123, ChinesePercent::PerMille);
+    test!("萬分之三", 0.0003, ChinesePercent::PerTenThousand);
+    test!("萬分之三萬", 3u64, ChinesePercent::PerTenThousand);
+
+    assert_eq!(
+        "佰分之拾伍點參",
+        0.153
+            .to_chinese_percent_with(
+                &options.with_case(ChineseCase::Upper),
+                ChinesePercent::Percent
+            )
+            .unwrap()
+    );
+    assert_eq!(
+        "正千分之五",
+        0.005
+            .to_chinese_percent_with(
+                &options.with_sign_style(ChineseSignStyle::Explicit),
+                ChinesePercent::PerMille
+            )
+            .unwrap()
+    );
+    assert_eq!(
+        "万分之一点二",
+        0.00012
+            .to_chinese_percent_with(
+                &options.with_variant(ChineseVariant::Simple),
+                ChinesePercent::PerTenThousand
+            )
+            .unwrap()
+    );
+    assert_eq!(
+        "百分之三十三點三三",
+        (1.0 / 3.0)
+            .to_chinese_percent_with(
+                &options.with_fraction_options(ChineseFractionOptions::new()),
+                ChinesePercent::Percent
+            )
+            .unwrap()
+    );
+    assert_eq!(
+        Err(NumberToChineseError::NotANumber),
+        f64::NAN.to_chinese_percent_with(&options, ChinesePercent::Percent)
+    );
+    assert_eq!(
+        Err(NumberToChineseError::Infinite),
+        f32::NEG_INFINITY.to_chinese_percent_with(&options, ChinesePercent::Percent)
+    );
+    assert_eq!(
+        Err(NumberToChineseError::Overflow),
+        1e14.to_chinese_percent_with(
+            &options.with_method(ChineseCountMethod::Low),
+            ChinesePercent::Percent
+        )
+    );
+    assert_eq!(
+        Err(NumberToChineseError::Underflow),
+        (-1e14).to_chinese_percent_with(
+            &options.with_method(ChineseCountMethod::Low),
+            ChinesePercent::Percent
+        )
+    );
+}
+
+#[test]
+fn mixed() {
+    let options = ChineseFormatOptions::new();
+
+    macro_rules! test {
+        ($expect:expr, $value:expr) => {
+            assert_eq!($expect, $value.to_chinese_mixed_with(&options, None).unwrap());
+        };
+        ($expect:expr, $value:expr, $significant_digits:expr) => {
+            assert_eq!(
+                $expect,
+                $value.to_chinese_mixed_with(&options, Some($significant_digits)).unwrap()
+            );
+        };
+    }
+
+    test!("0", 0);
+    test!("5", 5u8);
+    test!("9999", 9999);
+    test!("1萬", 1_0000);
+    test!("1萬0005", 1_0005);
+    test!("12億3456萬7890", 12_3456_7890u64);
+    test!("1億0050萬", 1_0050_0000);
+    test!("1億0005", 1_0000_0005);
+    test!("12京3456兆7890億1234萬5678", 12_3456_7890_1234_5678u128);
+    test!("-3萬5000", -3_5000i64);
+    test!("3萬5000.5", 3_5000.5);
+    test!("1億0.5", 1_0000_0000.5);
+    test!("0.25", 0.25);
+
+    test!("1.23億", 1_2345_6789, 3);
+    test!("3.5萬", 3_5000.0, 2);
+    test!("3.5萬", 3_5000.0, 5);
+    test!("4萬", 3_5000.0, 1);
+    test!("12.3京", 12_3456_7890_1234_5678u128, 3);
+    test!("1234萬", 1234_4321, 2);
+    test!("1億", 9999_5000, 3);
+    test!("1億", 1_0000_0005, 3);
+    test!("-1.5萬", -1_4999.5, 2);
+    test!("-1.23億", -1_2345_6789, 3);
+    test!("1億", 1_2345_6789, 0);
+    test!("12億", 12_3456_7890u64, 0);
+    test!("9999.99", 9999.99, 2);
+
+    assert_eq!(
+        "12兆3456萬7890億1234萬5678",
+        12_3456_7890_1234_5678u128
+            .to_chinese_mixed_with(&options.with_method(ChineseCountMethod::Middle), None)
+            .unwrap()
+    );
+    assert_eq!(
+        "1萬億",
+        1_0000_0000_0000u64
+            .to_chinese_mixed_with(&options.with_method(ChineseCountMethod::High), None)
+            .unwrap()
+    );
+    assert_eq!(
+        "1.2萬億",
+        1_2345_0000_0000u64
+            .to_chinese_mixed_with(&options.with_method(ChineseCountMethod::Middle), Some(2))
+            .unwrap()
+    );
+    assert_eq!(
+        "1垓5億",
+        1_0050_0000
+            .to_chinese_mixed_with(&options.with_method(ChineseCountMethod::Low), None)
+            .unwrap()
+    );
+    assert_eq!(
+        "1.2億",
+        12_3456
+            .to_chinese_mixed_with(&options.with_method(ChineseCountMethod::Low), Some(2))
+            .unwrap()
+    );
+    assert_eq!(
+        "1亿2345万",
+        1_2345_0000
+            .to_chinese_mixed_with(&options.with_variant(ChineseVariant::Simple), None)
+            .unwrap()
+    );
+    assert_eq!(
+        "1.24億",
+        1_2350_0000
+            .to_chinese_mixed_with(
+                &options.with_fraction_options(
+                    ChineseFractionOptions::new().with_rounding(ChineseRounding::HalfEven)
+                ),
+                Some(3)
+            )
+            .unwrap()
+    );
+    assert_eq!(
+        "+1萬",
+        1_0000
+            .to_chinese_mixed_with(&options.with_sign_style(ChineseSignStyle::Explicit), None)
+            .unwrap()
+    );
+    assert_eq!(
+        Err(NumberToChineseError::Overflow),
+        1_0000_0000_0000_0000u64
+            .to_chinese_mixed_with(&options.with_method(ChineseCountMethod::Low), None)
+    );
+    assert_eq!(
+        Err(NumberToChineseError::NotANumber),
+        f64::NAN.to_chinese_mixed_with(&options, None)
+    );
+}
+
+#[test]
+fn approximate() {
+    let options = ChineseFormatOptions::new();
+
+    macro_rules! test {
+        ($expect:expr, $value:expr) => {
+            test!($expect, $value, ChineseApproximateOptions::new());
+        };
+        ($expect:expr, $value:expr, $approximate_options:expr) => {
+            assert_eq!(
+                $expect,
+                $value.to_chinese_approximate_with(&options, &$approximate_options).unwrap()
+            );
+        };
+    }
+
+    test!("零", 0);
+    test!("五", 5);
+    test!("十", 10);
+    test!("三十多", 35);
+    test!("近一百", 98);
+    test!("三千", 3000);
+    test!("三千多", 3001);
+    test!("近三千", 2950);
+    test!("一萬多", 1_2345);
+    test!("近一萬", 9800);
+    test!("近十萬", 9_9999);
+    test!("十多萬", 15_0000);
+    test!("三百多萬", 345_6789);
+    test!("三兆多", 3_4000_0000_0000u64);
+    test!("負三十多", -35);
+    test!("零點多", 0.5);
+    test!("五點多", 5.5);
+    test!("三千多", 3000.5);
+
+    let two = ChineseApproximateOptions::new().with_significant_digits(2);
+
+    test!("三十五", 35, two);
+    test!("三萬五千多", 3_5123, two);
+    test!("近三千", 2950, two);
+    test!("九千八百", 9800, two);
+    test!("近三百五十萬", 345_6789, two);
+
+    test!("三十多", 35, ChineseApproximateOptions::new().with_significant_digits(0));
+    test!("九千多", 9800, ChineseApproximateOptions::new().with_tolerance(0));
+    test!("近三千", 2800, ChineseApproximateOptions::new().with_tolerance(10));
+
+    let magnitude = ChineseApproximateOptions::new().with_magnitude(true);
+
+    test!("五", 5, magnitude);
+    test!("上十", 10, magnitude);
+    test!("數十", 35, magnitude);
+    test!("近百", 98, magnitude);
+    test!("數千", 3000, magnitude);
+    test!("上萬", 1_2345, magnitude);
+    test!("近萬", 9800, magnitude);
+    test!("數百萬", 345_6789, magnitude);
+    test!("負數十", -35, magnitude);
+
+    assert_eq!(
+        "三萬多億",
+        3_4000_0000_0000u64
+            .to_chinese_approximate_with(
+                &options.with_method(ChineseCountMethod::Middle),
+                &ChineseApproximateOptions::new()
+            )
+            .unwrap()
+    );
+    assert_eq!(
+        "数百万",
+        345_6789
+            .to_chinese_approximate_with(&options.with_variant(ChineseVariant::Simple), &magnitude)
+            .unwrap()
+    );
+    assert_eq!(
+        "上萬",
+        1_2345
+            .to_chinese_approximate_with(
+                &options.with_case(ChineseCase::Upper).with_glyph_style(ChineseGlyphStyle::Ancient),
+                &magnitude
+            )
+            .unwrap()
+    );
+    assert_eq!(
+        "數陌萬",
+        345_6789
+            .to_chinese_approximate_with(
+                &options.with_case(ChineseCase::Upper).with_glyph_style(ChineseGlyphStyle::Ancient),
+                &magnitude
+            )
+            .unwrap()
+    );
+    assert_eq!(
+        "上萬",
+        1_2345
+            .to_chinese_approximate_with(
+                &options.with_case(ChineseCase::Upper).with_script(ChineseScript::Japanese),
+                &magnitude
+            )
+            .unwrap()
+    );
+    assert_eq!(
+        "上万",
+        1_2345
+            .to_chinese_approximate_with(&options.with_script(ChineseScript::Japanese), &magnitude)
+            .unwrap()
+    );
+    assert_eq!(
+        "三十多",
+        35.to_chinese_approximate_with(
+            &options.with_colloquial(true),
+            &ChineseApproximateOptions::new()
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        Err(NumberToChineseError::Overflow),
+        1_0000_0000_0000_0000u64.to_chinese_approximate_with(
+            &options.with_method(ChineseCountMethod::Low),
+            &ChineseApproximateOptions::new()
+        )
+    );
+    assert_eq!(
+        Err(NumberToChineseError::NotANumber),
+        f64::NAN.to_chinese_approximate_with(&options, &ChineseApproximateOptions::new())
+    );
+}
+
+#[test]
+fn grouped_digits() {
+    let options = ChineseFormatOptions::new();
+
+    macro_rules! test {
+        ($expect:expr, $value:expr) => {
+            test!($expect, $value, options);
+        };
+        ($expect:expr, $value:expr, $options:expr) => {
+            assert_eq!(
+                $expect,
+                $value.to_grouped_digits_with(&$options, ChineseDigitSeparator::Comma).unwrap()
+            );
+        };
+    }
+
+    test!("0", 0);
+    test!("1234", 1234);
+    test!("1,2345", 1_2345);
+    test!("12,3456,7890", 12_3456_7890u64);
+    test!("1,0000,0005", 1_0000_0005);
+    test!("-1,2345", -1_2345);
+    test!("0", -0.0);
+    test!("0.25", 0.25);
+    test!("-1,2345.5", -1_2345.5);
+    test!("+1,2345", 1_2345, options.with_sign_style(ChineseSignStyle::Explicit));
+    test!("0", 0, options.with_sign_style(ChineseSignStyle::Explicit));
+    test!("1,2345.68", 1_2345.675, options.with_fraction_options(ChineseFractionOptions::new()));
+    test!("1,2,3,4567", 123_4567, options.with_method(ChineseCountMethod::Low));
+    test!("12,34567890", 12_3456_7890u64, options.with_method(ChineseCountMethod::Middle));
+    test!(
+        "1234567,8901234567890123,4567890123456789",
+        1234567_8901234567890123_4567890123456789u128,
+        options.with_method(ChineseCountMethod::High)
+    );
+
+    assert_eq!(
+        "12，3456，7890",
+        12_3456_7890u64
+            .to_grouped_digits_with(&options, ChineseDigitSeparator::FullWidthComma)
+            .unwrap()
+    );
+    assert_eq!(
+        "12 3456 7890",
+        12_3456_7890u64.to_grouped_digits_with(&options, ChineseDigitSeparator::Space).unwrap()
+    );
+    assert_eq!(
+        Err(NumberToChineseError::Infinite),
+        f64::INFINITY.to_grouped_digits_with(&options, ChineseDigitSeparator::Comma)
+    );
+}
+
+#[test]
+fn currency() {
+    let options = ChineseCurrencyOptions::new();
+
+    macro_rules! test {
+        ($expect:expr, $value:expr) => {
+            test!($expect, $value, options);
+        };
+        ($expect:expr, $value:expr, $options:expr) => {
+            assert_eq!($expect, $value.to_chinese_currency_with(&$options).unwrap());
+        };
+    }
+
+    test!("人民幣零元整", 0);
+    test!("人民幣伍分", 5);
+    test!("人民幣伍角整", 50);
+    test!("人民幣伍角參分", 53);
+    test!("人民幣壹元整", 100);
+    test!("人民幣壹拾元整", 1000);
+    test!("人民幣壹萬零貳佰元整", 1_020_000);
+    test!("人民幣壹仟肆佰零玖元伍角整", 140_950);
+    test!("人民幣陸仟零柒元壹角肆分", 600_714);
+    test!("人民幣壹仟陸佰捌拾元零參角貳分", 168_032);
+    test!("人民幣壹拾萬柒仟元零伍角參分", 10_700_053);
+    test!("人民幣壹萬陸仟肆佰零玖元零貳分", 1_640_902);
+    test!("人民幣參佰貳拾伍元零肆分", 32_504);
+    test!("人民幣壹佰萬元零壹分", 100_000_001);
+    test!("人民幣壹佰億零壹元整", 1_000_000_000_100i64);
+    test!("人民幣壹拾伍元伍角伍分", 1555u16);
+    test!(
+        "人民幣壹澗柒仟零壹拾肆溝壹仟壹佰捌拾參穰肆仟陸佰零肆秭陸仟玖佰貳拾參垓壹仟柒佰參拾壹京陸仟捌佰柒拾參兆零參佰柒拾壹億伍仟捌佰捌拾肆萬壹仟零伍拾柒元貳角柒分",
+        i128::MAX
+    );
+    let simple = options.with_variant(ChineseVariant::Simple);
+
+    test!("人民币壹万零贰佰元整", 1_020_000, simple);
+    test!("人民币陆仟零柒元壹角肆分", 600_714, simple);
+    test!(
+        "人民币壹佰圆正",
+        10_000,
+        simple.with_yuan_style(ChineseYuanStyle::Round).with_whole_style(ChineseWholeStyle::Exact)
+    );
+    test!(
+        "壹佰圓整",
+        10_000,
+        options.with_yuan_style(ChineseYuanStyle::Round).with_currency_name(false)
+    );
+    test!(
+        "伍角正",
+        50,
+        options.with_whole_style(ChineseWholeStyle::Exact).with_currency_name(false)
+    );
+
+    let taiwan = ChineseCurrencyOptions::new_taiwan_dollar();
+
+    test!("新臺幣壹萬貳仟參佰元整", 1_230_000, taiwan);
+    test!("新臺幣壹拾萬零伍佰元整", 10_050_000, taiwan);
+    test!("新台幣壹萬貳仟參佰元整", 1_230_000, taiwan.with_tai_style(ChineseTaiStyle::Common));
+    test!("新台币壹万贰仟叁佰元整", 1_230_000, taiwan.with_variant(ChineseVariant::Simple));
+    test!("新臺幣伍元伍角整", 550, taiwan);
+    test!("新臺幣壹仟元零伍分", 100_005, taiwan);
+    test!("新臺幣壹佰萬元零壹分", 100_000_001, taiwan);
+    test!("新臺幣壹佰億零壹元整", 1_000_000_000_100i64, taiwan);
+    test!("新臺幣壹拾元整", 1000, taiwan);
+
+    // 新臺幣刻意沿用人民幣的 **「零」** 和 **「整」** 規則，只有貨幣名稱不同
+    for value in [1000, 1005, 10_050_000, 100_000_001, 600_714, 1_000_000_000_100i64] {
+        assert_eq!(
+            value.to_chinese_currency_with(&options).unwrap().replacen("人民幣", "新臺幣", 1),
+            value.to_chinese_currency_with(&taiwan).unwrap()
+        );
+    }
+
+    let hong_kong = ChineseCurrencyOptions::hong_kong_dollar();
+
+    test!("港幣壹佰貳拾圓伍毫", 12_050, hong_kong);
+    test!("港幣壹佰貳拾圓伍毫貳仙", 12_052, hong_kong);
+    test!("港幣壹佰圓正", 10_000, hong_kong);
+    test!("港幣壹佰圓零伍仙", 10_005, hong_kong);
+    test!("港幣伍毫", 50, hong_kong);
+    test!("港币壹佰贰拾圆伍毫", 12_050, hong_kong.with_variant(ChineseVariant::Simple));
+    test!(
+        "港幣壹佰元整",
+        10_000,
+        hong_kong
+            .with_yuan_style(ChineseYuanStyle::Yuan)
+            .with_whole_style(ChineseWholeStyle::Whole)
+    );
+
+    assert_eq!(Err(NumberToChineseError::Underflow), (-1).to_chinese_currency_with(&options));
 }
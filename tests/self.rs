@@ -6,7 +6,9 @@ use core::{
 };
 
 use chinese_number::{
-    ChineseCase, ChineseCountMethod, ChineseToNumber, ChineseVariant, NumberToChinese,
+    ChineseCase, ChineseCountMethod, ChineseCurrencyOptions, ChineseDigitSeparator,
+    ChineseFormatOptions, ChineseFractionOptions, ChinesePercent, ChineseTenStyle, ChineseToNumber,
+    ChineseTwoStyle, ChineseVariant, ChineseZeroStyle, NumberToChinese,
 };
 use num_traits::{CheckedAdd, CheckedMul};
 
@@ -64,6 +66,7 @@ fn test_u8() {
         assert_eq!(
             i,
             i.to_chinese_naive(ChineseVariant::Traditional, ChineseCase::Lower)
+                .unwrap()
                 .to_number_naive()
                 .unwrap()
         );
@@ -88,6 +91,7 @@ fn test_i8() {
         assert_eq!(
             i,
             i.to_chinese_naive(ChineseVariant::Traditional, ChineseCase::Lower)
+                .unwrap()
                 .to_number_naive()
                 .unwrap()
         );
@@ -112,6 +116,7 @@ fn test_u16() {
         assert_eq!(
             i,
             i.to_chinese_naive(ChineseVariant::Traditional, ChineseCase::Lower)
+                .unwrap()
                 .to_number_naive()
                 .unwrap()
         );
@@ -136,6 +141,7 @@ fn test_i16() {
         assert_eq!(
             i,
             i.to_chinese_naive(ChineseVariant::Traditional, ChineseCase::Lower)
+                .unwrap()
                 .to_number_naive()
                 .unwrap()
         );
@@ -160,6 +166,7 @@ fn test_u32() {
         assert_eq!(
             i,
             i.to_chinese_naive(ChineseVariant::Traditional, ChineseCase::Lower)
+                .unwrap()
                 .to_number_naive()
                 .unwrap()
         );
@@ -184,6 +191,7 @@ fn test_i32() {
         assert_eq!(
             i,
             i.to_chinese_naive(ChineseVariant::Traditional, ChineseCase::Lower)
+                .unwrap()
                 .to_number_naive()
                 .unwrap()
         );
@@ -218,6 +226,7 @@ fn test_u64() {
         assert_eq!(
             i,
             i.to_chinese_naive(ChineseVariant::Traditional, ChineseCase::Lower)
+                .unwrap()
                 .to_number_naive()
                 .unwrap()
         );
@@ -252,6 +261,7 @@ fn test_i64() {
         assert_eq!(
             i,
             i.to_chinese_naive(ChineseVariant::Traditional, ChineseCase::Lower)
+                .unwrap()
                 .to_number_naive()
                 .unwrap()
         );
@@ -286,6 +296,7 @@ fn test_u128() {
         assert_eq!(
             i,
             i.to_chinese_naive(ChineseVariant::Traditional, ChineseCase::Lower)
+                .unwrap()
                 .to_number_naive()
                 .unwrap()
         );
@@ -320,6 +331,7 @@ fn test_i128() {
         assert_eq!(
             i,
             i.to_chinese_naive(ChineseVariant::Traditional, ChineseCase::Lower)
+                .unwrap()
                 .to_number_naive()
                 .unwrap()
         );
@@ -354,6 +366,7 @@ fn test_usize() {
         assert_eq!(
             i,
             i.to_chinese_naive(ChineseVariant::Traditional, ChineseCase::Lower)
+                .unwrap()
                 .to_number_naive()
                 .unwrap()
         );
@@ -388,8 +401,193 @@ fn test_isize() {
         assert_eq!(
             i,
             i.to_chinese_naive(ChineseVariant::Traditional, ChineseCase::Lower)
+                .unwrap()
                 .to_number_naive()
                 .unwrap()
         );
     });
 }
+
+#[test]
+fn test_format_options() {
+    for method in ChineseCountMethod::variants().iter().copied().skip(1) {
+        for ten_style in [ChineseTenStyle::Natural, ChineseTenStyle::Short, ChineseTenStyle::Full] {
+            for chinese_variant in [ChineseVariant::Traditional, ChineseVariant::Simple] {
+                let options = ChineseFormatOptions::new()
+                    .with_variant(chinese_variant)
+                    .with_method(method)
+                    .with_ten_style(ten_style)
+                    .with_two_style(ChineseTwoStyle::Liang);
+
+                ranger(i64::MIN..=i64::MAX, |i| {
+                    assert_eq!(i, i.to_chinese_with(&options).unwrap().to_number(method).unwrap());
+                });
+            }
+        }
+    }
+}
+
+#[test]
+fn test_colloquial() {
+    for method in ChineseCountMethod::variants().iter().copied() {
+        for chinese_variant in [ChineseVariant::Traditional, ChineseVariant::Simple] {
+            let options = ChineseFormatOptions::new()
+                .with_variant(chinese_variant)
+                .with_method(method)
+                .with_two_style(ChineseTwoStyle::Liang)
+                .with_colloquial(true);
+
+            for i in 0..=20_0000u32 {
+                assert_eq!(i, i.to_chinese_with(&options).unwrap().to_number(method).unwrap());
+            }
+
+            if method != ChineseCountMethod::Low {
+                ranger(i64::MIN..=i64::MAX, |i| {
+                    assert_eq!(i, i.to_chinese_with(&options).unwrap().to_number(method).unwrap());
+                });
+            }
+        }
+    }
+}
+
+#[test]
+fn test_naive_with() {
+    for separator in [
+        ChineseDigitSeparator::FullWidthComma,
+        ChineseDigitSeparator::Comma,
+        ChineseDigitSeparator::Space,
+    ] {
+        for size in 0..=5 {
+            let options = ChineseFormatOptions::new()
+                .with_case(ChineseCase::Upper)
+                .with_zero_style(ChineseZeroStyle::Circle)
+                .with_digit_grouping(size, separator);
+
+            ranger(i128::MIN..=i128::MAX, |i| {
+                assert_eq!(
+                    i,
+                    i.to_chinese_naive_with(&options).unwrap().to_number_naive().unwrap()
+                );
+            });
+        }
+    }
+}
+
+#[test]
+fn test_ordinal() {
+    for method in ChineseCountMethod::variants().iter().copied() {
+        let options = ChineseFormatOptions::new().with_method(method);
+
+        ranger(1..=u32::MAX, |i| {
+            assert_eq!(
+                i,
+                i.to_chinese_ordinal_with(&options, "").unwrap().to_number_ordinal(method).unwrap()
+            );
+        });
+    }
+}
+
+#[test]
+fn test_fraction() {
+    for method in ChineseCountMethod::variants().iter().copied() {
+        let options = ChineseFormatOptions::new().with_method(method);
+
+        ranger(i32::MIN..=i32::MAX, |i| {
+            for d in [1i32, 10, 100, 3_0000, i32::MAX] {
+                assert_eq!(
+                    (i, d),
+                    (i, d).to_chinese_with(&options).unwrap().to_number(method).unwrap()
+                );
+                assert_eq!(
+                    (i, d),
+                    (i, d).to_chinese_naive_with(&options).unwrap().to_number_naive().unwrap()
+                );
+            }
+        });
+    }
+}
+
+#[test]
+fn test_fraction_precision() {
+    for method in ChineseCountMethod::variants().iter().copied() {
+        for precision in [4, 10] {
+            let options = ChineseFormatOptions::new()
+                .with_method(method)
+                .with_fraction_options(ChineseFractionOptions::new().with_precision(precision));
+
+            for i in -4_0000i32..=4_0000 {
+                let f = i as f64 / 1_0000.0;
+
+                let value: f64 = f.to_chinese_with(&options).unwrap().to_number(method).unwrap();
+
+                assert!((f - value).abs() < 1e-9, "{} {}", f, value);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_percent() {
+    for method in ChineseCountMethod::variants().iter().copied() {
+        for percent in
+            [ChinesePercent::Percent, ChinesePercent::PerMille, ChinesePercent::PerTenThousand]
+        {
+            let options = ChineseFormatOptions::new().with_method(method);
+
+            ranger(-100_0000i32..=100_0000i32, |i| {
+                let f = i as f64 / 1000.0;
+
+                let value: f64 = f
+                    .to_chinese_percent_with(&options, percent)
+                    .unwrap()
+                    .to_number_percent(method)
+                    .unwrap();
+
+                assert!((f - value).abs() < 1e-9);
+            });
+        }
+    }
+}
+
+#[test]
+fn test_grouped_digits() {
+    for method in ChineseCountMethod::variants().iter().copied() {
+        let options = ChineseFormatOptions::new().with_method(method);
+
+        for separator in [
+            ChineseDigitSeparator::FullWidthComma,
+            ChineseDigitSeparator::Comma,
+            ChineseDigitSeparator::Space,
+        ] {
+            ranger(i128::MIN..=i128::MAX, |i| {
+                assert_eq!(
+                    i,
+                    i.to_grouped_digits_with(&options, separator)
+                        .unwrap()
+                        .to_number_grouped(method)
+                        .unwrap()
+                );
+            });
+        }
+    }
+}
+
+#[test]
+fn test_currency() {
+    for chinese_variant in [ChineseVariant::Traditional, ChineseVariant::Simple] {
+        for options in [
+            ChineseCurrencyOptions::renminbi(),
+            ChineseCurrencyOptions::new_taiwan_dollar(),
+            ChineseCurrencyOptions::hong_kong_dollar(),
+        ] {
+            let options = options.with_variant(chinese_variant);
+
+            ranger(0..=i64::MAX, |i| {
+                assert_eq!(
+                    i,
+                    i.to_chinese_currency_with(&options).unwrap().to_number_currency().unwrap()
+                );
+            });
+        }
+    }
+}